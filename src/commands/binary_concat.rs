@@ -0,0 +1,190 @@
+//! Implements `secret binary concat` — appends binary data to a secret binary.
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, Signature, Span, SyntaxShape, Type, Value,
+};
+
+use crate::SecretBinary;
+
+#[derive(Clone)]
+pub struct SecretBinaryConcatCommand;
+
+/// Extract the bytes of the `other` argument, which may be plain or secret binary.
+fn other_bytes(other: &Value, span: Span) -> Result<Vec<u8>, LabeledError> {
+    match other {
+        Value::Binary { val, .. } => Ok(val.clone()),
+        Value::Custom { val, .. } => val
+            .as_any()
+            .downcast_ref::<SecretBinary>()
+            .map(|secret_binary| secret_binary.reveal().into_owned())
+            .ok_or_else(|| {
+                LabeledError::new("Type mismatch").with_label(
+                    format!(
+                        "Expected binary or secret_binary, found {}",
+                        val.type_name()
+                    ),
+                    span,
+                )
+            }),
+        _ => Err(LabeledError::new("Type mismatch").with_label(
+            format!(
+                "Expected binary or secret_binary, found {}",
+                other.get_type()
+            ),
+            span,
+        )),
+    }
+}
+
+/// Append `other` to the bytes of a secret custom value, producing a new `SecretBinary`.
+fn concat_secret_binary(
+    val: &dyn nu_protocol::CustomValue,
+    other: &[u8],
+    span: Span,
+) -> Result<Value, LabeledError> {
+    if let Some(secret_binary) = val.as_any().downcast_ref::<SecretBinary>() {
+        let mut bytes = secret_binary.reveal().into_owned();
+        bytes.extend_from_slice(other);
+        Ok(Value::custom(
+            Box::new(secret_binary.with_value(bytes)),
+            span,
+        ))
+    } else {
+        Err(LabeledError::new("Unsupported secret type")
+            .with_label("Only SecretBinary supports concatenation", span))
+    }
+}
+
+impl PluginCommand for SecretBinaryConcatCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret binary concat"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(
+                Type::Custom("secret_binary".into()),
+                Type::Custom("secret_binary".into()),
+            )])
+            .required(
+                "other",
+                SyntaxShape::Any,
+                "Binary or secret binary to append",
+            )
+            .category(Category::Bytes)
+    }
+
+    fn description(&self) -> &str {
+        "Append binary data to a secret binary without revealing it"
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                example: r#"0x[01 02] | secret wrap | secret binary concat 0x[03 04]"#,
+                description: "Append plain bytes to a secret binary",
+                result: None,
+            },
+            Example {
+                example: r#"let body = (0x[ca fe] | secret wrap); 0x[01] | secret wrap | secret binary concat $body"#,
+                description: "Join a secret header and a secret body",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let other: Value = call.req(0)?;
+        let other = other_bytes(&other, call.head)?;
+
+        match input {
+            PipelineData::Value(value, metadata) => {
+                let result = match value {
+                    Value::Custom { val, .. } => {
+                        concat_secret_binary(val.as_ref(), &other, call.head)?
+                    }
+                    _ => {
+                        return Err(LabeledError::new("Invalid input").with_label(
+                            "Input must be a secret binary. Use 'secret wrap' to create a secret first",
+                            call.head,
+                        ));
+                    }
+                };
+
+                Ok(PipelineData::Value(result, metadata))
+            }
+            _ => Err(LabeledError::new("Invalid input")
+                .with_label("Expected a single secret value", call.head)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::unwrap::reveal_secret_value;
+    use super::*;
+    use crate::SecretString;
+
+    #[test]
+    fn test_command_name() {
+        let command = SecretBinaryConcatCommand;
+        assert_eq!(command.name(), "secret binary concat");
+    }
+
+    #[test]
+    fn test_signature() {
+        let command = SecretBinaryConcatCommand;
+        let signature = command.signature();
+        assert_eq!(signature.name, "secret binary concat");
+        assert_eq!(signature.required_positional.len(), 1);
+    }
+
+    #[test]
+    fn test_concat_round_trip() {
+        let span = Span::test_data();
+        let header = SecretBinary::new(vec![0x01, 0x02]);
+        let body = Value::custom(Box::new(SecretBinary::new(vec![0xca, 0xfe, 0xba])), span);
+
+        let other = other_bytes(&body, span).unwrap();
+        let result = concat_secret_binary(&header, &other, span).unwrap();
+
+        let custom = result.as_custom_value().unwrap();
+        assert_eq!(custom.type_name(), "secret_binary");
+
+        let unwrapped = reveal_secret_value(custom, span).unwrap();
+        let bytes = unwrapped.as_binary().unwrap();
+        assert_eq!(bytes.len(), 5);
+        assert_eq!(bytes, &[0x01, 0x02, 0xca, 0xfe, 0xba]);
+    }
+
+    #[test]
+    fn test_concat_plain_binary() {
+        let span = Span::test_data();
+        let other = other_bytes(&Value::binary(vec![0x03], span), span).unwrap();
+        let result = concat_secret_binary(&SecretBinary::new(vec![]), &other, span).unwrap();
+
+        let unwrapped = reveal_secret_value(result.as_custom_value().unwrap(), span).unwrap();
+        assert_eq!(unwrapped.as_binary().unwrap(), &[0x03]);
+    }
+
+    #[test]
+    fn test_concat_rejects_non_binary() {
+        let span = Span::test_data();
+        assert!(other_bytes(&Value::string("abc", span), span).is_err());
+
+        let secret_string = Value::custom(Box::new(SecretString::new("abc".to_string())), span);
+        assert!(other_bytes(&secret_string, span).is_err());
+
+        let secret = SecretString::new("abc".to_string());
+        assert!(concat_secret_binary(&secret, &[0x01], span).is_err());
+    }
+}
@@ -0,0 +1,266 @@
+//! Implements `secret binary to-hex` and `secret binary from-hex` — convert
+//! between secret binaries and hex-encoded secret strings without revealing them.
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{Category, Example, LabeledError, PipelineData, Signature, Span, Type, Value};
+
+use crate::{SecretBinary, SecretString};
+
+#[derive(Clone)]
+pub struct SecretBinaryToHexCommand;
+
+#[derive(Clone)]
+pub struct SecretBinaryFromHexCommand;
+
+/// Hex-encode a `SecretBinary`, producing a new `SecretString`.
+fn secret_binary_to_hex(
+    val: &dyn nu_protocol::CustomValue,
+    uppercase: bool,
+    span: Span,
+) -> Result<Value, LabeledError> {
+    if let Some(secret_binary) = val.as_any().downcast_ref::<SecretBinary>() {
+        let encoded = if uppercase {
+            hex::encode_upper(secret_binary.reveal())
+        } else {
+            hex::encode(secret_binary.reveal())
+        };
+        Ok(Value::custom(Box::new(SecretString::new(encoded)), span))
+    } else {
+        Err(LabeledError::new("Unsupported secret type")
+            .with_label("Only SecretBinary supports hex encoding", span))
+    }
+}
+
+/// Decode the hex text of a `SecretString`, producing a new `SecretBinary`.
+///
+/// Both letter cases are accepted. Decode errors never echo the secret's content.
+fn secret_binary_from_hex(
+    val: &dyn nu_protocol::CustomValue,
+    span: Span,
+) -> Result<Value, LabeledError> {
+    if let Some(secret_string) = val.as_any().downcast_ref::<SecretString>() {
+        let bytes = hex::decode(secret_string.reveal().trim()).map_err(|e| {
+            let reason = match e {
+                hex::FromHexError::InvalidHexCharacter { index, .. } => {
+                    format!("invalid character at offset {}", index)
+                }
+                hex::FromHexError::OddLength => "odd number of digits".to_string(),
+                hex::FromHexError::InvalidStringLength => "invalid length".to_string(),
+            };
+            LabeledError::new("Invalid hex")
+                .with_label(format!("Secret string is not valid hex ({})", reason), span)
+        })?;
+        Ok(Value::custom(Box::new(SecretBinary::new(bytes)), span))
+    } else {
+        Err(LabeledError::new("Unsupported secret type")
+            .with_label("Only SecretString supports hex decoding", span))
+    }
+}
+
+/// Unwrap the single secret custom value both hex commands expect as input.
+fn run_hex(
+    call: &EvaluatedCall,
+    input: PipelineData,
+    convert: impl FnOnce(&dyn nu_protocol::CustomValue) -> Result<Value, LabeledError>,
+) -> Result<PipelineData, LabeledError> {
+    match input {
+        PipelineData::Value(Value::Custom { val, .. }, metadata) => {
+            Ok(PipelineData::Value(convert(val.as_ref())?, metadata))
+        }
+        PipelineData::Value(_, _) => Err(LabeledError::new("Invalid input").with_label(
+            "Input must be a secret value. Use 'secret wrap' to create a secret first",
+            call.head,
+        )),
+        _ => Err(LabeledError::new("Invalid input")
+            .with_label("Expected a single secret value", call.head)),
+    }
+}
+
+impl PluginCommand for SecretBinaryToHexCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret binary to-hex"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(
+                Type::Custom("secret_binary".into()),
+                Type::Custom("secret_string".into()),
+            )])
+            .switch("uppercase", "Use uppercase hex digits", Some('u'))
+            .category(Category::Formats)
+    }
+
+    fn description(&self) -> &str {
+        "Encode a secret binary as a hex secret string without revealing it"
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                example: r#"0x[de ad be ef] | secret wrap | secret binary to-hex"#,
+                description: "Hex-encode a secret binary key",
+                result: None,
+            },
+            Example {
+                example: r#"0x[de ad be ef] | secret wrap | secret binary to-hex --uppercase"#,
+                description: "Hex-encode a secret binary with uppercase digits",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let uppercase = call.has_flag("uppercase")?;
+        run_hex(call, input, |val| {
+            secret_binary_to_hex(val, uppercase, call.head)
+        })
+    }
+}
+
+impl PluginCommand for SecretBinaryFromHexCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret binary from-hex"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(
+                Type::Custom("secret_string".into()),
+                Type::Custom("secret_binary".into()),
+            )])
+            .category(Category::Formats)
+    }
+
+    fn description(&self) -> &str {
+        "Decode a hex secret string into a secret binary without revealing it"
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![Example {
+            example: r#""deadbeef" | secret wrap | secret binary from-hex"#,
+            description: "Decode a hex-encoded key into a secret binary",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        run_hex(call, input, |val| secret_binary_from_hex(val, call.head))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SecretInt;
+
+    fn reveal_string(value: &Value) -> String {
+        value
+            .as_custom_value()
+            .unwrap()
+            .as_any()
+            .downcast_ref::<SecretString>()
+            .unwrap()
+            .reveal()
+            .to_string()
+    }
+
+    fn reveal_binary(value: &Value) -> Vec<u8> {
+        value
+            .as_custom_value()
+            .unwrap()
+            .as_any()
+            .downcast_ref::<SecretBinary>()
+            .unwrap()
+            .reveal()
+            .into_owned()
+    }
+
+    #[test]
+    fn test_command_names() {
+        assert_eq!(SecretBinaryToHexCommand.name(), "secret binary to-hex");
+        assert_eq!(SecretBinaryFromHexCommand.name(), "secret binary from-hex");
+    }
+
+    #[test]
+    fn test_signature() {
+        let signature = SecretBinaryToHexCommand.signature();
+        assert_eq!(signature.input_output_types.len(), 1);
+        assert!(signature.get_long_flag("uppercase").is_some());
+
+        let signature = SecretBinaryFromHexCommand.signature();
+        assert_eq!(signature.input_output_types.len(), 1);
+    }
+
+    #[test]
+    fn test_known_vector() {
+        let span = Span::test_data();
+        let secret = SecretBinary::new(vec![0xde, 0xad, 0xbe, 0xef]);
+        let lower = secret_binary_to_hex(&secret, false, span).unwrap();
+        let upper = secret_binary_to_hex(&secret, true, span).unwrap();
+        assert_eq!(reveal_string(&lower), "deadbeef");
+        assert_eq!(reveal_string(&upper), "DEADBEEF");
+        assert!(!format!("{:?}", lower).contains("deadbeef"));
+
+        for text in ["deadbeef", "DEADBEEF"] {
+            let decoded =
+                secret_binary_from_hex(&SecretString::new(text.to_string()), span).unwrap();
+            assert_eq!(reveal_binary(&decoded), vec![0xde, 0xad, 0xbe, 0xef]);
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let span = Span::test_data();
+        for data in [Vec::new(), (0..=255).collect::<Vec<u8>>()] {
+            for uppercase in [false, true] {
+                let encoded =
+                    secret_binary_to_hex(&SecretBinary::new(data.clone()), uppercase, span)
+                        .unwrap();
+                let decoded =
+                    secret_binary_from_hex(encoded.as_custom_value().unwrap(), span).unwrap();
+                assert_eq!(reveal_binary(&decoded), data);
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_hex_rejected() {
+        let span = Span::test_data();
+        let odd = SecretString::new("abc".to_string());
+        let err = secret_binary_from_hex(&odd, span).unwrap_err();
+        assert_eq!(err.msg, "Invalid hex");
+        assert!(format!("{:?}", err).contains("odd number of digits"));
+
+        let non_hex = SecretString::new("zzsecret".to_string());
+        let err = secret_binary_from_hex(&non_hex, span).unwrap_err();
+        assert_eq!(err.msg, "Invalid hex");
+        assert!(!format!("{:?}", err).contains("zzsecret"));
+    }
+
+    #[test]
+    fn test_rejects_unsupported_secrets() {
+        let span = Span::test_data();
+        assert!(secret_binary_to_hex(&SecretInt::new(1), false, span).is_err());
+        let string = SecretString::new("deadbeef".to_string());
+        assert!(secret_binary_to_hex(&string, false, span).is_err());
+        let binary = SecretBinary::new(vec![1, 2, 3]);
+        assert!(secret_binary_from_hex(&binary, span).is_err());
+    }
+}
@@ -0,0 +1,356 @@
+//! Implements `secret binary to-int` and `secret int to-binary` — convert
+//! between secret binaries and secret ints with an explicit byte order.
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, Signature, Span, SyntaxShape, Type, Value,
+};
+
+use crate::{SecretBinary, SecretInt};
+
+#[derive(Clone)]
+pub struct SecretBinaryToIntCommand;
+
+#[derive(Clone)]
+pub struct SecretIntToBinaryCommand;
+
+/// Byte order used to read or write an integer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Endian {
+    Big,
+    Little,
+}
+
+impl std::str::FromStr for Endian {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "be" | "big" => Ok(Endian::Big),
+            "le" | "little" => Ok(Endian::Little),
+            _ => Err(format!("Unknown endianness '{}'. Valid options: be, le", s)),
+        }
+    }
+}
+
+/// Read the `--endian` flag, defaulting to big-endian.
+fn endian_flag(call: &EvaluatedCall) -> Result<Endian, LabeledError> {
+    match call.get_flag::<String>("endian")? {
+        Some(endian) => endian
+            .parse()
+            .map_err(|e| LabeledError::new("Invalid endianness").with_label(e, call.head)),
+        None => Ok(Endian::Big),
+    }
+}
+
+/// Interpret 1 to 8 bytes as an integer in the given byte order.
+///
+/// Fewer than 8 bytes are zero-extended, or sign-extended from their most
+/// significant bit when `signed` is set. Errors mention only the byte count.
+fn bytes_to_int(bytes: &[u8], endian: Endian, signed: bool) -> Result<i64, String> {
+    let len = bytes.len();
+    if len == 0 || len > 8 {
+        return Err(format!(
+            "Secret binary has {} bytes; to-int reads between 1 and 8 bytes",
+            len
+        ));
+    }
+
+    let most_significant = match endian {
+        Endian::Big => bytes[0],
+        Endian::Little => bytes[len - 1],
+    };
+    let fill = if signed && most_significant & 0x80 != 0 {
+        0xff
+    } else {
+        0x00
+    };
+
+    let mut buf = [fill; 8];
+    Ok(match endian {
+        Endian::Big => {
+            buf[8 - len..].copy_from_slice(bytes);
+            i64::from_be_bytes(buf)
+        }
+        Endian::Little => {
+            buf[..len].copy_from_slice(bytes);
+            i64::from_le_bytes(buf)
+        }
+    })
+}
+
+/// Write `value` as `width` bytes in the given byte order.
+///
+/// For widths below 8 the value must fit either as an unsigned or as a
+/// two's-complement signed integer of that width. Errors never include the value.
+fn int_to_bytes(value: i64, endian: Endian, width: i64) -> Result<Vec<u8>, String> {
+    if !(1..=8).contains(&width) {
+        return Err(format!(
+            "Byte width {} is out of range; use between 1 and 8",
+            width
+        ));
+    }
+    let width = width as usize;
+
+    if width < 8 {
+        let bits = 8 * width as u32;
+        let min = -(1i64 << (bits - 1));
+        let max = (1i64 << bits) - 1;
+        if value < min || value > max {
+            return Err(format!("Secret int does not fit in {} bytes", width));
+        }
+    }
+
+    Ok(match endian {
+        Endian::Big => value.to_be_bytes()[8 - width..].to_vec(),
+        Endian::Little => value.to_le_bytes()[..width].to_vec(),
+    })
+}
+
+impl PluginCommand for SecretBinaryToIntCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret binary to-int"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(
+                Type::Custom("secret_binary".into()),
+                Type::Custom("secret_int".into()),
+            )])
+            .named(
+                "endian",
+                SyntaxShape::String,
+                "Byte order of the input: be or le (default: be)",
+                Some('e'),
+            )
+            .switch(
+                "signed",
+                "Sign-extend inputs shorter than 8 bytes",
+                Some('s'),
+            )
+            .category(Category::Bytes)
+    }
+
+    fn description(&self) -> &str {
+        "Interpret up to 8 bytes of a secret binary as a secret int without revealing it"
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                example: r#"0x[01 bb] | secret wrap | secret binary to-int"#,
+                description: "Read a big-endian port number into a secret int",
+                result: None,
+            },
+            Example {
+                example: r#"0x[ff ff] | secret wrap | secret binary to-int --endian le --signed"#,
+                description: "Read a little-endian signed 16-bit value (-1)",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let endian = endian_flag(call)?;
+        let signed = call.has_flag("signed")?;
+
+        match input {
+            PipelineData::Value(Value::Custom { val, .. }, metadata) => {
+                let secret_binary =
+                    val.as_any().downcast_ref::<SecretBinary>().ok_or_else(|| {
+                        LabeledError::new("Unsupported secret type")
+                            .with_label("Only SecretBinary supports to-int", call.head)
+                    })?;
+
+                let int = bytes_to_int(&secret_binary.reveal(), endian, signed).map_err(|e| {
+                    LabeledError::new("Invalid byte length").with_label(e, call.head)
+                })?;
+                Ok(PipelineData::Value(
+                    Value::custom(Box::new(SecretInt::new(int)), call.head),
+                    metadata,
+                ))
+            }
+            PipelineData::Value(_, _) => Err(LabeledError::new("Invalid input").with_label(
+                "Input must be a secret binary. Use 'secret wrap' to create a secret first",
+                call.head,
+            )),
+            _ => Err(LabeledError::new("Invalid input")
+                .with_label("Expected a single secret value", call.head)),
+        }
+    }
+}
+
+impl PluginCommand for SecretIntToBinaryCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret int to-binary"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(
+                Type::Custom("secret_int".into()),
+                Type::Custom("secret_binary".into()),
+            )])
+            .named(
+                "endian",
+                SyntaxShape::String,
+                "Byte order of the output: be or le (default: be)",
+                Some('e'),
+            )
+            .named(
+                "bytes",
+                SyntaxShape::Int,
+                "Number of bytes to write, between 1 and 8 (default: 8)",
+                Some('b'),
+            )
+            .category(Category::Bytes)
+    }
+
+    fn description(&self) -> &str {
+        "Encode a secret int as a secret binary in a given byte order without revealing it"
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![Example {
+            example: r#"443 | secret wrap | secret int to-binary --endian be --bytes 2"#,
+            description: "Encode a port number as two big-endian bytes",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let endian = endian_flag(call)?;
+        let width = call.get_flag::<i64>("bytes")?.unwrap_or(8);
+
+        match input {
+            PipelineData::Value(Value::Custom { val, .. }, metadata) => {
+                let secret_int = val.as_any().downcast_ref::<SecretInt>().ok_or_else(|| {
+                    LabeledError::new("Unsupported secret type")
+                        .with_label("Only SecretInt supports to-binary", call.head)
+                })?;
+
+                let bytes = int_to_bytes(secret_int.reveal(), endian, width).map_err(|e| {
+                    LabeledError::new("Invalid byte width").with_label(e, call.head)
+                })?;
+                Ok(PipelineData::Value(
+                    Value::custom(Box::new(SecretBinary::new(bytes)), call.head),
+                    metadata,
+                ))
+            }
+            PipelineData::Value(_, _) => Err(LabeledError::new("Invalid input").with_label(
+                "Input must be a secret int. Use 'secret wrap' to create a secret first",
+                call.head,
+            )),
+            _ => Err(LabeledError::new("Invalid input")
+                .with_label("Expected a single secret value", call.head)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_command_names() {
+        assert_eq!(SecretBinaryToIntCommand.name(), "secret binary to-int");
+        assert_eq!(SecretIntToBinaryCommand.name(), "secret int to-binary");
+    }
+
+    #[test]
+    fn test_signatures() {
+        let signature = SecretBinaryToIntCommand.signature();
+        assert!(signature.get_long_flag("endian").is_some());
+        assert!(signature.get_long_flag("signed").is_some());
+
+        let signature = SecretIntToBinaryCommand.signature();
+        assert!(signature.get_long_flag("endian").is_some());
+        assert!(signature.get_long_flag("bytes").is_some());
+    }
+
+    #[test]
+    fn test_round_trip_big_endian() {
+        let bytes = int_to_bytes(0x0102_0304, Endian::Big, 4).unwrap();
+        assert_eq!(bytes, vec![0x01, 0x02, 0x03, 0x04]);
+        assert_eq!(
+            bytes_to_int(&bytes, Endian::Big, false).unwrap(),
+            0x0102_0304
+        );
+
+        let bytes = int_to_bytes(i64::MIN, Endian::Big, 8).unwrap();
+        assert_eq!(bytes_to_int(&bytes, Endian::Big, false).unwrap(), i64::MIN);
+    }
+
+    #[test]
+    fn test_round_trip_little_endian() {
+        let bytes = int_to_bytes(0x0102_0304, Endian::Little, 4).unwrap();
+        assert_eq!(bytes, vec![0x04, 0x03, 0x02, 0x01]);
+        assert_eq!(
+            bytes_to_int(&bytes, Endian::Little, false).unwrap(),
+            0x0102_0304
+        );
+
+        let bytes = int_to_bytes(-2, Endian::Little, 2).unwrap();
+        assert_eq!(bytes, vec![0xfe, 0xff]);
+        assert_eq!(bytes_to_int(&bytes, Endian::Little, true).unwrap(), -2);
+        assert_eq!(bytes_to_int(&bytes, Endian::Little, false).unwrap(), 0xfffe);
+    }
+
+    #[test]
+    fn test_too_many_bytes_is_rejected() {
+        let err = bytes_to_int(&[0u8; 9], Endian::Big, false).unwrap_err();
+        assert!(err.contains("9 bytes"));
+        assert!(bytes_to_int(&[], Endian::Little, false).is_err());
+    }
+
+    #[test]
+    fn test_int_that_does_not_fit_is_rejected() {
+        assert!(int_to_bytes(256, Endian::Big, 1).is_err());
+        assert!(int_to_bytes(-129, Endian::Big, 1).is_err());
+        assert!(int_to_bytes(255, Endian::Big, 1).is_ok());
+        assert!(int_to_bytes(1, Endian::Big, 0).is_err());
+        assert!(int_to_bytes(1, Endian::Big, 9).is_err());
+    }
+
+    #[test]
+    fn test_unknown_endianness() {
+        assert!("middle".parse::<Endian>().is_err());
+        assert_eq!("le".parse::<Endian>().unwrap(), Endian::Little);
+    }
+
+    #[test]
+    fn test_secret_types_round_trip() {
+        let span = Span::test_data();
+        let secret = SecretInt::new(8443);
+        let bytes = int_to_bytes(secret.reveal(), Endian::Big, 2).unwrap();
+        let binary = SecretBinary::new(bytes);
+        let value = Value::custom(Box::new(binary), span);
+        let binary = value
+            .as_custom_value()
+            .unwrap()
+            .as_any()
+            .downcast_ref::<SecretBinary>()
+            .unwrap();
+        assert_eq!(
+            bytes_to_int(&binary.reveal(), Endian::Big, false).unwrap(),
+            8443
+        );
+    }
+}
@@ -0,0 +1,139 @@
+//! Implements `secret binary reverse` — reverses the bytes of a secret binary.
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{Category, Example, LabeledError, PipelineData, Signature, Type, Value};
+
+use crate::SecretBinary;
+
+#[derive(Clone)]
+pub struct SecretBinaryReverseCommand;
+
+/// Reverse the bytes of a secret custom value, producing a new `SecretBinary`.
+fn reverse_secret_binary(
+    val: &dyn nu_protocol::CustomValue,
+    span: nu_protocol::Span,
+) -> Result<Value, LabeledError> {
+    if let Some(secret_binary) = val.as_any().downcast_ref::<SecretBinary>() {
+        let mut bytes = secret_binary.reveal().into_owned();
+        bytes.reverse();
+        Ok(Value::custom(
+            Box::new(secret_binary.with_value(bytes)),
+            span,
+        ))
+    } else {
+        Err(LabeledError::new("Unsupported secret type")
+            .with_label("Only SecretBinary supports byte reversal", span))
+    }
+}
+
+impl PluginCommand for SecretBinaryReverseCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret binary reverse"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(
+                Type::Custom("secret_binary".into()),
+                Type::Custom("secret_binary".into()),
+            )])
+            .category(Category::Bytes)
+    }
+
+    fn description(&self) -> &str {
+        "Reverse the byte order of a secret binary without revealing it"
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![Example {
+            example: r#"0x[01 02 03 04] | secret wrap | secret binary reverse"#,
+            description: "Swap the endianness of a secret 32-bit value",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        match input {
+            PipelineData::Value(value, metadata) => {
+                let result = match value {
+                    Value::Custom { val, .. } => reverse_secret_binary(val.as_ref(), call.head)?,
+                    _ => {
+                        return Err(LabeledError::new("Invalid input").with_label(
+                            "Input must be a secret binary. Use 'secret wrap' to create a secret first",
+                            call.head,
+                        ));
+                    }
+                };
+
+                Ok(PipelineData::Value(result, metadata))
+            }
+            _ => Err(LabeledError::new("Invalid input")
+                .with_label("Expected a single secret value", call.head)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SecretString;
+    use nu_protocol::Span;
+
+    fn reversed_bytes(value: &Value) -> Vec<u8> {
+        value
+            .as_custom_value()
+            .unwrap()
+            .as_any()
+            .downcast_ref::<SecretBinary>()
+            .unwrap()
+            .reveal()
+            .into_owned()
+    }
+
+    #[test]
+    fn test_command_name() {
+        let command = SecretBinaryReverseCommand;
+        assert_eq!(command.name(), "secret binary reverse");
+    }
+
+    #[test]
+    fn test_signature() {
+        let command = SecretBinaryReverseCommand;
+        let signature = command.signature();
+        assert_eq!(signature.name, "secret binary reverse");
+        assert_eq!(signature.input_output_types.len(), 1);
+    }
+
+    #[test]
+    fn test_reverse_known_bytes() {
+        let secret = SecretBinary::new(vec![0xde, 0xad, 0xbe, 0xef]);
+        let result = reverse_secret_binary(&secret, Span::test_data()).unwrap();
+
+        assert_eq!(reversed_bytes(&result), vec![0xef, 0xbe, 0xad, 0xde]);
+
+        let display = format!("{:?}", result);
+        assert!(display.contains("redacted"));
+        assert!(!display.contains("239"));
+    }
+
+    #[test]
+    fn test_reverse_empty_binary() {
+        let secret = SecretBinary::new(vec![]);
+        let result = reverse_secret_binary(&secret, Span::test_data()).unwrap();
+        assert!(reversed_bytes(&result).is_empty());
+    }
+
+    #[test]
+    fn test_reverse_rejects_non_binary_secret() {
+        let secret = SecretString::new("abc".to_string());
+        assert!(reverse_secret_binary(&secret, Span::test_data()).is_err());
+    }
+}
@@ -0,0 +1,136 @@
+//! Implements `secret binary to-string` — decodes a secret binary as UTF-8 into a
+//! secret string.
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{Category, Example, LabeledError, PipelineData, Signature, Type, Value};
+
+use crate::{SecretBinary, SecretString};
+
+#[derive(Clone)]
+pub struct SecretBinaryToStringCommand;
+
+/// Decode the bytes of a secret custom value as UTF-8, producing a new `SecretString`.
+///
+/// Invalid UTF-8 is rejected with the offset of the first bad byte only.
+pub(crate) fn secret_binary_to_string(
+    val: &dyn nu_protocol::CustomValue,
+    span: nu_protocol::Span,
+) -> Result<Value, LabeledError> {
+    if let Some(secret_binary) = val.as_any().downcast_ref::<SecretBinary>() {
+        let text = String::from_utf8(secret_binary.reveal().into_owned()).map_err(|e| {
+            LabeledError::new("Invalid UTF-8").with_label(
+                format!(
+                    "Secret binary is not valid UTF-8 (invalid sequence at byte {})",
+                    e.utf8_error().valid_up_to()
+                ),
+                span,
+            )
+        })?;
+        Ok(Value::custom(Box::new(SecretString::new(text)), span))
+    } else {
+        Err(LabeledError::new("Unsupported secret type")
+            .with_label("Only SecretBinary supports conversion to string", span))
+    }
+}
+
+impl PluginCommand for SecretBinaryToStringCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret binary to-string"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(
+                Type::Custom("secret_binary".into()),
+                Type::Custom("secret_string".into()),
+            )])
+            .category(Category::Bytes)
+    }
+
+    fn description(&self) -> &str {
+        "Decode a secret binary as UTF-8 into a secret string without revealing it"
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![Example {
+            example: r#"0x[68 75 6e 74 65 72 32] | secret wrap | secret binary to-string"#,
+            description: "Turn secret UTF-8 bytes back into a secret string",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        match input {
+            PipelineData::Value(value, metadata) => {
+                let result = match value {
+                    Value::Custom { val, .. } => secret_binary_to_string(val.as_ref(), call.head)?,
+                    _ => {
+                        return Err(LabeledError::new("Invalid input").with_label(
+                            "Input must be a secret binary. Use 'secret wrap' to create a secret first",
+                            call.head,
+                        ));
+                    }
+                };
+
+                Ok(PipelineData::Value(result, metadata))
+            }
+            _ => Err(LabeledError::new("Invalid input")
+                .with_label("Expected a single secret value", call.head)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nu_protocol::Span;
+
+    #[test]
+    fn test_command_name() {
+        let command = SecretBinaryToStringCommand;
+        assert_eq!(command.name(), "secret binary to-string");
+    }
+
+    #[test]
+    fn test_signature() {
+        let command = SecretBinaryToStringCommand;
+        let signature = command.signature();
+        assert_eq!(signature.name, "secret binary to-string");
+        assert_eq!(signature.input_output_types.len(), 1);
+    }
+
+    #[test]
+    fn test_decode_utf8() {
+        let secret = SecretBinary::new("héllo🔑".as_bytes().to_vec());
+        let result = secret_binary_to_string(&secret, Span::test_data()).unwrap();
+        let secret_string = result
+            .as_custom_value()
+            .unwrap()
+            .as_any()
+            .downcast_ref::<SecretString>()
+            .unwrap();
+        assert_eq!(secret_string.reveal(), "héllo🔑");
+    }
+
+    #[test]
+    fn test_invalid_utf8_rejected() {
+        let secret = SecretBinary::new(vec![0x61, 0x62, 0xff, 0xfe]);
+        let err = secret_binary_to_string(&secret, Span::test_data()).unwrap_err();
+        assert_eq!(err.msg, "Invalid UTF-8");
+        assert!(format!("{:?}", err).contains("byte 2"));
+    }
+
+    #[test]
+    fn test_rejects_non_binary_secret() {
+        let secret = SecretString::new("abc".to_string());
+        assert!(secret_binary_to_string(&secret, Span::test_data()).is_err());
+    }
+}
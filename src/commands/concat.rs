@@ -0,0 +1,214 @@
+//! Implements `secret concat` — appends a plain or secret string to a secret string.
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, Signature, Span, SyntaxShape, Type, Value,
+};
+
+use crate::SecretString;
+
+#[derive(Clone)]
+pub struct SecretConcatCommand;
+
+/// Borrow the text of the `other` argument, which may be a plain or secret string.
+fn other_text(other: &Value, span: Span) -> Result<&str, LabeledError> {
+    match other {
+        Value::String { val, .. } => Ok(val),
+        Value::Custom { val, .. } => val
+            .as_any()
+            .downcast_ref::<SecretString>()
+            .map(SecretString::reveal)
+            .ok_or_else(|| {
+                LabeledError::new("Type mismatch").with_label(
+                    format!(
+                        "Expected string or secret_string, found {}",
+                        val.type_name()
+                    ),
+                    span,
+                )
+            }),
+        _ => Err(LabeledError::new("Type mismatch").with_label(
+            format!(
+                "Expected string or secret_string, found {}",
+                other.get_type()
+            ),
+            span,
+        )),
+    }
+}
+
+/// Append `separator` and `other` to a secret string, producing a new `SecretString`.
+///
+/// The joined text is built directly in the new secret's buffer, so no partial
+/// concatenation exists outside a secret. The result keeps the input's template.
+fn concat_secret_string(
+    val: &dyn nu_protocol::CustomValue,
+    other: &str,
+    separator: &str,
+    span: Span,
+) -> Result<Value, LabeledError> {
+    if let Some(secret_string) = val.as_any().downcast_ref::<SecretString>() {
+        let inner = secret_string.reveal();
+        let mut joined = String::with_capacity(inner.len() + separator.len() + other.len());
+        joined.push_str(inner);
+        joined.push_str(separator);
+        joined.push_str(other);
+        Ok(Value::custom(
+            Box::new(secret_string.with_value(joined)),
+            span,
+        ))
+    } else {
+        Err(LabeledError::new("Unsupported secret type")
+            .with_label("Only SecretString supports concatenation", span))
+    }
+}
+
+impl PluginCommand for SecretConcatCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret concat"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(
+                Type::Custom("secret_string".into()),
+                Type::Custom("secret_string".into()),
+            )])
+            .required(
+                "other",
+                SyntaxShape::Any,
+                "String or secret string to append",
+            )
+            .named(
+                "separator",
+                SyntaxShape::String,
+                "Text inserted between the two strings",
+                Some('s'),
+            )
+            .category(Category::Strings)
+    }
+
+    fn description(&self) -> &str {
+        "Append a string or secret string to a secret string without revealing it"
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                example: r#""sk_live_" | secret wrap | secret concat $env.KEY_SUFFIX"#,
+                description: "Prefix a key with a plain string",
+                result: None,
+            },
+            Example {
+                example: r#"let password = ("hunter2" | secret wrap); "admin" | secret wrap | secret concat $password --separator ":""#,
+                description: "Build a secret user:password credential from two secrets",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let other: Value = call.req(0)?;
+        let other = other_text(&other, call.head)?;
+        let separator = call.get_flag::<String>("separator")?.unwrap_or_default();
+
+        match input {
+            PipelineData::Value(Value::Custom { val, .. }, metadata) => {
+                let result = concat_secret_string(val.as_ref(), other, &separator, call.head)?;
+                Ok(PipelineData::Value(result, metadata))
+            }
+            PipelineData::Value(_, _) => Err(LabeledError::new("Invalid input").with_label(
+                "Input must be a secret string. Use 'secret wrap' to create a secret first",
+                call.head,
+            )),
+            _ => Err(LabeledError::new("Invalid input")
+                .with_label("Expected a single secret value", call.head)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SecretInt;
+
+    fn revealed(value: &Value) -> String {
+        value
+            .as_custom_value()
+            .unwrap()
+            .as_any()
+            .downcast_ref::<SecretString>()
+            .unwrap()
+            .reveal()
+            .to_string()
+    }
+
+    #[test]
+    fn test_command_name() {
+        let command = SecretConcatCommand;
+        assert_eq!(command.name(), "secret concat");
+    }
+
+    #[test]
+    fn test_signature() {
+        let command = SecretConcatCommand;
+        let signature = command.signature();
+        assert_eq!(signature.name, "secret concat");
+        assert_eq!(signature.required_positional.len(), 1);
+        assert!(signature.get_long_flag("separator").is_some());
+    }
+
+    #[test]
+    fn test_concat_secret_and_plaintext() {
+        let span = Span::test_data();
+        let prefix = SecretString::new("sk_live_".to_string());
+        let other = Value::string("abc123", span);
+
+        let result =
+            concat_secret_string(&prefix, other_text(&other, span).unwrap(), "", span).unwrap();
+        assert_eq!(revealed(&result), "sk_live_abc123");
+
+        let display = format!("{:?}", result);
+        assert!(!display.contains("abc123"));
+        assert!(!display.contains("sk_live_"));
+    }
+
+    #[test]
+    fn test_concat_secret_and_secret_with_separator() {
+        let span = Span::test_data();
+        let user = SecretString::new("admin".to_string());
+        let password = Value::custom(Box::new(SecretString::new("hunter2".to_string())), span);
+
+        let result =
+            concat_secret_string(&user, other_text(&password, span).unwrap(), ":", span).unwrap();
+        assert_eq!(revealed(&result), "admin:hunter2");
+        assert!(!format!("{:?}", result).contains("hunter2"));
+    }
+
+    #[test]
+    fn test_concat_unicode() {
+        let span = Span::test_data();
+        let secret = SecretString::new("café".to_string());
+        let result = concat_secret_string(&secret, "世界🌍", " ", span).unwrap();
+        assert_eq!(revealed(&result), "café 世界🌍");
+    }
+
+    #[test]
+    fn test_concat_rejects_non_string() {
+        let span = Span::test_data();
+        assert!(other_text(&Value::int(1, span), span).is_err());
+
+        let secret_int = Value::custom(Box::new(SecretInt::new(1)), span);
+        assert!(other_text(&secret_int, span).is_err());
+
+        assert!(concat_secret_string(&SecretInt::new(1), "x", "", span).is_err());
+    }
+}
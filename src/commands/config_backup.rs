@@ -0,0 +1,153 @@
+//! Named configuration backup command for nu_plugin_secret
+
+use std::path::{Path, PathBuf};
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, Record, Signature, SyntaxShape, Type, Value,
+};
+
+use crate::config::{ConfigError, ConfigManager};
+
+/// Command to snapshot the active configuration under a name
+pub struct SecretConfigBackupCommand;
+
+/// Write the manager's configuration to `<backup_dir>/<name>.toml`.
+///
+/// Refuses to replace an existing backup unless `overwrite` is set.
+pub(crate) fn write_named_backup(
+    manager: &ConfigManager,
+    backup_dir: &Path,
+    name: &str,
+    overwrite: bool,
+) -> Result<PathBuf, ConfigError> {
+    let backup_path = crate::config::named_backup_path(backup_dir, name)?;
+
+    if backup_path.exists() && !overwrite {
+        return Err(ConfigError::Invalid(format!(
+            "Backup '{}' already exists. Use --overwrite to replace it",
+            name
+        )));
+    }
+
+    manager.save_to_path(&backup_path)?;
+    Ok(backup_path)
+}
+
+impl PluginCommand for SecretConfigBackupCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret config backup"
+    }
+
+    fn description(&self) -> &str {
+        "Snapshot the current secret plugin configuration to a named backup"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Record(Box::new([])))])
+            .required("name", SyntaxShape::String, "Name of the backup")
+            .switch(
+                "overwrite",
+                "Overwrite an existing backup with the same name",
+                Some('o'),
+            )
+            .category(Category::Custom("secret".into()))
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                example: "secret config backup before-upgrade",
+                description: "Save the current configuration as 'before-upgrade'",
+                result: None,
+            },
+            Example {
+                example: "secret config backup nightly --overwrite",
+                description: "Replace an existing backup named 'nightly'",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        _input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let span = call.head;
+        let name: String = call.req(0)?;
+
+        let backup_dir = crate::config::get_backup_dir().ok_or_else(|| {
+            LabeledError::new("Backup Failed")
+                .with_label("Cannot determine config directory for backup", span)
+        })?;
+
+        let config_manager = plugin.config_manager().read().map_err(|e| {
+            LabeledError::new("Configuration Error")
+                .with_label(format!("Failed to access configuration: {}", e), span)
+        })?;
+
+        let backup_path = write_named_backup(
+            &config_manager,
+            &backup_dir,
+            &name,
+            call.has_flag("overwrite")?,
+        )
+        .map_err(|e| {
+            LabeledError::new("Backup Failed")
+                .with_label(format!("Failed to create backup: {}", e), span)
+        })?;
+
+        let mut record = Record::new();
+        record.push("status", Value::string("Configuration backed up", span));
+        record.push("name", Value::string(name, span));
+        record.push(
+            "backup_path",
+            Value::string(backup_path.to_string_lossy().to_string(), span),
+        );
+
+        Ok(PipelineData::Value(Value::record(record, span), None))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::PluginConfig;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_command_name() {
+        let command = SecretConfigBackupCommand;
+        assert_eq!(command.name(), "secret config backup");
+    }
+
+    #[test]
+    fn test_signature() {
+        let command = SecretConfigBackupCommand;
+        let signature = command.signature();
+
+        assert_eq!(signature.name, "secret config backup");
+        assert_eq!(signature.required_positional[0].name, "name");
+        assert!(signature.get_long_flag("overwrite").is_some());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_write_named_backup_refuses_overwrite() {
+        let temp_dir = TempDir::new().unwrap();
+        let manager = ConfigManager::new_in_memory(PluginConfig::default());
+
+        let path = write_named_backup(&manager, temp_dir.path(), "snap", false).unwrap();
+        assert!(path.exists());
+        assert!(path.ends_with("snap.toml"));
+
+        assert!(write_named_backup(&manager, temp_dir.path(), "snap", false).is_err());
+        assert!(write_named_backup(&manager, temp_dir.path(), "snap", true).is_ok());
+    }
+}
@@ -0,0 +1,261 @@
+//! Conflicting settings check command for nu_plugin_secret
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, Record, Signature, Span, Type, Value,
+};
+
+use crate::config::{PluginConfig, SecurityLevel};
+
+/// Command to warn about settings that are valid but contradict each other
+pub struct SecretConfigCheckConflictsCommand;
+
+/// A pair of settings where one cancels out or defeats the other
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Conflict {
+    /// Dotted paths of the settings involved
+    settings: Vec<&'static str>,
+    /// Human-readable explanation
+    warning: String,
+}
+
+/// Names from `disabled` that `template` uses as a whole identifier.
+fn disabled_names_used<'a>(template: &str, disabled: &'a [String]) -> Vec<&'a str> {
+    let words: Vec<&str> = template
+        .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .collect();
+    disabled
+        .iter()
+        .map(String::as_str)
+        .filter(|name| words.contains(name))
+        .collect()
+}
+
+/// Collect every pair of settings in `config` that contradict each other.
+///
+/// None of these make the configuration invalid, so `secret config validate`
+/// accepts them; they explain why a setting appears to have no effect.
+fn find_conflicts(config: &PluginConfig) -> Vec<Conflict> {
+    let mut conflicts = Vec::new();
+    let redaction = &config.redaction;
+    let security = &config.security;
+
+    if redaction.show_unredacted {
+        if redaction.redaction_template.is_some() {
+            conflicts.push(Conflict {
+                settings: vec!["redaction.show_unredacted", "redaction.redaction_template"],
+                warning:
+                    "show_unredacted is enabled, so the custom redaction template is never applied"
+                        .to_string(),
+            });
+        }
+        if redaction.mask_secret {
+            conflicts.push(Conflict {
+                settings: vec!["redaction.show_unredacted", "redaction.mask_secret"],
+                warning: "show_unredacted is enabled, so secrets are never masked".to_string(),
+            });
+        }
+        if security.level == SecurityLevel::Paranoid {
+            conflicts.push(Conflict {
+                settings: vec!["redaction.show_unredacted", "security.level"],
+                warning: "show_unredacted disables redaction under the paranoid security level"
+                    .to_string(),
+            });
+        }
+    }
+
+    if let Some(template) = &redaction.redaction_template {
+        let used = disabled_names_used(template, &security.disabled_template_functions);
+        if !used.is_empty() {
+            conflicts.push(Conflict {
+                settings: vec![
+                    "redaction.redaction_template",
+                    "security.disabled_template_functions",
+                ],
+                warning: format!(
+                    "The redaction template uses disabled template functions ({}) and falls back to the default redaction",
+                    used.join(", ")
+                ),
+            });
+        }
+    }
+
+    if security.audit_log_path.is_some() && !security.audit_config_changes {
+        conflicts.push(Conflict {
+            settings: vec!["security.audit_log_path", "security.audit_config_changes"],
+            warning: "audit_config_changes is disabled, so nothing is written to audit_log_path"
+                .to_string(),
+        });
+    }
+
+    conflicts
+}
+
+/// Build one `{settings, warning}` row per conflict.
+fn conflict_rows(conflicts: &[Conflict], span: Span) -> Vec<Value> {
+    conflicts
+        .iter()
+        .map(|conflict| {
+            let mut record = Record::new();
+            record.push(
+                "settings",
+                Value::list(
+                    conflict
+                        .settings
+                        .iter()
+                        .map(|setting| Value::string(*setting, span))
+                        .collect(),
+                    span,
+                ),
+            );
+            record.push("warning", Value::string(&conflict.warning, span));
+            Value::record(record, span)
+        })
+        .collect()
+}
+
+impl PluginCommand for SecretConfigCheckConflictsCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret config check-conflicts"
+    }
+
+    fn description(&self) -> &str {
+        "Warn about configuration settings that are valid but contradict each other"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(
+                Type::Nothing,
+                Type::List(Box::new(Type::Record(Box::new([])))),
+            )])
+            .category(Category::Custom("secret".into()))
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![Example {
+            example: "secret config check-conflicts",
+            description: "List settings that cancel each other out",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        _input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let span = call.head;
+
+        let config_manager = plugin.config_manager().read().map_err(|e| {
+            LabeledError::new("Configuration Error")
+                .with_label(format!("Failed to access configuration: {}", e), span)
+        })?;
+
+        let rows = conflict_rows(&find_conflicts(config_manager.config()), span);
+        Ok(PipelineData::Value(Value::list(rows, span), None))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_command_name() {
+        let command = SecretConfigCheckConflictsCommand;
+        assert_eq!(command.name(), "secret config check-conflicts");
+    }
+
+    #[test]
+    fn test_signature() {
+        let command = SecretConfigCheckConflictsCommand;
+        let signature = command.signature();
+
+        assert_eq!(signature.name, "secret config check-conflicts");
+        assert!(signature.required_positional.is_empty());
+    }
+
+    #[test]
+    fn test_default_config_has_no_conflicts() {
+        assert!(find_conflicts(&PluginConfig::default()).is_empty());
+    }
+
+    #[test]
+    fn test_unredacted_with_template_warns() {
+        let mut config = PluginConfig::default();
+        config.redaction.show_unredacted = true;
+        config.redaction.redaction_template = Some("[HIDDEN]".to_string());
+
+        let conflicts = find_conflicts(&config);
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(
+            conflicts[0].settings,
+            vec!["redaction.show_unredacted", "redaction.redaction_template"]
+        );
+
+        let rows = conflict_rows(&conflicts, Span::test_data());
+        let row = rows[0].as_record().unwrap();
+        assert!(row
+            .get("warning")
+            .unwrap()
+            .as_str()
+            .unwrap()
+            .contains("never applied"));
+
+        config.redaction.show_unredacted = false;
+        assert!(find_conflicts(&config).is_empty());
+    }
+
+    #[test]
+    fn test_unredacted_with_mask_and_paranoid_warns() {
+        let mut config = PluginConfig::default();
+        config.redaction.show_unredacted = true;
+        config.redaction.mask_secret = true;
+        config.security.level = SecurityLevel::Paranoid;
+
+        let settings: Vec<Vec<&str>> = find_conflicts(&config)
+            .into_iter()
+            .map(|conflict| conflict.settings)
+            .collect();
+        assert_eq!(
+            settings,
+            vec![
+                vec!["redaction.show_unredacted", "redaction.mask_secret"],
+                vec!["redaction.show_unredacted", "security.level"],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_template_using_disabled_function_warns() {
+        let mut config = PluginConfig::default();
+        config.redaction.redaction_template = Some("{{ take(s=secret_string, n=2) }}".to_string());
+        config.security.disabled_template_functions = vec!["take".to_string()];
+
+        let conflicts = find_conflicts(&config);
+        assert_eq!(conflicts.len(), 1);
+        assert!(conflicts[0].warning.contains("(take)"));
+
+        config.redaction.redaction_template = Some("<retake:{{secret_type}}>".to_string());
+        assert!(find_conflicts(&config).is_empty());
+    }
+
+    #[test]
+    fn test_audit_log_path_without_auditing_warns() {
+        let mut config = PluginConfig::default();
+        config.security.audit_log_path = Some(PathBuf::from("/tmp/audit.log"));
+        assert!(find_conflicts(&config).is_empty());
+
+        config.security.audit_config_changes = false;
+        config.security.level = SecurityLevel::Minimal;
+        let conflicts = find_conflicts(&config);
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].settings[0], "security.audit_log_path");
+    }
+}
@@ -0,0 +1,223 @@
+//! Template variable availability check command for nu_plugin_secret
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, Record, Signature, Span, SyntaxShape, Type,
+    Value,
+};
+
+/// Command to report which secret types a redaction template falls back for
+pub struct SecretConfigCheckTemplateVarsCommand;
+
+/// Secret types and whether their custom template context includes `secret_length`
+/// (and with it `secret_char_length`).
+///
+/// Mirrors what each secret type passes when rendering its template: scalars and
+/// binaries provide a length, while list, record and range do not. Every type
+/// provides `secret_type` and `secret_string`.
+const TYPE_CONTEXTS: &[(&str, bool)] = &[
+    ("string", true),
+    ("int", true),
+    ("float", true),
+    ("bool", true),
+    ("date", true),
+    ("binary", true),
+    ("duration", true),
+    ("filesize", true),
+    ("list", false),
+    ("record", false),
+    ("range", false),
+];
+
+/// Sample value used for `secret_string` while checking templates
+const SAMPLE_VALUE: &str = "sample";
+
+/// Flatten a Tera error and its causes into one message.
+fn tera_error_message(error: &tera::Error) -> String {
+    let mut message = error.to_string();
+    let mut source = std::error::Error::source(error);
+    while let Some(cause) = source {
+        message.push_str(": ");
+        message.push_str(&cause.to_string());
+        source = cause.source();
+    }
+    message
+}
+
+/// Render `template` against each secret type's context and report fallbacks.
+///
+/// Returns one `{type, falls_back, reason}` row per secret type, or an error if
+/// the template does not compile at all.
+fn check_template_vars(template: &str, span: Span) -> Result<Vec<Value>, LabeledError> {
+    let mut tera = tera::Tera::default();
+    crate::tera_functions::register_all_standard_functions(&mut tera);
+    crate::redaction::register_reveal_chars_function(&mut tera, Some(SAMPLE_VALUE));
+    tera.add_raw_template("check", template).map_err(|e| {
+        LabeledError::new("Invalid template").with_label(tera_error_message(&e), span)
+    })?;
+
+    let rows = TYPE_CONTEXTS
+        .iter()
+        .map(|(secret_type, has_length)| {
+            let mut context = tera::Context::new();
+            context.insert("secret_type", secret_type);
+            context.insert("secret_string", SAMPLE_VALUE);
+            if *has_length {
+                context.insert("secret_length", &SAMPLE_VALUE.len());
+                context.insert("secret_char_length", &SAMPLE_VALUE.chars().count());
+            }
+
+            let outcome = tera.render("check", &context);
+
+            let mut row = Record::new();
+            row.push("type", Value::string(*secret_type, span));
+            row.push("falls_back", Value::bool(outcome.is_err(), span));
+            row.push(
+                "reason",
+                match outcome {
+                    Ok(_) => Value::nothing(span),
+                    Err(e) => Value::string(tera_error_message(&e), span),
+                },
+            );
+            Value::record(row, span)
+        })
+        .collect();
+
+    Ok(rows)
+}
+
+impl PluginCommand for SecretConfigCheckTemplateVarsCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret config check-template-vars"
+    }
+
+    fn description(&self) -> &str {
+        "Report for which secret types a redaction template would fall back to the default"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(
+                Type::Nothing,
+                Type::List(Box::new(Type::Record(Box::new([])))),
+            )])
+            .optional(
+                "template",
+                SyntaxShape::String,
+                "Template to check instead of the configured one",
+            )
+            .category(Category::Custom("secret".into()))
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                example: "secret config check-template-vars '{{secret_type}}:{{secret_length}}'",
+                description: "Find the secret types that provide no length to templates",
+                result: None,
+            },
+            Example {
+                example: "secret config check-template-vars | where falls_back",
+                description: "Check the configured redaction template",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        _input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let span = call.head;
+
+        let template = match call.opt::<String>(0)? {
+            Some(template) => template,
+            None => {
+                let config_manager = plugin.config_manager().read().map_err(|e| {
+                    LabeledError::new("Configuration Error")
+                        .with_label(format!("Failed to access configuration: {}", e), span)
+                })?;
+                config_manager
+                    .config()
+                    .redaction
+                    .get_redaction_template()
+                    .to_string()
+            }
+        };
+
+        let rows = check_template_vars(&template, span)?;
+        Ok(PipelineData::Value(Value::list(rows, span), None))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn falling_back(template: &str) -> Vec<String> {
+        check_template_vars(template, Span::test_data())
+            .unwrap()
+            .into_iter()
+            .map(|row| row.into_record().unwrap())
+            .filter(|row| row.get("falls_back").unwrap().as_bool().unwrap())
+            .map(|row| row.get("type").unwrap().as_str().unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_command_name() {
+        let command = SecretConfigCheckTemplateVarsCommand;
+        assert_eq!(command.name(), "secret config check-template-vars");
+    }
+
+    #[test]
+    fn test_signature() {
+        let command = SecretConfigCheckTemplateVarsCommand;
+        let signature = command.signature();
+
+        assert_eq!(signature.name, "secret config check-template-vars");
+        assert_eq!(signature.optional_positional.len(), 1);
+    }
+
+    #[test]
+    fn test_secret_length_flagged_for_types_without_length() {
+        assert_eq!(
+            falling_back("{{secret_type}}:{{secret_length}}"),
+            vec!["list", "record", "range"]
+        );
+    }
+
+    #[test]
+    fn test_reason_names_missing_variable() {
+        let rows = check_template_vars("{{replicate(s='*', n=secret_length)}}", Span::test_data())
+            .unwrap();
+        let list = rows
+            .into_iter()
+            .map(|row| row.into_record().unwrap())
+            .find(|row| row.get("type").unwrap().as_str().unwrap() == "list")
+            .unwrap();
+
+        assert!(list
+            .get("reason")
+            .unwrap()
+            .as_str()
+            .unwrap()
+            .contains("secret_length"));
+    }
+
+    #[test]
+    fn test_default_template_works_for_all_types() {
+        assert!(falling_back(crate::redaction::REDACTION_TEMPLATE).is_empty());
+    }
+
+    #[test]
+    fn test_invalid_template_rejected() {
+        let err = check_template_vars("{{ unclosed", Span::test_data()).unwrap_err();
+        assert_eq!(err.msg, "Invalid template");
+    }
+}
@@ -0,0 +1,249 @@
+//! Configuration file comparison command for nu_plugin_secret
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, Record, Signature, Span, SyntaxShape, Type,
+    Value,
+};
+use std::path::Path;
+
+use crate::config::{ConfigManager, PluginConfig};
+
+/// Command to compare two configuration files field by field
+pub struct SecretConfigDiffFilesCommand;
+
+/// Flatten a configuration into `(field, value)` pairs using dotted TOML paths.
+///
+/// Unset optional fields are reported as `nothing`.
+pub(crate) fn config_fields(config: &PluginConfig, span: Span) -> Vec<(&'static str, Value)> {
+    vec![
+        (
+            "redaction.show_unredacted",
+            Value::bool(config.redaction.show_unredacted, span),
+        ),
+        (
+            "redaction.mask_secret",
+            Value::bool(config.redaction.mask_secret, span),
+        ),
+        (
+            "redaction.redaction_template",
+            config
+                .redaction
+                .redaction_template
+                .as_ref()
+                .map(|template| Value::string(template.clone(), span))
+                .unwrap_or_else(|| Value::nothing(span)),
+        ),
+        (
+            "security.level",
+            Value::string(format!("{:?}", config.security.level).to_lowercase(), span),
+        ),
+        (
+            "security.minimum_level",
+            config
+                .security
+                .minimum_level
+                .as_ref()
+                .map(|level| Value::string(format!("{:?}", level).to_lowercase(), span))
+                .unwrap_or_else(|| Value::nothing(span)),
+        ),
+        (
+            "security.audit_config_changes",
+            Value::bool(config.security.audit_config_changes, span),
+        ),
+        (
+            "security.audit_mask_custom_text",
+            Value::bool(config.security.audit_mask_custom_text, span),
+        ),
+        (
+            "security.max_custom_text_length",
+            Value::int(config.security.max_custom_text_length as i64, span),
+        ),
+        (
+            "security.audit_format",
+            Value::string(
+                format!("{:?}", config.security.audit_format).to_lowercase(),
+                span,
+            ),
+        ),
+        (
+            "security.audit_log_path",
+            config
+                .security
+                .audit_log_path
+                .as_ref()
+                .map(|path| Value::string(path.display().to_string(), span))
+                .unwrap_or_else(|| Value::nothing(span)),
+        ),
+        (
+            "security.disabled_template_functions",
+            Value::list(
+                config
+                    .security
+                    .disabled_template_functions
+                    .iter()
+                    .map(|name| Value::string(name.clone(), span))
+                    .collect(),
+                span,
+            ),
+        ),
+        ("version", Value::string(config.version.clone(), span)),
+    ]
+}
+
+/// Build one `{field, a, b}` row for every field that differs between the configs.
+fn diff_configs(a: &PluginConfig, b: &PluginConfig, span: Span) -> Vec<Value> {
+    config_fields(a, span)
+        .into_iter()
+        .zip(config_fields(b, span))
+        .filter(|((_, a_value), (_, b_value))| a_value != b_value)
+        .map(|((field, a_value), (_, b_value))| {
+            let mut row = Record::new();
+            row.push("field", Value::string(field, span));
+            row.push("a", a_value);
+            row.push("b", b_value);
+            Value::record(row, span)
+        })
+        .collect()
+}
+
+/// Load and validate a configuration file, labelling errors with its path.
+fn load_config_file(path: &Path, span: Span) -> Result<PluginConfig, LabeledError> {
+    ConfigManager::load_from_path(path)
+        .map(|manager| manager.config().clone())
+        .map_err(|e| {
+            LabeledError::new("Failed to load configuration")
+                .with_label(format!("{}: {}", path.display(), e), span)
+        })
+}
+
+impl PluginCommand for SecretConfigDiffFilesCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret config diff-files"
+    }
+
+    fn description(&self) -> &str {
+        "Compare two configuration files and list the fields that differ"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(
+                Type::Nothing,
+                Type::List(Box::new(Type::Record(Box::new([])))),
+            )])
+            .required("a", SyntaxShape::Filepath, "First configuration file")
+            .required("b", SyntaxShape::Filepath, "Second configuration file")
+            .category(Category::Custom("secret".into()))
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![Example {
+            example: "secret config diff-files old.toml new.toml",
+            description: "Show which settings differ between two configuration files",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        _input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let span = call.head;
+
+        let a_path: String = call.req(0)?;
+        let b_path: String = call.req(1)?;
+
+        let a = load_config_file(Path::new(&a_path), span)?;
+        let b = load_config_file(Path::new(&b_path), span)?;
+
+        Ok(PipelineData::Value(
+            Value::list(diff_configs(&a, &b, span), span),
+            None,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::SecurityLevel;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_command_name() {
+        let command = SecretConfigDiffFilesCommand;
+        assert_eq!(command.name(), "secret config diff-files");
+    }
+
+    #[test]
+    fn test_signature() {
+        let command = SecretConfigDiffFilesCommand;
+        let signature = command.signature();
+
+        assert_eq!(signature.name, "secret config diff-files");
+        assert_eq!(signature.required_positional.len(), 2);
+    }
+
+    #[test]
+    fn test_diff_files_template_and_level() {
+        let temp_dir = TempDir::new().unwrap();
+        let a_path = temp_dir.path().join("a.toml");
+        let b_path = temp_dir.path().join("b.toml");
+
+        ConfigManager::new_in_memory(PluginConfig::default())
+            .save_to_path(&a_path)
+            .unwrap();
+
+        let mut b_config = PluginConfig::default();
+        b_config.redaction.redaction_template = Some("[HIDDEN:{{secret_type}}]".to_string());
+        b_config.security.level = SecurityLevel::Paranoid;
+        ConfigManager::new_in_memory(b_config)
+            .save_to_path(&b_path)
+            .unwrap();
+
+        let span = Span::test_data();
+        let a = load_config_file(&a_path, span).unwrap();
+        let b = load_config_file(&b_path, span).unwrap();
+        let rows = diff_configs(&a, &b, span);
+
+        assert_eq!(rows.len(), 2);
+
+        let template = rows[0].as_record().unwrap();
+        assert_eq!(
+            template.get("field").unwrap().as_str().unwrap(),
+            "redaction.redaction_template"
+        );
+        assert!(template.get("a").unwrap().is_nothing());
+        assert_eq!(
+            template.get("b").unwrap().as_str().unwrap(),
+            "[HIDDEN:{{secret_type}}]"
+        );
+
+        let level = rows[1].as_record().unwrap();
+        assert_eq!(
+            level.get("field").unwrap().as_str().unwrap(),
+            "security.level"
+        );
+        assert_eq!(level.get("a").unwrap().as_str().unwrap(), "standard");
+        assert_eq!(level.get("b").unwrap().as_str().unwrap(), "paranoid");
+    }
+
+    #[test]
+    fn test_diff_identical_configs_is_empty() {
+        let config = PluginConfig::default();
+        assert!(diff_configs(&config, &config, Span::test_data()).is_empty());
+    }
+
+    #[test]
+    fn test_load_missing_file_errors() {
+        let temp_dir = TempDir::new().unwrap();
+        let missing = temp_dir.path().join("missing.toml");
+        assert!(load_config_file(&missing, Span::test_data()).is_err());
+    }
+}
@@ -0,0 +1,268 @@
+//! Minimum security level enforcement command for nu_plugin_secret
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, Record, Signature, Span, SyntaxShape, Type,
+    Value,
+};
+
+use crate::config::{check_minimum_level_change, ConfigManager, PluginConfig, SecurityLevel};
+
+/// Command to set and report the lowest security level the configuration may use
+pub struct SecretConfigEnforceMinimumCommand;
+
+/// Return a copy of `config` with its minimum security level set to `level_str`.
+///
+/// Fails if the level is unknown, the current security level is already below
+/// the new minimum, or the new minimum is lower than the enforced one and
+/// `allow_lower_minimum` is not set.
+fn apply_minimum_level(
+    config: &PluginConfig,
+    level_str: &str,
+    allow_lower_minimum: bool,
+    span: Span,
+) -> Result<PluginConfig, LabeledError> {
+    let level = level_str
+        .parse::<SecurityLevel>()
+        .map_err(|e| LabeledError::new("Invalid Security Level").with_label(e, span))?;
+
+    let mut updated = config.clone();
+    updated.security.minimum_level = Some(level);
+
+    if !allow_lower_minimum {
+        check_minimum_level_change(config, &updated).map_err(|e| {
+            LabeledError::new("Minimum Level Protected").with_label(e.to_string(), span)
+        })?;
+    }
+
+    ConfigManager::validate_config(&updated).map_err(|e| {
+        LabeledError::new("Configuration Validation Failed")
+            .with_label(format!("Invalid configuration: {}", e), span)
+    })?;
+
+    Ok(updated)
+}
+
+/// Build the `{security_level, minimum_level, status}` record for `config`.
+fn build_minimum_record(config: &PluginConfig, status: &str, span: Span) -> Value {
+    let mut record = Record::new();
+    record.push(
+        "security_level",
+        Value::string(format!("{:?}", config.security.level).to_lowercase(), span),
+    );
+    record.push(
+        "minimum_level",
+        config
+            .security
+            .minimum_level
+            .as_ref()
+            .map(|level| Value::string(format!("{:?}", level).to_lowercase(), span))
+            .unwrap_or_else(|| Value::nothing(span)),
+    );
+    record.push("status", Value::string(status, span));
+
+    Value::record(record, span)
+}
+
+impl PluginCommand for SecretConfigEnforceMinimumCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret config enforce-minimum"
+    }
+
+    fn description(&self) -> &str {
+        "Set or report the lowest security level the configuration may be set to"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Record(Box::new([])))])
+            .optional(
+                "level",
+                SyntaxShape::String,
+                "Minimum security level to enforce (minimal, standard, paranoid)",
+            )
+            .switch(
+                "allow-lower-minimum",
+                "Allow lowering the enforced security.minimum_level",
+                None,
+            )
+            .category(Category::Custom("secret".into()))
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                example: "secret config enforce-minimum standard",
+                description: "Prevent the security level from being lowered below standard",
+                result: None,
+            },
+            Example {
+                example: "secret config enforce-minimum",
+                description: "Show the current security level and enforced minimum",
+                result: None,
+            },
+            Example {
+                example: "secret config enforce-minimum minimal --allow-lower-minimum",
+                description: "Deliberately lower the enforced minimum to minimal",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        _input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let span = call.head;
+
+        let Some(level_str) = call.opt::<String>(0)? else {
+            let config_manager = plugin.config_manager().read().map_err(|e| {
+                LabeledError::new("Configuration Error")
+                    .with_label(format!("Failed to access configuration: {}", e), span)
+            })?;
+            let status = if config_manager.config().security.minimum_level.is_some() {
+                "Minimum security level enforced"
+            } else {
+                "No minimum security level enforced"
+            };
+            return Ok(PipelineData::Value(
+                build_minimum_record(config_manager.config(), status, span),
+                None,
+            ));
+        };
+
+        let mut config_manager = plugin.config_manager().write().map_err(|e| {
+            LabeledError::new("Configuration Error")
+                .with_label(format!("Failed to acquire write lock: {}", e), span)
+        })?;
+
+        let config = apply_minimum_level(
+            config_manager.config(),
+            &level_str,
+            call.has_flag("allow-lower-minimum")?,
+            span,
+        )?;
+
+        if config_manager.config().security.audit_config_changes {
+            let _ = crate::config::audit_config_change(config_manager.config(), &config);
+        }
+
+        config_manager.replace_config(config);
+
+        config_manager.save().map_err(|e| {
+            LabeledError::new("Save Error")
+                .with_label(format!("Failed to save configuration: {}", e), span)
+        })?;
+
+        Ok(PipelineData::Value(
+            build_minimum_record(
+                config_manager.config(),
+                "Minimum security level updated",
+                span,
+            ),
+            None,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_command_name() {
+        let command = SecretConfigEnforceMinimumCommand;
+        assert_eq!(command.name(), "secret config enforce-minimum");
+    }
+
+    #[test]
+    fn test_signature() {
+        let command = SecretConfigEnforceMinimumCommand;
+        let signature = command.signature();
+
+        assert_eq!(signature.name, "secret config enforce-minimum");
+        assert_eq!(signature.optional_positional.len(), 1);
+        assert!(signature.get_long_flag("allow-lower-minimum").is_some());
+    }
+
+    #[test]
+    fn test_apply_minimum_level() {
+        let config = apply_minimum_level(
+            &PluginConfig::default(),
+            "standard",
+            false,
+            Span::test_data(),
+        )
+        .unwrap();
+        assert_eq!(config.security.minimum_level, Some(SecurityLevel::Standard));
+    }
+
+    #[test]
+    fn test_minimum_rejects_lower_level() {
+        let span = Span::test_data();
+        let mut config =
+            apply_minimum_level(&PluginConfig::default(), "standard", false, span).unwrap();
+
+        config.security.level = SecurityLevel::Minimal;
+        assert!(ConfigManager::validate_config(&config).is_err());
+
+        config.security.level = SecurityLevel::Paranoid;
+        assert!(ConfigManager::validate_config(&config).is_ok());
+    }
+
+    #[test]
+    fn test_minimum_above_current_level_is_rejected() {
+        let err = apply_minimum_level(
+            &PluginConfig::default(),
+            "paranoid",
+            false,
+            Span::test_data(),
+        )
+        .unwrap_err();
+        assert_eq!(err.msg, "Configuration Validation Failed");
+    }
+
+    #[test]
+    fn test_lowering_minimum_requires_override() {
+        let span = Span::test_data();
+        let mut start = PluginConfig::default();
+        start.security.minimum_level = Some(SecurityLevel::Standard);
+
+        let err = apply_minimum_level(&start, "minimal", false, span).unwrap_err();
+        assert_eq!(err.msg, "Minimum Level Protected");
+
+        let config = apply_minimum_level(&start, "minimal", true, span).unwrap();
+        assert_eq!(config.security.minimum_level, Some(SecurityLevel::Minimal));
+    }
+
+    #[test]
+    fn test_unknown_level_is_rejected() {
+        let err = apply_minimum_level(
+            &PluginConfig::default(),
+            "extreme",
+            false,
+            Span::test_data(),
+        )
+        .unwrap_err();
+        assert_eq!(err.msg, "Invalid Security Level");
+    }
+
+    #[test]
+    fn test_build_minimum_record() {
+        let span = Span::test_data();
+        let record = build_minimum_record(&PluginConfig::default(), "ok", span)
+            .into_record()
+            .unwrap();
+
+        assert_eq!(
+            record.get("security_level").unwrap().as_str().unwrap(),
+            "standard"
+        );
+        assert!(record.get("minimum_level").unwrap().is_nothing());
+    }
+}
@@ -7,11 +7,54 @@ use nu_protocol::{
     Category, Example, LabeledError, PipelineData, Record, Signature, SyntaxShape, Type, Value,
 };
 
-use crate::config::ConfigManager;
+use crate::config::{ConfigError, ConfigFormat, ConfigManager, PluginConfig};
 
 /// Command to export configuration to a file
 pub struct SecretConfigExportCommand;
 
+/// Remove every entry of `table` that equals its counterpart in `defaults`.
+///
+/// Sub-tables are compared field by field and dropped once empty.
+fn strip_defaults(table: &mut toml::Table, defaults: &toml::Table) {
+    let keys: Vec<String> = table.keys().cloned().collect();
+    for key in keys {
+        let keep = match (table.get_mut(&key), defaults.get(&key)) {
+            (Some(toml::Value::Table(sub)), Some(toml::Value::Table(default_sub))) => {
+                strip_defaults(sub, default_sub);
+                !sub.is_empty()
+            }
+            (Some(value), Some(default)) => value != default,
+            _ => true,
+        };
+        if !keep {
+            table.remove(&key);
+        }
+    }
+}
+
+/// Serialize only the fields of `config` that differ from `PluginConfig::default()`.
+///
+/// `version` is always kept so the exported file still carries its format version.
+fn minimal_config(config: &PluginConfig, format: ConfigFormat) -> Result<String, ConfigError> {
+    let to_table = |config: &PluginConfig| -> Result<toml::Table, ConfigError> {
+        match toml::Value::try_from(config)? {
+            toml::Value::Table(table) => Ok(table),
+            _ => Err(ConfigError::Invalid(
+                "Configuration did not serialize to a table".to_string(),
+            )),
+        }
+    };
+
+    let mut table = to_table(config)?;
+    strip_defaults(&mut table, &to_table(&PluginConfig::default())?);
+    table.insert(
+        "version".to_string(),
+        toml::Value::String(config.version.clone()),
+    );
+
+    format.serialize(&table)
+}
+
 /// Build the result record summarising a successful configuration export.
 fn build_export_result(
     manager: &ConfigManager,
@@ -75,6 +118,17 @@ impl PluginCommand for SecretConfigExportCommand {
                 "Overwrite existing file if it exists",
                 Some('o'),
             )
+            .named(
+                "format",
+                SyntaxShape::String,
+                "Output format: toml (default) or json",
+                Some('f'),
+            )
+            .switch(
+                "minimal",
+                "Only export settings that differ from the defaults",
+                Some('m'),
+            )
             .category(Category::Custom("secret".into()))
     }
 
@@ -90,6 +144,16 @@ impl PluginCommand for SecretConfigExportCommand {
                 description: "Export configuration, overwriting existing file",
                 result: None,
             },
+            Example {
+                example: "secret config export --minimal secret_config.toml",
+                description: "Export only explicitly changed settings, for version control",
+                result: None,
+            },
+            Example {
+                example: "secret config export --format json secret_config.json",
+                description: "Export configuration as JSON",
+                result: None,
+            },
         ]
     }
 
@@ -120,8 +184,26 @@ impl PluginCommand for SecretConfigExportCommand {
                 .with_label(format!("Failed to load configuration: {}", e), span)
         })?;
 
+        let format = match call.get_flag::<String>("format")? {
+            Some(format) => format
+                .parse::<ConfigFormat>()
+                .map_err(|e| LabeledError::new("Invalid Format").with_label(e, span))?,
+            None => ConfigFormat::Toml,
+        };
+
         // Export configuration to specified path
-        manager.save_to_path(&export_path).map_err(|e| {
+        let result = if call.has_flag("minimal")? {
+            minimal_config(manager.config(), format).and_then(|content| {
+                if let Some(parent) = export_path.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+                std::fs::write(&export_path, content)?;
+                Ok(())
+            })
+        } else {
+            manager.save_to_path_as(&export_path, format)
+        };
+        result.map_err(|e| {
             LabeledError::new("Export Failed")
                 .with_label(format!("Failed to export configuration: {}", e), span)
         })?;
@@ -150,5 +232,47 @@ mod tests {
         assert_eq!(signature.name, "secret config export");
         assert!(!signature.required_positional.is_empty());
         assert_eq!(signature.required_positional[0].name, "path");
+        assert!(signature.get_long_flag("minimal").is_some());
+        assert!(signature.get_long_flag("format").is_some());
+    }
+
+    #[test]
+    fn test_minimal_export_json() {
+        let mut config = PluginConfig::default();
+        config.security.max_custom_text_length = 64;
+
+        let content = minimal_config(&config, ConfigFormat::Json).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&content).unwrap();
+        assert_eq!(json["security"]["max_custom_text_length"], 64);
+        assert!(json.get("redaction").is_none());
+
+        assert_eq!(ConfigFormat::Json.parse(&content).unwrap(), config);
+    }
+
+    #[test]
+    fn test_minimal_export_of_default_config() {
+        let content = minimal_config(&PluginConfig::default(), ConfigFormat::Toml).unwrap();
+        assert_eq!(content.trim(), r#"version = "1.0""#);
+
+        let parsed: PluginConfig = toml::from_str(&content).unwrap();
+        assert_eq!(parsed, PluginConfig::default());
+    }
+
+    #[test]
+    fn test_minimal_export_single_changed_field() {
+        let mut config = PluginConfig::default();
+        config.redaction.redaction_template = Some("[HIDDEN]".to_string());
+
+        let content = minimal_config(&config, ConfigFormat::Toml).unwrap();
+        let table: toml::Table = toml::from_str(&content).unwrap();
+
+        assert_eq!(table.len(), 2);
+        let redaction = table["redaction"].as_table().unwrap();
+        assert_eq!(redaction.len(), 1);
+        assert_eq!(redaction["redaction_template"].as_str(), Some("[HIDDEN]"));
+        assert!(!table.contains_key("security"));
+
+        let parsed: PluginConfig = toml::from_str(&content).unwrap();
+        assert_eq!(parsed, config);
     }
 }
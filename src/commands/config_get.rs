@@ -0,0 +1,175 @@
+//! Single configuration value lookup command for nu_plugin_secret
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, Signature, Span, SyntaxShape, Type, Value,
+};
+
+use super::config_diff_files::config_fields;
+use crate::config::PluginConfig;
+
+/// Command to read one configuration value by its dotted key
+pub struct SecretConfigGetCommand;
+
+/// Resolve a dotted key such as `security.level` against `config`.
+///
+/// Unset optional fields resolve to `nothing`. Unknown keys produce an error
+/// listing every valid key.
+fn get_config_value(config: &PluginConfig, key: &str, span: Span) -> Result<Value, LabeledError> {
+    let fields = config_fields(config, span);
+    let valid_keys = fields
+        .iter()
+        .map(|(field, _)| *field)
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    fields
+        .into_iter()
+        .find(|(field, _)| *field == key)
+        .map(|(_, value)| value)
+        .ok_or_else(|| {
+            LabeledError::new("Unknown configuration key").with_label(
+                format!(
+                    "'{}' is not a configuration key. Valid keys: {}",
+                    key, valid_keys
+                ),
+                span,
+            )
+        })
+}
+
+impl PluginCommand for SecretConfigGetCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret config get"
+    }
+
+    fn description(&self) -> &str {
+        "Get a single configuration value by its dotted key"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Any)])
+            .required(
+                "key",
+                SyntaxShape::String,
+                "Dotted configuration key, e.g. security.level",
+            )
+            .category(Category::Custom("secret".into()))
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                example: "secret config get security.level",
+                description: "Get the configured security level",
+                result: None,
+            },
+            Example {
+                example: "secret config get redaction.mask_secret",
+                description: "Check whether secret strings are masked",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        _input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let span = call.head;
+        let key: String = call.req(0)?;
+
+        let config_manager = plugin.config_manager().read().map_err(|e| {
+            LabeledError::new("Configuration Error")
+                .with_label(format!("Failed to access configuration: {}", e), span)
+        })?;
+
+        let value = get_config_value(config_manager.config(), &key, span)?;
+        Ok(PipelineData::Value(value, None))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::SecurityLevel;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_command_name() {
+        let command = SecretConfigGetCommand;
+        assert_eq!(command.name(), "secret config get");
+    }
+
+    #[test]
+    fn test_signature() {
+        let command = SecretConfigGetCommand;
+        let signature = command.signature();
+
+        assert_eq!(signature.name, "secret config get");
+        assert_eq!(signature.required_positional.len(), 1);
+    }
+
+    #[test]
+    fn test_get_each_key() {
+        let span = Span::test_data();
+        let mut config = PluginConfig::default();
+        config.redaction.mask_secret = true;
+        config.redaction.redaction_template = Some("[HIDDEN]".to_string());
+        config.security.level = SecurityLevel::Paranoid;
+        config.security.max_custom_text_length = 64;
+        config.security.audit_log_path = Some(PathBuf::from("/tmp/audit.log"));
+        config.security.disabled_template_functions = vec!["take".to_string()];
+
+        let get = |key: &str| get_config_value(&config, key, span).unwrap();
+
+        assert!(!get("redaction.show_unredacted").as_bool().unwrap());
+        assert!(get("redaction.mask_secret").as_bool().unwrap());
+        assert_eq!(
+            get("redaction.redaction_template").as_str().unwrap(),
+            "[HIDDEN]"
+        );
+        assert_eq!(get("security.level").as_str().unwrap(), "paranoid");
+        assert!(get("security.audit_config_changes").as_bool().unwrap());
+        assert_eq!(get("security.max_custom_text_length").as_int().unwrap(), 64);
+        assert_eq!(
+            get("security.audit_log_path").as_str().unwrap(),
+            "/tmp/audit.log"
+        );
+        assert_eq!(
+            get("security.disabled_template_functions")
+                .as_list()
+                .unwrap()
+                .len(),
+            1
+        );
+        assert_eq!(get("version").as_str().unwrap(), config.version);
+    }
+
+    #[test]
+    fn test_get_unset_optional_is_nothing() {
+        let config = PluginConfig::default();
+        assert!(
+            get_config_value(&config, "redaction.redaction_template", Span::test_data())
+                .unwrap()
+                .is_nothing()
+        );
+    }
+
+    #[test]
+    fn test_get_unknown_key_lists_valid_keys() {
+        let config = PluginConfig::default();
+        let err = get_config_value(&config, "redaction.style", Span::test_data()).unwrap_err();
+
+        assert_eq!(err.msg, "Unknown configuration key");
+        let message = format!("{:?}", err);
+        assert!(message.contains("security.level"));
+        assert!(message.contains("redaction.mask_secret"));
+    }
+}
@@ -7,7 +7,7 @@ use nu_protocol::{
     Category, Example, LabeledError, PipelineData, Record, Signature, SyntaxShape, Type, Value,
 };
 
-use crate::config::ConfigManager;
+use crate::config::{check_minimum_level_change, ConfigFormat, ConfigManager, PluginConfig};
 
 /// Command to import configuration from a file
 pub struct SecretConfigImportCommand;
@@ -43,9 +43,25 @@ fn create_backup_before_import(span: nu_protocol::Span) -> Result<Option<String>
     }
 }
 
+/// Refuse an import that would lower or remove the enforced minimum security
+/// level of `current`, unless `allow_lower_minimum` is set.
+fn check_import_minimum(
+    current: &PluginConfig,
+    imported: &PluginConfig,
+    allow_lower_minimum: bool,
+    span: nu_protocol::Span,
+) -> Result<(), LabeledError> {
+    if allow_lower_minimum {
+        return Ok(());
+    }
+
+    check_minimum_level_change(current, imported)
+        .map_err(|e| LabeledError::new("Minimum Level Protected").with_label(e.to_string(), span))
+}
+
 /// Build the summary portion of the import result record, including config metadata.
 fn build_import_summary(
-    imported_config: &crate::config::PluginConfig,
+    imported_config: &PluginConfig,
     import_path: &std::path::Path,
     span: nu_protocol::Span,
 ) -> Record {
@@ -111,6 +127,17 @@ impl PluginCommand for SecretConfigImportCommand {
                 "Validate imported configuration before applying",
                 Some('v'),
             )
+            .switch(
+                "allow-lower-minimum",
+                "Allow the import to lower or remove the enforced security.minimum_level",
+                None,
+            )
+            .named(
+                "format",
+                SyntaxShape::String,
+                "Input format: toml or json. Detected from the file extension by default",
+                Some('f'),
+            )
             .category(Category::Custom("secret".into()))
     }
 
@@ -126,6 +153,11 @@ impl PluginCommand for SecretConfigImportCommand {
                 description: "Import configuration with backup and validation",
                 result: None,
             },
+            Example {
+                example: "secret config import secret_config.json",
+                description: "Import a JSON configuration export",
+                result: None,
+            },
         ]
     }
 
@@ -166,13 +198,21 @@ impl PluginCommand for SecretConfigImportCommand {
             }
         }
 
+        let format = match call.get_flag::<String>("format")? {
+            Some(format) => format
+                .parse::<ConfigFormat>()
+                .map_err(|e| LabeledError::new("Invalid Format").with_label(e, span))?,
+            None => ConfigFormat::from_path(&import_path),
+        };
+
         // Load configuration from import file
-        let imported_manager = ConfigManager::load_from_path(&import_path).map_err(|e| {
-            LabeledError::new("Import Failed").with_label(
-                format!("Failed to load configuration from import file: {}", e),
-                span,
-            )
-        })?;
+        let imported_manager =
+            ConfigManager::load_from_path_as(&import_path, format).map_err(|e| {
+                LabeledError::new("Import Failed").with_label(
+                    format!("Failed to load configuration from import file: {}", e),
+                    span,
+                )
+            })?;
 
         // Validate imported configuration if requested
         if call.has_flag("validate")? {
@@ -203,7 +243,14 @@ impl PluginCommand for SecretConfigImportCommand {
                     .with_label(format!("Failed to acquire write lock: {}", e), span)
             })?;
 
-            *config_manager.config_mut() = imported_manager.config().clone();
+            check_import_minimum(
+                config_manager.config(),
+                imported_manager.config(),
+                call.has_flag("allow-lower-minimum")?,
+                span,
+            )?;
+
+            config_manager.replace_config(imported_manager.config().clone());
 
             // Save to disk
             config_manager.save().map_err(|e| {
@@ -230,7 +277,7 @@ impl PluginCommand for SecretConfigImportCommand {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::{PluginConfig, RedactionConfig, SecurityConfig, SecurityLevel};
+    use crate::config::{AuditFormat, RedactionConfig, SecurityConfig, SecurityLevel};
     use std::fs;
     use tempfile::TempDir;
 
@@ -270,6 +317,68 @@ mod tests {
         let validate_flag = signature.get_long_flag("validate");
         assert!(validate_flag.is_some());
         assert_eq!(validate_flag.unwrap().short, Some('v'));
+
+        assert!(signature.get_long_flag("allow-lower-minimum").is_some());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_export_import_round_trip_both_formats() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let mut config = PluginConfig::default();
+        config.redaction.redaction_template = Some("[HIDDEN:{{secret_type}}]".to_string());
+        config.security.level = SecurityLevel::Paranoid;
+        config.security.max_custom_text_length = 30;
+        let manager = ConfigManager::new_in_memory(config.clone());
+
+        for (file_name, format) in [
+            ("export.toml", ConfigFormat::Toml),
+            ("export.json", ConfigFormat::Json),
+        ] {
+            let path = temp_dir.path().join(file_name);
+            manager.save_to_path_as(&path, format).unwrap();
+
+            assert_eq!(ConfigFormat::from_path(&path), format);
+            let imported = ConfigManager::load_from_path_as(&path, format).unwrap();
+            assert_eq!(imported.config(), &config);
+        }
+
+        // A JSON export is not valid TOML
+        let json_path = temp_dir.path().join("export.json");
+        assert!(ConfigManager::load_from_path_as(&json_path, ConfigFormat::Toml).is_err());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_import_cannot_lower_minimum_level() {
+        let temp_dir = TempDir::new().unwrap();
+        let span = nu_protocol::Span::test_data();
+
+        let mut current = PluginConfig::default();
+        current.security.minimum_level = Some(SecurityLevel::Standard);
+
+        let mut lowered = current.clone();
+        lowered.security.level = SecurityLevel::Minimal;
+        lowered.security.minimum_level = Some(SecurityLevel::Minimal);
+
+        let path = temp_dir.path().join("lowered.toml");
+        ConfigManager::new_in_memory(lowered.clone())
+            .save_to_path(&path)
+            .unwrap();
+        let imported = ConfigManager::load_from_path_as(&path, ConfigFormat::Toml).unwrap();
+
+        // The imported file is valid on its own, so only the floor check stops it
+        assert!(ConfigManager::validate_config(imported.config()).is_ok());
+        let err = check_import_minimum(&current, imported.config(), false, span).unwrap_err();
+        assert_eq!(err.msg, "Minimum Level Protected");
+
+        // Dropping the minimum altogether is refused as well
+        let mut removed = current.clone();
+        removed.security.minimum_level = None;
+        assert!(check_import_minimum(&current, &removed, false, span).is_err());
+
+        assert!(check_import_minimum(&current, imported.config(), true, span).is_ok());
     }
 
     #[test]
@@ -277,7 +386,7 @@ mod tests {
         let command = SecretConfigImportCommand;
         let examples = command.examples();
 
-        assert_eq!(examples.len(), 2);
+        assert_eq!(examples.len(), 3);
 
         // Check first example
         assert_eq!(
@@ -292,6 +401,12 @@ mod tests {
             "secret config import --backup --validate production_config.toml"
         );
         assert!(!examples[1].description.is_empty());
+
+        // Check third example
+        assert_eq!(
+            examples[2].example,
+            "secret config import secret_config.json"
+        );
     }
 
     #[test]
@@ -306,11 +421,17 @@ mod tests {
                 show_unredacted: false,
                 mask_secret: false,
                 redaction_template: Some("[HIDDEN:{{secret_type}}]".to_string()),
+                unredacted_types: Vec::new(),
             },
             security: SecurityConfig {
                 level: SecurityLevel::Paranoid,
+                minimum_level: None,
                 audit_config_changes: true, // Paranoid level requires audit logging
+                audit_mask_custom_text: true,
                 max_custom_text_length: 30,
+                audit_log_path: None,
+                audit_format: AuditFormat::Text,
+                disabled_template_functions: Vec::new(),
             },
             version: "1.0".to_string(),
         };
@@ -336,6 +457,23 @@ mod tests {
         assert!(!config.redaction.show_unredacted);
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_import_rejects_paranoid_unredacted_types() {
+        let temp_dir = TempDir::new().unwrap();
+        let import_path = temp_dir.path().join("paranoid.toml");
+
+        let mut test_config = PluginConfig::default();
+        test_config.security.level = SecurityLevel::Paranoid;
+        test_config.redaction.unredacted_types = vec!["int".to_string()];
+        fs::write(&import_path, toml::to_string_pretty(&test_config).unwrap()).unwrap();
+
+        let err = ConfigManager::load_from_path_as(&import_path, ConfigFormat::Toml).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("forbids displaying any secret type unredacted"));
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)]
     fn test_config_manager_load_nonexistent_file() {
@@ -455,6 +593,8 @@ mod tests {
         let config_with_max_length = PluginConfig {
             security: SecurityConfig {
                 max_custom_text_length: 100,
+                audit_log_path: None,
+                disabled_template_functions: Vec::new(),
                 ..Default::default()
             },
             ..Default::default()
@@ -0,0 +1,217 @@
+//! Annotated configuration file generation command for nu_plugin_secret
+
+use std::path::Path;
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{Category, Example, LabeledError, PipelineData, Record, Signature, Type, Value};
+
+use crate::config::{ConfigError, PluginConfig};
+
+/// Command to write a commented default configuration file
+pub struct SecretConfigInitCommand;
+
+/// Render the default configuration as TOML with comments documenting each field.
+///
+/// The `toml` crate cannot emit comments, so the file is assembled by hand from
+/// the values of `PluginConfig::default()`.
+fn annotated_default_config() -> String {
+    let config = PluginConfig::default();
+
+    format!(
+        r#"# nu_plugin_secret configuration
+#
+# Environment variables (NU_PLUGIN_SECRET_*) override the values in this file.
+
+# Configuration file format version
+version = "{version}"
+
+[redaction]
+# Disable redaction entirely and display secret values in plain text
+show_unredacted = {show_unredacted}
+
+# Mask secret strings with '*' characters when displaying them
+mask_secret = {mask_secret}
+
+# Custom Tera template used to render redacted values.
+# Available variables: secret_type, secret_length
+# Example: "[HIDDEN:{{{{secret_type}}}}]"
+# redaction_template = "<redacted:{{{{secret_type}}}}>"
+
+[security]
+# Validation strictness: "minimal", "standard" or "paranoid"
+level = "{level}"
+
+# Lowest security level that `level` may be set to
+# minimum_level = "standard"
+
+# Record configuration changes in the audit log.
+# Required at the standard and paranoid security levels.
+audit_config_changes = {audit_config_changes}
+
+# Record custom redaction text in the audit log as its length only
+audit_mask_custom_text = {audit_mask_custom_text}
+
+# Maximum length of custom redaction text
+max_custom_text_length = {max_custom_text_length}
+
+# Write the audit log to this file instead of audit.log in the config directory
+# audit_log_path = "/var/log/nu_plugin_secret/audit.log"
+
+# Audit log entry format: "text" (one line per change set) or "jsonl"
+# (one JSON object with timestamp, field, old and new per changed setting)
+audit_format = "{audit_format}"
+
+# Template functions that redaction templates may not use. Templates that use a
+# disabled function fall back to "<redacted:{{{{secret_type}}}}>".
+# Options: replicate, reverse, take, strlen, mask_partial, mask_middle, hash, secret_string
+# disabled_template_functions = ["secret_string", "take", "reverse"]
+"#,
+        version = config.version,
+        show_unredacted = config.redaction.show_unredacted,
+        mask_secret = config.redaction.mask_secret,
+        level = format!("{:?}", config.security.level).to_lowercase(),
+        audit_config_changes = config.security.audit_config_changes,
+        audit_mask_custom_text = config.security.audit_mask_custom_text,
+        max_custom_text_length = config.security.max_custom_text_length,
+        audit_format = format!("{:?}", config.security.audit_format).to_lowercase(),
+    )
+}
+
+/// Write the annotated default configuration to `path`.
+///
+/// Refuses to replace an existing file unless `force` is set.
+fn write_annotated_config(path: &Path, force: bool) -> Result<(), ConfigError> {
+    if path.exists() && !force {
+        return Err(ConfigError::Invalid(format!(
+            "Configuration file '{}' already exists. Use --force to overwrite it",
+            path.display()
+        )));
+    }
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, annotated_default_config())?;
+    Ok(())
+}
+
+impl PluginCommand for SecretConfigInitCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret config init"
+    }
+
+    fn description(&self) -> &str {
+        "Write a default configuration file with comments documenting each setting"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Record(Box::new([])))])
+            .switch(
+                "force",
+                "Overwrite an existing configuration file",
+                Some('f'),
+            )
+            .category(Category::Custom("secret".into()))
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                example: "secret config init",
+                description: "Create an annotated configuration file if none exists",
+                result: None,
+            },
+            Example {
+                example: "secret config init --force",
+                description: "Replace the configuration file with annotated defaults",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        _input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let span = call.head;
+
+        let config_path = crate::config::get_config_file_path().ok_or_else(|| {
+            LabeledError::new("Init Failed")
+                .with_label("Cannot determine configuration file path", span)
+        })?;
+
+        write_annotated_config(&config_path, call.has_flag("force")?).map_err(|e| {
+            LabeledError::new("Init Failed")
+                .with_label(format!("Failed to write configuration file: {}", e), span)
+        })?;
+
+        let mut record = Record::new();
+        record.push("status", Value::string("Configuration file created", span));
+        record.push(
+            "config_path",
+            Value::string(config_path.to_string_lossy().to_string(), span),
+        );
+
+        Ok(PipelineData::Value(Value::record(record, span), None))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ConfigManager;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_command_name() {
+        let command = SecretConfigInitCommand;
+        assert_eq!(command.name(), "secret config init");
+    }
+
+    #[test]
+    fn test_signature() {
+        let command = SecretConfigInitCommand;
+        let signature = command.signature();
+
+        assert_eq!(signature.name, "secret config init");
+        assert!(signature.get_long_flag("force").is_some());
+    }
+
+    #[test]
+    fn test_annotated_config_parses_to_default() {
+        let content = annotated_default_config();
+        let config: PluginConfig = toml::from_str(&content).expect("Should parse");
+
+        assert_eq!(config, PluginConfig::default());
+        assert!(content.lines().filter(|l| l.starts_with('#')).count() > 5);
+        assert!(content.contains("# redaction_template"));
+    }
+
+    #[test]
+    fn test_write_refuses_overwrite_without_force() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("secret").join("config.toml");
+
+        write_annotated_config(&path, false).unwrap();
+        let loaded = ConfigManager::load_from_path(&path).unwrap();
+        assert_eq!(loaded.config(), &PluginConfig::default());
+
+        std::fs::write(&path, "version = \"1.0\"\n").unwrap();
+        assert!(write_annotated_config(&path, false).is_err());
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "version = \"1.0\"\n"
+        );
+
+        write_annotated_config(&path, true).unwrap();
+        assert!(std::fs::read_to_string(&path)
+            .unwrap()
+            .contains("# Validation strictness"));
+    }
+}
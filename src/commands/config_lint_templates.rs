@@ -0,0 +1,197 @@
+//! Template performance lint command for nu_plugin_secret
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, Record, Signature, Span, SyntaxShape, Type,
+    Value,
+};
+
+/// Command to flag redaction templates whose output depends on the secret value
+pub struct SecretConfigLintTemplatesCommand;
+
+/// Template identifiers whose rendered output varies with the secret value.
+///
+/// A template using any of these must be re-rendered for every secret, so its
+/// output cannot be cached per secret type (see `redaction.rs`).
+const VALUE_DEPENDENT_TOKENS: &[&str] = &[
+    "secret_string",
+    "secret_length",
+    "secret_char_length",
+    "take",
+    "reverse",
+    "strlen",
+    "mask_partial",
+    "mask_middle",
+    "hash",
+    "reveal_chars",
+];
+
+/// Return the value-dependent identifiers used by `template`, in first-seen order.
+fn value_dependent_tokens(template: &str) -> Vec<&'static str> {
+    let mut found = Vec::new();
+    for word in template.split(|c: char| !(c.is_alphanumeric() || c == '_')) {
+        if let Some(token) = VALUE_DEPENDENT_TOKENS.iter().find(|t| **t == word) {
+            if !found.contains(token) {
+                found.push(*token);
+            }
+        }
+    }
+    found
+}
+
+/// Build the lint result for a single template.
+fn lint_template(source: &str, template: &str, span: Span) -> Record {
+    let tokens = value_dependent_tokens(template);
+    let value_dependent = !tokens.is_empty();
+
+    let mut record = Record::new();
+    record.push("source", Value::string(source, span));
+    record.push("template", Value::string(template, span));
+    record.push("value_dependent", Value::bool(value_dependent, span));
+    record.push(
+        "uses",
+        Value::list(
+            tokens
+                .iter()
+                .map(|token| Value::string(*token, span))
+                .collect(),
+            span,
+        ),
+    );
+    let suggestion = if value_dependent {
+        Value::string(
+            "Template output depends on the secret value and is rendered on every display; \
+             use only {{secret_type}} and static text (e.g. '<redacted:{{secret_type}}>') \
+             if display performance matters",
+            span,
+        )
+    } else {
+        Value::nothing(span)
+    };
+    record.push("suggestion", suggestion);
+
+    record
+}
+
+impl PluginCommand for SecretConfigLintTemplatesCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret config lint-templates"
+    }
+
+    fn description(&self) -> &str {
+        "Flag redaction templates that depend on the secret value and cannot be cached"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Record(Box::new([])))])
+            .optional(
+                "template",
+                SyntaxShape::String,
+                "Template to lint instead of the configured one",
+            )
+            .category(Category::Custom("secret".into()))
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                example: "secret config lint-templates",
+                description: "Lint the configured redaction template",
+                result: None,
+            },
+            Example {
+                example: "secret config lint-templates '{{ reverse(s=secret_string) }}'",
+                description: "Lint a template before configuring it",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        _input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let span = call.head;
+
+        let record = if let Some(template) = call.opt::<String>(0)? {
+            lint_template("argument", &template, span)
+        } else {
+            let config_manager = plugin.config_manager().read().map_err(|e| {
+                LabeledError::new("Configuration Error")
+                    .with_label(format!("Failed to access configuration: {}", e), span)
+            })?;
+
+            match &config_manager.config().redaction.redaction_template {
+                Some(template) => lint_template("config", template, span),
+                None => lint_template("default", crate::redaction::REDACTION_TEMPLATE, span),
+            }
+        };
+
+        Ok(PipelineData::Value(Value::record(record, span), None))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_command_name() {
+        let command = SecretConfigLintTemplatesCommand;
+        assert_eq!(command.name(), "secret config lint-templates");
+    }
+
+    #[test]
+    fn test_signature() {
+        let command = SecretConfigLintTemplatesCommand;
+        let signature = command.signature();
+
+        assert_eq!(signature.name, "secret config lint-templates");
+        assert_eq!(signature.optional_positional[0].name, "template");
+    }
+
+    #[test]
+    fn test_lint_flags_value_dependent_template() {
+        let record = lint_template(
+            "argument",
+            "{{ take(s=secret_string, n=2) }}***",
+            Span::test_data(),
+        );
+
+        assert!(record.get("value_dependent").unwrap().as_bool().unwrap());
+        let uses: Vec<&str> = record
+            .get("uses")
+            .unwrap()
+            .as_list()
+            .unwrap()
+            .iter()
+            .map(|v| v.as_str().unwrap())
+            .collect();
+        assert_eq!(uses, vec!["take", "secret_string"]);
+        assert!(!record.get("suggestion").unwrap().is_nothing());
+    }
+
+    #[test]
+    fn test_lint_passes_static_template() {
+        let record = lint_template(
+            "default",
+            crate::redaction::REDACTION_TEMPLATE,
+            Span::test_data(),
+        );
+
+        assert!(!record.get("value_dependent").unwrap().as_bool().unwrap());
+        assert!(record.get("uses").unwrap().as_list().unwrap().is_empty());
+        assert!(record.get("suggestion").unwrap().is_nothing());
+    }
+
+    #[test]
+    fn test_value_dependent_tokens_ignores_substrings() {
+        assert!(value_dependent_tokens("<hidden:{{secret_type}} retake>").is_empty());
+    }
+}
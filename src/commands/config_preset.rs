@@ -0,0 +1,271 @@
+//! Redaction profile preset command for nu_plugin_secret
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, Record, Signature, Span, SyntaxShape, Type,
+    Value,
+};
+
+use crate::config::{ConfigManager, PluginConfig, SecurityLevel};
+
+/// Command to apply a bundle of redaction and security settings at once
+pub struct SecretConfigPresetCommand;
+
+/// A named bundle of redaction and security settings
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ConfigPreset {
+    /// Minimal security, redaction shows the secret's type and length
+    Minimal,
+    /// Standard security with the default typed redaction
+    Balanced,
+    /// Paranoid security, redaction reveals neither type nor length
+    Strict,
+}
+
+impl ConfigPreset {
+    /// Apply this preset's settings to `config`, leaving unrelated settings untouched.
+    fn apply(&self, config: &mut PluginConfig) {
+        config.redaction.show_unredacted = false;
+        config.security.audit_config_changes = true;
+
+        match self {
+            ConfigPreset::Minimal => {
+                config.security.level = SecurityLevel::Minimal;
+                config.redaction.redaction_template =
+                    Some("<redacted:{{secret_type}}:{{secret_length}}>".to_string());
+            }
+            ConfigPreset::Balanced => {
+                config.security.level = SecurityLevel::Standard;
+                config.redaction.redaction_template = None;
+            }
+            ConfigPreset::Strict => {
+                config.security.level = SecurityLevel::Paranoid;
+                config.security.audit_mask_custom_text = true;
+                config.redaction.redaction_template = Some("<redacted>".to_string());
+                config.redaction.unredacted_types.clear();
+            }
+        }
+    }
+}
+
+impl std::str::FromStr for ConfigPreset {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "minimal" => Ok(ConfigPreset::Minimal),
+            "balanced" => Ok(ConfigPreset::Balanced),
+            "strict" => Ok(ConfigPreset::Strict),
+            _ => Err(format!(
+                "Unknown preset '{}'. Valid options: minimal, balanced, strict",
+                s
+            )),
+        }
+    }
+}
+
+/// Return a copy of `config` with the preset named `preset_str` applied.
+///
+/// Fails if the preset is unknown or the result does not validate (e.g. the
+/// preset's level is below `security.minimum_level`).
+fn apply_preset(
+    config: &PluginConfig,
+    preset_str: &str,
+    span: Span,
+) -> Result<PluginConfig, LabeledError> {
+    let preset = preset_str
+        .parse::<ConfigPreset>()
+        .map_err(|e| LabeledError::new("Invalid Preset").with_label(e, span))?;
+
+    let mut config = config.clone();
+    preset.apply(&mut config);
+
+    ConfigManager::validate_config(&config).map_err(|e| {
+        LabeledError::new("Configuration Validation Failed")
+            .with_label(format!("Invalid configuration: {}", e), span)
+    })?;
+
+    Ok(config)
+}
+
+/// Build the `{preset, security_level, redaction_template, status}` record.
+fn build_preset_record(config: &PluginConfig, preset: &str, span: Span) -> Value {
+    let mut record = Record::new();
+    record.push("preset", Value::string(preset, span));
+    record.push(
+        "security_level",
+        Value::string(format!("{:?}", config.security.level).to_lowercase(), span),
+    );
+    record.push(
+        "redaction_template",
+        Value::string(config.redaction.get_redaction_template(), span),
+    );
+    record.push("status", Value::string("Preset applied", span));
+
+    Value::record(record, span)
+}
+
+impl PluginCommand for SecretConfigPresetCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret config preset"
+    }
+
+    fn description(&self) -> &str {
+        "Apply a preset bundle of redaction and security settings"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Record(Box::new([])))])
+            .required(
+                "preset",
+                SyntaxShape::String,
+                "Preset to apply (minimal, balanced, strict)",
+            )
+            .category(Category::Custom("secret".into()))
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                example: "secret config preset strict",
+                description: "Use paranoid security and hide the type and length of secrets",
+                result: None,
+            },
+            Example {
+                example: "secret config preset balanced",
+                description: "Return to standard security and the default redaction",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        _input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let span = call.head;
+        let preset: String = call.req(0)?;
+
+        let mut config_manager = plugin.config_manager().write().map_err(|e| {
+            LabeledError::new("Configuration Error")
+                .with_label(format!("Failed to acquire write lock: {}", e), span)
+        })?;
+
+        let config = apply_preset(config_manager.config(), &preset, span)?;
+
+        if config_manager.config().security.audit_config_changes {
+            let _ = crate::config::audit_config_change(config_manager.config(), &config);
+        }
+
+        config_manager.replace_config(config);
+
+        config_manager.save().map_err(|e| {
+            LabeledError::new("Save Error")
+                .with_label(format!("Failed to save configuration: {}", e), span)
+        })?;
+
+        Ok(PipelineData::Value(
+            build_preset_record(config_manager.config(), &preset, span),
+            None,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::security_level_violations;
+
+    #[test]
+    fn test_command_name() {
+        let command = SecretConfigPresetCommand;
+        assert_eq!(command.name(), "secret config preset");
+    }
+
+    #[test]
+    fn test_signature() {
+        let command = SecretConfigPresetCommand;
+        let signature = command.signature();
+
+        assert_eq!(signature.name, "secret config preset");
+        assert_eq!(signature.required_positional.len(), 1);
+    }
+
+    #[test]
+    fn test_minimal_preset() {
+        let config = apply_preset(&PluginConfig::default(), "minimal", Span::test_data()).unwrap();
+        assert_eq!(config.security.level, SecurityLevel::Minimal);
+        assert_eq!(
+            config.redaction.get_redaction_template(),
+            "<redacted:{{secret_type}}:{{secret_length}}>"
+        );
+        assert!(!config.redaction.show_unredacted);
+    }
+
+    #[test]
+    fn test_balanced_preset() {
+        let mut start = PluginConfig::default();
+        start.redaction.redaction_template = Some("[HIDDEN]".to_string());
+
+        let config = apply_preset(&start, "balanced", Span::test_data()).unwrap();
+        assert_eq!(config.security.level, SecurityLevel::Standard);
+        assert!(config.redaction.redaction_template.is_none());
+        assert!(config.security.audit_config_changes);
+    }
+
+    #[test]
+    fn test_strict_preset_passes_paranoid_validation() {
+        let mut start = PluginConfig::default();
+        start.security.level = SecurityLevel::Minimal;
+        start.security.audit_config_changes = false;
+        start.redaction.show_unredacted = true;
+        start.redaction.unredacted_types = vec!["int".to_string(), "string".to_string()];
+
+        let config = apply_preset(&start, "strict", Span::test_data()).unwrap();
+        assert_eq!(config.security.level, SecurityLevel::Paranoid);
+        assert_eq!(config.redaction.get_redaction_template(), "<redacted>");
+        assert!(!config.redaction.show_unredacted);
+        assert!(config.redaction.unredacted_types.is_empty());
+        assert!(config.security.audit_config_changes);
+        assert!(config.security.audit_mask_custom_text);
+        assert!(security_level_violations(&config).is_empty());
+        assert!(ConfigManager::validate_config(&config).is_ok());
+    }
+
+    #[test]
+    fn test_presets_change_rendered_redaction() {
+        for (preset, expected) in [
+            ("minimal", "<redacted:string:8>"),
+            ("balanced", "<redacted:string>"),
+            ("strict", "<redacted>"),
+        ] {
+            let config = apply_preset(&PluginConfig::default(), preset, Span::test_data()).unwrap();
+            let rendered = crate::redaction::with_redaction_template(
+                config.redaction.get_redaction_template(),
+                || crate::SecretString::new("hunter22".to_string()).to_string(),
+            );
+            assert_eq!(rendered, expected, "preset {}", preset);
+        }
+    }
+
+    #[test]
+    fn test_preset_below_minimum_level_is_rejected() {
+        let mut start = PluginConfig::default();
+        start.security.minimum_level = Some(SecurityLevel::Standard);
+
+        let err = apply_preset(&start, "minimal", Span::test_data()).unwrap_err();
+        assert_eq!(err.msg, "Configuration Validation Failed");
+    }
+
+    #[test]
+    fn test_unknown_preset_is_rejected() {
+        let err = apply_preset(&PluginConfig::default(), "extreme", Span::test_data()).unwrap_err();
+        assert_eq!(err.msg, "Invalid Preset");
+    }
+}
@@ -0,0 +1,184 @@
+//! Security level preview command for nu_plugin_secret
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, Record, Signature, SyntaxShape, Type, Value,
+};
+
+use crate::config::{PluginConfig, SecurityLevel};
+
+/// Command to preview the impact of a security level change without applying it
+pub struct SecretConfigPreviewLevelCommand;
+
+/// Build the preview record describing what switching `config` to `level` would do.
+///
+/// The live configuration is never modified; the level is applied to a clone and
+/// every resulting security-level violation is reported as a required change.
+fn build_level_preview(
+    config: &PluginConfig,
+    level: SecurityLevel,
+    span: nu_protocol::Span,
+) -> Record {
+    let mut proposed = config.clone();
+    proposed.security.level = level;
+
+    let changes = crate::config::collect_config_changes(config, &proposed);
+    let violations = crate::config::security_level_violations(&proposed);
+
+    let mut record = Record::new();
+    record.push(
+        "current_level",
+        Value::string(format!("{:?}", config.security.level).to_lowercase(), span),
+    );
+    record.push(
+        "proposed_level",
+        Value::string(
+            format!("{:?}", proposed.security.level).to_lowercase(),
+            span,
+        ),
+    );
+    record.push(
+        "changes",
+        Value::list(
+            changes
+                .into_iter()
+                .map(|change| Value::string(change, span))
+                .collect(),
+            span,
+        ),
+    );
+
+    let required_changes = violations
+        .iter()
+        .map(|violation| {
+            let mut entry = Record::new();
+            entry.push("field", Value::string(violation.field, span));
+            entry.push("current", Value::string(violation.current.clone(), span));
+            entry.push("required", Value::string(violation.required.clone(), span));
+            entry.push("reason", Value::string(violation.reason.clone(), span));
+            Value::record(entry, span)
+        })
+        .collect();
+    record.push("required_changes", Value::list(required_changes, span));
+    record.push("can_apply", Value::bool(violations.is_empty(), span));
+
+    record
+}
+
+impl PluginCommand for SecretConfigPreviewLevelCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret config preview-level"
+    }
+
+    fn description(&self) -> &str {
+        "Preview what changing the security level would require, without applying it"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Record(Box::new([])))])
+            .required(
+                "level",
+                SyntaxShape::String,
+                "Security level to preview (minimal, standard, paranoid)",
+            )
+            .category(Category::Custom("secret".into()))
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![Example {
+            example: "secret config preview-level paranoid",
+            description: "Show which settings would have to change to move to paranoid",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        _input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let span = call.head;
+
+        let level_str: String = call.req(0)?;
+        let level = level_str
+            .parse::<SecurityLevel>()
+            .map_err(|e| LabeledError::new("Invalid Security Level").with_label(e, span))?;
+
+        let config_manager = plugin.config_manager().read().map_err(|e| {
+            LabeledError::new("Configuration Error")
+                .with_label(format!("Failed to access configuration: {}", e), span)
+        })?;
+
+        let record = build_level_preview(config_manager.config(), level, span);
+
+        Ok(PipelineData::Value(Value::record(record, span), None))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nu_protocol::Span;
+
+    #[test]
+    fn test_command_name() {
+        let command = SecretConfigPreviewLevelCommand;
+        assert_eq!(command.name(), "secret config preview-level");
+    }
+
+    #[test]
+    fn test_signature() {
+        let command = SecretConfigPreviewLevelCommand;
+        let signature = command.signature();
+
+        assert_eq!(signature.name, "secret config preview-level");
+        assert_eq!(signature.required_positional[0].name, "level");
+    }
+
+    #[test]
+    fn test_preview_paranoid_lists_required_change() {
+        let mut config = PluginConfig::default();
+        config.security.level = SecurityLevel::Minimal;
+        config.security.audit_config_changes = false;
+
+        let record = build_level_preview(&config, SecurityLevel::Paranoid, Span::test_data());
+
+        assert_eq!(
+            record.get("proposed_level").unwrap().as_str().unwrap(),
+            "paranoid"
+        );
+        assert!(!record.get("can_apply").unwrap().as_bool().unwrap());
+
+        let required = record.get("required_changes").unwrap().as_list().unwrap();
+        assert_eq!(required.len(), 1);
+        let entry = required[0].as_record().unwrap();
+        assert_eq!(
+            entry.get("field").unwrap().as_str().unwrap(),
+            "security.audit_config_changes"
+        );
+        assert_eq!(entry.get("required").unwrap().as_str().unwrap(), "true");
+    }
+
+    #[test]
+    fn test_preview_does_not_modify_config() {
+        let config = PluginConfig::default();
+        let record = build_level_preview(&config, SecurityLevel::Paranoid, Span::test_data());
+
+        assert!(record.get("can_apply").unwrap().as_bool().unwrap());
+        assert_eq!(record.get("changes").unwrap().as_list().unwrap().len(), 1);
+        assert_eq!(config.security.level, SecurityLevel::Standard);
+    }
+
+    #[test]
+    fn test_preview_same_level_has_no_changes() {
+        let config = PluginConfig::default();
+        let record = build_level_preview(&config, SecurityLevel::Standard, Span::test_data());
+
+        assert!(record.get("changes").unwrap().as_list().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,305 @@
+//! Redaction report card command for nu_plugin_secret
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, Record, Signature, Span, Type, Value,
+};
+
+use crate::config::{security_level_violations, PluginConfig, SecurityLevel};
+
+/// Command to summarize how the current configuration handles secrets
+pub struct SecretConfigReportCommand;
+
+/// Overall risk of the configuration disclosing secrets
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum RiskRating {
+    Low,
+    Medium,
+    High,
+}
+
+impl RiskRating {
+    fn as_str(&self) -> &'static str {
+        match self {
+            RiskRating::Low => "low",
+            RiskRating::Medium => "medium",
+            RiskRating::High => "high",
+        }
+    }
+}
+
+/// Whether `template` uses `name` as a whole identifier.
+fn template_uses(template: &str, name: &str) -> bool {
+    template
+        .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .any(|word| word == name)
+}
+
+/// Rate the configuration and explain each finding that raised the rating.
+///
+/// Anything that can put secret content on screen (unredacted mode or a
+/// template reading `secret_string`) is high risk. Leaking the length,
+/// disabled auditing, the minimal level or level violations are medium risk.
+fn assess_risk(config: &PluginConfig) -> (RiskRating, Vec<String>) {
+    let redaction = &config.redaction;
+    let security = &config.security;
+    let template = redaction.get_redaction_template();
+    let mut findings = Vec::new();
+
+    if redaction.show_unredacted {
+        findings.push((
+            RiskRating::High,
+            "show_unredacted is enabled, so secrets are displayed in plain text".to_string(),
+        ));
+    }
+    if template_uses(template, "secret_string") {
+        findings.push((
+            RiskRating::High,
+            "The redaction template references secret_string and can reveal secret content"
+                .to_string(),
+        ));
+    }
+    if template_uses(template, "secret_length")
+        || template_uses(template, "secret_char_length")
+        || template_uses(template, "strlen")
+        || template_uses(template, "reveal_chars")
+    {
+        findings.push((
+            RiskRating::Medium,
+            "The redaction template reveals the length of secrets".to_string(),
+        ));
+    }
+    if !security.audit_config_changes {
+        findings.push((
+            RiskRating::Medium,
+            "Configuration changes are not audited".to_string(),
+        ));
+    }
+    if security.level == SecurityLevel::Minimal {
+        findings.push((
+            RiskRating::Medium,
+            "The minimal security level applies the fewest restrictions".to_string(),
+        ));
+    }
+    for violation in security_level_violations(config) {
+        findings.push((RiskRating::Medium, violation.reason));
+    }
+
+    let rating = findings
+        .iter()
+        .map(|(rating, _)| *rating)
+        .max()
+        .unwrap_or(RiskRating::Low);
+    let reasons = findings.into_iter().map(|(_, reason)| reason).collect();
+
+    (rating, reasons)
+}
+
+/// Build the report card record for `config`.
+fn build_report(config: &PluginConfig, span: Span) -> Value {
+    let redaction = &config.redaction;
+    let security = &config.security;
+    let template = redaction.get_redaction_template();
+    let (rating, reasons) = assess_risk(config);
+
+    let redaction_style = if redaction.show_unredacted {
+        "unredacted"
+    } else if redaction.redaction_template.is_some() {
+        "custom_template"
+    } else {
+        "default_template"
+    };
+
+    let mut record = Record::new();
+    record.push(
+        "security_level",
+        Value::string(format!("{:?}", security.level).to_lowercase(), span),
+    );
+    record.push("redaction_style", Value::string(redaction_style, span));
+    record.push(
+        "show_unredacted",
+        Value::bool(redaction.show_unredacted, span),
+    );
+    record.push(
+        "leaks_type",
+        Value::bool(template_uses(template, "secret_type"), span),
+    );
+    record.push(
+        "leaks_length",
+        Value::bool(
+            template_uses(template, "secret_length")
+                || template_uses(template, "secret_char_length")
+                || template_uses(template, "strlen")
+                || template_uses(template, "reveal_chars"),
+            span,
+        ),
+    );
+    record.push(
+        "references_secret_string",
+        Value::bool(template_uses(template, "secret_string"), span),
+    );
+    record.push(
+        "audit_config_changes",
+        Value::bool(security.audit_config_changes, span),
+    );
+    record.push(
+        "audit_mask_custom_text",
+        Value::bool(security.audit_mask_custom_text, span),
+    );
+    record.push(
+        "audit_log_path",
+        match &security.audit_log_path {
+            Some(path) => Value::string(path.display().to_string(), span),
+            None => Value::nothing(span),
+        },
+    );
+    record.push("risk", Value::string(rating.as_str(), span));
+    record.push(
+        "findings",
+        Value::list(
+            reasons
+                .into_iter()
+                .map(|reason| Value::string(reason, span))
+                .collect(),
+            span,
+        ),
+    );
+
+    Value::record(record, span)
+}
+
+impl PluginCommand for SecretConfigReportCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret config report"
+    }
+
+    fn description(&self) -> &str {
+        "Summarize how secrets are redacted and audited, with an overall risk rating"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Record(Box::new([])))])
+            .category(Category::Custom("secret".into()))
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![Example {
+            example: "secret config report",
+            description: "Produce a redaction report card for a security review",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        _input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let span = call.head;
+
+        let config_manager = plugin.config_manager().read().map_err(|e| {
+            LabeledError::new("Configuration Error")
+                .with_label(format!("Failed to access configuration: {}", e), span)
+        })?;
+
+        Ok(PipelineData::Value(
+            build_report(config_manager.config(), span),
+            None,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report_field(report: &Value, field: &str) -> Value {
+        report.as_record().unwrap().get(field).unwrap().clone()
+    }
+
+    #[test]
+    fn test_command_name() {
+        let command = SecretConfigReportCommand;
+        assert_eq!(command.name(), "secret config report");
+    }
+
+    #[test]
+    fn test_signature() {
+        let command = SecretConfigReportCommand;
+        let signature = command.signature();
+
+        assert_eq!(signature.name, "secret config report");
+        assert!(signature.required_positional.is_empty());
+    }
+
+    #[test]
+    fn test_default_config_is_low_risk() {
+        let report = build_report(&PluginConfig::default(), Span::test_data());
+
+        assert_eq!(report_field(&report, "risk").as_str().unwrap(), "low");
+        assert_eq!(
+            report_field(&report, "redaction_style").as_str().unwrap(),
+            "default_template"
+        );
+        assert!(report_field(&report, "leaks_type").as_bool().unwrap());
+        assert!(!report_field(&report, "leaks_length").as_bool().unwrap());
+        assert!(report_field(&report, "findings")
+            .as_list()
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_show_unredacted_is_high_risk() {
+        let mut config = PluginConfig::default();
+        config.redaction.show_unredacted = true;
+
+        let report = build_report(&config, Span::test_data());
+        assert_eq!(report_field(&report, "risk").as_str().unwrap(), "high");
+        assert_eq!(
+            report_field(&report, "redaction_style").as_str().unwrap(),
+            "unredacted"
+        );
+        assert_eq!(
+            report_field(&report, "findings").as_list().unwrap().len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_template_ratings() {
+        let mut config = PluginConfig::default();
+        config.redaction.redaction_template =
+            Some("<redacted:{{secret_type}}:{{secret_length}}>".to_string());
+        assert_eq!(assess_risk(&config).0, RiskRating::Medium);
+
+        config.redaction.redaction_template =
+            Some("{{ take(s=secret_string, n=2) }}***".to_string());
+        let report = build_report(&config, Span::test_data());
+        assert!(report_field(&report, "references_secret_string")
+            .as_bool()
+            .unwrap());
+        assert_eq!(report_field(&report, "risk").as_str().unwrap(), "high");
+
+        config.redaction.redaction_template = Some("<redacted>".to_string());
+        let report = build_report(&config, Span::test_data());
+        assert!(!report_field(&report, "leaks_type").as_bool().unwrap());
+        assert_eq!(report_field(&report, "risk").as_str().unwrap(), "low");
+    }
+
+    #[test]
+    fn test_disabled_auditing_is_medium_risk() {
+        let mut config = PluginConfig::default();
+        config.security.audit_config_changes = false;
+
+        let (rating, findings) = assess_risk(&config);
+        assert_eq!(rating, RiskRating::Medium);
+        // Both the missing audit and the standard-level violation are reported
+        assert_eq!(findings.len(), 2);
+    }
+}
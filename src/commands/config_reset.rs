@@ -1,13 +1,91 @@
 //! Configuration reset command for nu_plugin_secret
 
 use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
-use nu_protocol::{Category, Example, LabeledError, PipelineData, Record, Signature, Type, Value};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, Record, Signature, Span, SyntaxShape, Type,
+    Value,
+};
 
-use crate::config::PluginConfig;
+use crate::config::{check_minimum_level_change, ConfigManager, PluginConfig};
 
 /// Command to reset configuration to defaults
 pub struct SecretConfigResetCommand;
 
+/// The part of the configuration a reset replaces with its defaults
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ResetSection {
+    /// Only the `redaction` section
+    Redaction,
+    /// Only the `security` section
+    Security,
+    /// The whole configuration
+    All,
+}
+
+impl ResetSection {
+    /// Replace this section of `config` with its default value.
+    fn apply(&self, config: &mut PluginConfig) {
+        match self {
+            ResetSection::Redaction => config.redaction = Default::default(),
+            ResetSection::Security => config.security = Default::default(),
+            ResetSection::All => *config = PluginConfig::default(),
+        }
+    }
+
+    /// Status message reported after a successful reset
+    fn status(&self) -> &'static str {
+        match self {
+            ResetSection::Redaction => "Redaction settings reset to defaults",
+            ResetSection::Security => "Security settings reset to defaults",
+            ResetSection::All => "Configuration reset to defaults",
+        }
+    }
+}
+
+impl std::str::FromStr for ResetSection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "redaction" => Ok(ResetSection::Redaction),
+            "security" => Ok(ResetSection::Security),
+            "all" => Ok(ResetSection::All),
+            _ => Err(format!(
+                "Unknown section '{}'. Valid options: redaction, security, all",
+                s
+            )),
+        }
+    }
+}
+
+/// Return a copy of `config` with `section` reset to its defaults.
+///
+/// Fails if the merged result does not validate (e.g. the kept redaction
+/// settings conflict with the default security level), or if the reset would
+/// remove an enforced minimum security level and `allow_lower_minimum` is not set.
+fn reset_section(
+    config: &PluginConfig,
+    section: ResetSection,
+    allow_lower_minimum: bool,
+    span: Span,
+) -> Result<PluginConfig, LabeledError> {
+    let mut updated = config.clone();
+    section.apply(&mut updated);
+
+    if !allow_lower_minimum {
+        check_minimum_level_change(config, &updated).map_err(|e| {
+            LabeledError::new("Minimum Level Protected").with_label(e.to_string(), span)
+        })?;
+    }
+
+    ConfigManager::validate_config(&updated).map_err(|e| {
+        LabeledError::new("Configuration Validation Failed")
+            .with_label(format!("Invalid configuration: {}", e), span)
+    })?;
+
+    Ok(updated)
+}
+
 /// Create a backup of the current configuration before resetting.
 ///
 /// Returns the backup file path on success, or a warning message if the
@@ -42,13 +120,14 @@ fn create_backup(
 }
 
 /// Build the result record for a successful reset, including default settings.
-fn build_reset_result(backup_info: &Record, span: nu_protocol::Span) -> Record {
+fn build_reset_result(
+    backup_info: &Record,
+    section: ResetSection,
+    span: nu_protocol::Span,
+) -> Record {
     let mut record = backup_info.clone();
 
-    record.push(
-        "status",
-        Value::string("Configuration reset to defaults", span),
-    );
+    record.push("status", Value::string(section.status(), span));
     record.push(
         "config_file",
         Value::string(
@@ -94,6 +173,17 @@ impl PluginCommand for SecretConfigResetCommand {
                 "Create backup of current configuration before reset",
                 Some('b'),
             )
+            .named(
+                "section",
+                SyntaxShape::String,
+                "Section to reset: redaction, security or all (default: all)",
+                Some('s'),
+            )
+            .switch(
+                "allow-lower-minimum",
+                "Allow the reset to remove the enforced security.minimum_level",
+                None,
+            )
             .category(Category::Custom("secret".into()))
     }
 
@@ -109,6 +199,11 @@ impl PluginCommand for SecretConfigResetCommand {
                 description: "Reset configuration with backup of current settings",
                 result: None,
             },
+            Example {
+                example: "secret config reset --confirm --section redaction",
+                description: "Reset only the redaction settings, keeping security settings",
+                result: None,
+            },
         ]
     }
 
@@ -129,6 +224,13 @@ impl PluginCommand for SecretConfigResetCommand {
             ));
         }
 
+        let section = call
+            .get_flag::<String>("section")?
+            .unwrap_or_else(|| "all".to_string())
+            .parse::<ResetSection>()
+            .map_err(|e| LabeledError::new("Invalid Section").with_label(e, span))?;
+        let allow_lower_minimum = call.has_flag("allow-lower-minimum")?;
+
         let mut backup_record = Record::new();
 
         // Create backup if requested
@@ -147,20 +249,28 @@ impl PluginCommand for SecretConfigResetCommand {
             }
         }
 
-        // Create default configuration
-        let default_config = PluginConfig::default();
+        // Reset the chosen section of the current configuration
+        let default_config = match plugin.config_manager().read() {
+            Ok(current_manager) => {
+                let default_config =
+                    reset_section(current_manager.config(), section, allow_lower_minimum, span)?;
+
+                // Audit the configuration change if enabled
+                if current_manager.config().security.audit_config_changes {
+                    let _ = crate::config::audit_config_change(
+                        current_manager.config(),
+                        &default_config,
+                    );
+                }
 
-        // Audit the configuration change if enabled
-        if let Ok(current_manager) = plugin.config_manager().read() {
-            if current_manager.config().security.audit_config_changes {
-                let _ =
-                    crate::config::audit_config_change(current_manager.config(), &default_config);
+                default_config
             }
-        }
+            Err(_) => reset_section(&PluginConfig::default(), section, allow_lower_minimum, span)?,
+        };
 
         // Update plugin's configuration
         if let Ok(mut config_manager) = plugin.config_manager().write() {
-            *config_manager.config_mut() = default_config;
+            config_manager.replace_config(default_config);
 
             // Save to disk
             config_manager.save().map_err(|e| {
@@ -172,7 +282,7 @@ impl PluginCommand for SecretConfigResetCommand {
                 .with_label("Failed to acquire write lock on configuration", span));
         }
 
-        let result_record = build_reset_result(&backup_record, span);
+        let result_record = build_reset_result(&backup_record, section, span);
 
         Ok(PipelineData::Value(
             Value::record(result_record, span),
@@ -184,6 +294,7 @@ impl PluginCommand for SecretConfigResetCommand {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::{RedactionConfig, SecurityLevel};
 
     #[test]
     fn test_command_name() {
@@ -197,5 +308,67 @@ mod tests {
         let signature = command.signature();
 
         assert_eq!(signature.name, "secret config reset");
+        assert!(signature.get_long_flag("section").is_some());
+        assert!(signature.get_long_flag("allow-lower-minimum").is_some());
+    }
+
+    #[test]
+    fn test_reset_redaction_keeps_security() {
+        let mut config = PluginConfig::default();
+        config.security.level = SecurityLevel::Paranoid;
+        config.redaction.redaction_template = Some("<hidden>".to_string());
+        config.redaction.mask_secret = true;
+
+        let reset =
+            reset_section(&config, ResetSection::Redaction, false, Span::test_data()).unwrap();
+        assert_eq!(reset.redaction, RedactionConfig::default());
+        assert_eq!(reset.security.level, SecurityLevel::Paranoid);
+    }
+
+    #[test]
+    fn test_reset_security_keeps_redaction() {
+        let mut config = PluginConfig::default();
+        config.security.level = SecurityLevel::Paranoid;
+        config.redaction.redaction_template = Some("<hidden>".to_string());
+
+        let reset =
+            reset_section(&config, ResetSection::Security, false, Span::test_data()).unwrap();
+        assert_eq!(reset.security.level, SecurityLevel::Standard);
+        assert_eq!(
+            reset.redaction.redaction_template,
+            Some("<hidden>".to_string())
+        );
+    }
+
+    #[test]
+    fn test_reset_cannot_remove_minimum_without_override() {
+        let mut config = PluginConfig::default();
+        config.security.minimum_level = Some(SecurityLevel::Standard);
+
+        let err = reset_section(&config, ResetSection::All, false, Span::test_data()).unwrap_err();
+        assert_eq!(err.msg, "Minimum Level Protected");
+
+        // Resetting only the redaction section keeps the minimum in place
+        assert!(reset_section(&config, ResetSection::Redaction, false, Span::test_data()).is_ok());
+
+        let reset =
+            reset_section(&config, ResetSection::Security, true, Span::test_data()).unwrap();
+        assert!(reset.security.minimum_level.is_none());
+    }
+
+    #[test]
+    fn test_reset_all_and_unknown_section() {
+        let mut config = PluginConfig::default();
+        config.security.level = SecurityLevel::Paranoid;
+        config.redaction.mask_secret = true;
+
+        let reset = reset_section(&config, ResetSection::All, false, Span::test_data()).unwrap();
+        assert_eq!(reset, PluginConfig::default());
+
+        assert!("everything".parse::<ResetSection>().is_err());
+        assert_eq!(
+            "redaction".parse::<ResetSection>(),
+            Ok(ResetSection::Redaction)
+        );
     }
 }
@@ -0,0 +1,176 @@
+//! Named configuration restore command for nu_plugin_secret
+
+use std::path::Path;
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, Record, Signature, SyntaxShape, Type, Value,
+};
+
+use crate::config::{ConfigError, ConfigManager, PluginConfig};
+
+/// Command to restore the active configuration from a named backup
+pub struct SecretConfigRestoreCommand;
+
+/// Load and validate the configuration stored in `<backup_dir>/<name>.toml`.
+pub(crate) fn read_named_backup(
+    backup_dir: &Path,
+    name: &str,
+) -> Result<PluginConfig, ConfigError> {
+    let backup_path = crate::config::named_backup_path(backup_dir, name)?;
+    let manager = ConfigManager::load_from_path(&backup_path)?;
+    Ok(manager.config().clone())
+}
+
+impl PluginCommand for SecretConfigRestoreCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret config restore"
+    }
+
+    fn description(&self) -> &str {
+        "Restore the secret plugin configuration from a named backup"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Record(Box::new([])))])
+            .required("name", SyntaxShape::String, "Name of the backup to restore")
+            .switch(
+                "allow-lower-minimum",
+                "Allow the restore to lower or remove the enforced security.minimum_level",
+                None,
+            )
+            .category(Category::Custom("secret".into()))
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![Example {
+            example: "secret config restore before-upgrade",
+            description: "Restore the configuration saved as 'before-upgrade'",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        _input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let span = call.head;
+        let name: String = call.req(0)?;
+
+        let backup_dir = crate::config::get_backup_dir().ok_or_else(|| {
+            LabeledError::new("Restore Failed")
+                .with_label("Cannot determine config directory for backups", span)
+        })?;
+
+        let restored = read_named_backup(&backup_dir, &name).map_err(|e| {
+            LabeledError::new("Restore Failed")
+                .with_label(format!("Failed to load backup '{}': {}", name, e), span)
+        })?;
+
+        // Audit the configuration change if enabled
+        if let Ok(current_manager) = plugin.config_manager().read() {
+            if current_manager.config().security.audit_config_changes {
+                let _ = crate::config::audit_config_change(current_manager.config(), &restored);
+            }
+        }
+
+        {
+            let mut config_manager = plugin.config_manager().write().map_err(|e| {
+                LabeledError::new("Update Error")
+                    .with_label(format!("Failed to acquire write lock: {}", e), span)
+            })?;
+
+            if !call.has_flag("allow-lower-minimum")? {
+                crate::config::check_minimum_level_change(config_manager.config(), &restored)
+                    .map_err(|e| {
+                        LabeledError::new("Minimum Level Protected").with_label(e.to_string(), span)
+                    })?;
+            }
+
+            config_manager.replace_config(restored.clone());
+
+            config_manager.save().map_err(|e| {
+                LabeledError::new("Save Failed").with_label(
+                    format!("Failed to save restored configuration: {}", e),
+                    span,
+                )
+            })?;
+        }
+
+        let mut record = Record::new();
+        record.push("status", Value::string("Configuration restored", span));
+        record.push("name", Value::string(name, span));
+        record.push(
+            "redaction_template",
+            Value::string(restored.redaction.get_redaction_template(), span),
+        );
+        record.push(
+            "security_level",
+            Value::string(
+                format!("{:?}", restored.security.level).to_lowercase(),
+                span,
+            ),
+        );
+
+        Ok(PipelineData::Value(Value::record(record, span), None))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::config_backup::write_named_backup;
+    use crate::config::SecurityLevel;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_command_name() {
+        let command = SecretConfigRestoreCommand;
+        assert_eq!(command.name(), "secret config restore");
+    }
+
+    #[test]
+    fn test_signature() {
+        let command = SecretConfigRestoreCommand;
+        let signature = command.signature();
+
+        assert_eq!(signature.name, "secret config restore");
+        assert_eq!(signature.required_positional[0].name, "name");
+        assert!(signature.get_long_flag("allow-lower-minimum").is_some());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_backup_change_restore_round_trip() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let mut original = PluginConfig::default();
+        original.redaction.redaction_template = Some("[SAVED:{{secret_type}}]".to_string());
+        let mut manager = ConfigManager::new_in_memory(original.clone());
+
+        write_named_backup(&manager, temp_dir.path(), "known-good", false).unwrap();
+
+        // Make a risky change to the live config
+        manager.config_mut().security.level = SecurityLevel::Minimal;
+        manager.config_mut().redaction.redaction_template = None;
+        assert_ne!(manager.config(), &original);
+
+        let restored = read_named_backup(temp_dir.path(), "known-good").unwrap();
+        *manager.config_mut() = restored;
+
+        assert_eq!(manager.config(), &original);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_restore_missing_backup_fails() {
+        let temp_dir = TempDir::new().unwrap();
+        assert!(read_named_backup(temp_dir.path(), "does-not-exist").is_err());
+    }
+}
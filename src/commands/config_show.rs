@@ -1,5 +1,7 @@
 //! Configuration display command for nu_plugin_secret
 
+use std::collections::HashMap;
+
 use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
 use nu_protocol::{Category, Example, LabeledError, PipelineData, Record, Signature, Type, Value};
 
@@ -47,10 +49,23 @@ fn build_structured_config_display(
             span,
         ),
     );
+    if let Some(minimum) = &config_manager.config().security.minimum_level {
+        security_record.push(
+            "minimum_level",
+            Value::string(format!("{:?}", minimum).to_lowercase(), span),
+        );
+    }
     security_record.push(
         "audit_config_changes",
         Value::bool(config_manager.config().security.audit_config_changes, span),
     );
+    security_record.push(
+        "audit_mask_custom_text",
+        Value::bool(
+            config_manager.config().security.audit_mask_custom_text,
+            span,
+        ),
+    );
     security_record.push(
         "max_custom_text_length",
         Value::int(
@@ -58,6 +73,25 @@ fn build_structured_config_display(
             span,
         ),
     );
+    if let Some(path) = &config_manager.config().security.audit_log_path {
+        security_record.push(
+            "audit_log_path",
+            Value::string(path.display().to_string(), span),
+        );
+    }
+    let disabled_functions = &config_manager.config().security.disabled_template_functions;
+    if !disabled_functions.is_empty() {
+        security_record.push(
+            "disabled_template_functions",
+            Value::list(
+                disabled_functions
+                    .iter()
+                    .map(|name| Value::string(name.clone(), span))
+                    .collect(),
+                span,
+            ),
+        );
+    }
     record.push("security", Value::record(security_record, span));
 
     // Environment variable overrides status
@@ -75,6 +109,141 @@ fn build_structured_config_display(
     record
 }
 
+/// Map a `PluginConfig` onto a native Nushell record with the same shape as the TOML file.
+///
+/// Unlike the display record, every field is included with its stored value so the
+/// result can be edited and saved with `to toml` before being re-imported. Enums are
+/// rendered as their lowercase TOML names and an unset template is omitted.
+fn config_to_record(config: &crate::config::PluginConfig, span: nu_protocol::Span) -> Record {
+    let mut redaction_record = Record::new();
+    redaction_record.push(
+        "show_unredacted",
+        Value::bool(config.redaction.show_unredacted, span),
+    );
+    redaction_record.push(
+        "mask_secret",
+        Value::bool(config.redaction.mask_secret, span),
+    );
+    if let Some(template) = &config.redaction.redaction_template {
+        redaction_record.push("redaction_template", Value::string(template.clone(), span));
+    }
+
+    let mut security_record = Record::new();
+    security_record.push(
+        "level",
+        Value::string(format!("{:?}", config.security.level).to_lowercase(), span),
+    );
+    if let Some(minimum) = &config.security.minimum_level {
+        security_record.push(
+            "minimum_level",
+            Value::string(format!("{:?}", minimum).to_lowercase(), span),
+        );
+    }
+    security_record.push(
+        "audit_config_changes",
+        Value::bool(config.security.audit_config_changes, span),
+    );
+    security_record.push(
+        "audit_mask_custom_text",
+        Value::bool(config.security.audit_mask_custom_text, span),
+    );
+    security_record.push(
+        "max_custom_text_length",
+        Value::int(config.security.max_custom_text_length as i64, span),
+    );
+    security_record.push(
+        "audit_format",
+        Value::string(
+            format!("{:?}", config.security.audit_format).to_lowercase(),
+            span,
+        ),
+    );
+    if let Some(path) = &config.security.audit_log_path {
+        security_record.push(
+            "audit_log_path",
+            Value::string(path.display().to_string(), span),
+        );
+    }
+    if !config.security.disabled_template_functions.is_empty() {
+        security_record.push(
+            "disabled_template_functions",
+            Value::list(
+                config
+                    .security
+                    .disabled_template_functions
+                    .iter()
+                    .map(|name| Value::string(name.clone(), span))
+                    .collect(),
+                span,
+            ),
+        );
+    }
+
+    let mut record = Record::new();
+    record.push("redaction", Value::record(redaction_record, span));
+    record.push("security", Value::record(security_record, span));
+    record.push("version", Value::string(config.version.clone(), span));
+
+    record
+}
+
+/// Fields set by each environment override variable, in `ENV_OVERRIDE_VARS` order
+const ENV_OVERRIDE_FIELDS: &[(&str, &str)] = &[
+    ("NU_PLUGIN_SECRET_SECURITY_LEVEL", "security.level"),
+    ("SHOW_UNREDACTED", "redaction.show_unredacted"),
+];
+
+/// Current value of a field that an environment variable can override.
+fn override_field_value(
+    config: &crate::config::PluginConfig,
+    field: &str,
+    span: nu_protocol::Span,
+) -> Value {
+    match field {
+        "security.level" => {
+            Value::string(format!("{:?}", config.security.level).to_lowercase(), span)
+        }
+        _ => Value::bool(config.redaction.show_unredacted, span),
+    }
+}
+
+/// Build the configuration record with environment overrides applied.
+///
+/// The overrides are applied to a clone, so the live configuration is never
+/// touched. The result has the same shape as `--structured`, plus an
+/// `env_overrides` list with one `{field, variable, configured, effective}`
+/// row per override variable that is set.
+fn build_effective_config<F>(
+    config: &crate::config::PluginConfig,
+    lookup: F,
+    span: nu_protocol::Span,
+) -> Result<Record, LabeledError>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    let mut effective = config.clone();
+    crate::config::ConfigManager::apply_env_overrides_from(&mut effective, &lookup).map_err(
+        |e| LabeledError::new("Invalid environment override").with_label(e.to_string(), span),
+    )?;
+
+    let overrides = ENV_OVERRIDE_FIELDS
+        .iter()
+        .filter(|(variable, _)| lookup(variable).is_some())
+        .map(|(variable, field)| {
+            let mut row = Record::new();
+            row.push("field", Value::string(*field, span));
+            row.push("variable", Value::string(*variable, span));
+            row.push("configured", override_field_value(config, field, span));
+            row.push("effective", override_field_value(&effective, field, span));
+            Value::record(row, span)
+        })
+        .collect();
+
+    let mut record = config_to_record(&effective, span);
+    record.push("env_overrides", Value::list(overrides, span));
+    Ok(record)
+}
+
 impl PluginCommand for SecretConfigShowCommand {
     type Plugin = crate::SecretPlugin;
 
@@ -91,6 +260,16 @@ impl PluginCommand for SecretConfigShowCommand {
             .input_output_types(vec![(Type::Nothing, Type::Record(Box::new([])))])
             .switch("raw", "Show raw TOML configuration", Some('r'))
             .switch("file-path", "Show configuration file path only", Some('f'))
+            .switch(
+                "structured",
+                "Output the full configuration as a record suitable for `to toml`",
+                Some('s'),
+            )
+            .switch(
+                "effective",
+                "Output the configuration with environment overrides applied, listing the overridden fields",
+                Some('e'),
+            )
             .category(Category::Custom("secret".into()))
     }
 
@@ -111,13 +290,23 @@ impl PluginCommand for SecretConfigShowCommand {
                 description: "Show only the configuration file path",
                 result: None,
             },
+            Example {
+                example: "secret config show --structured | update security.level paranoid | to toml | save -f edited.toml",
+                description: "Edit the configuration with Nushell commands and save it for import",
+                result: None,
+            },
+            Example {
+                example: "with-env { NU_PLUGIN_SECRET_SECURITY_LEVEL: paranoid } { secret config show --effective }",
+                description: "Show the configuration as environment overrides make it, with the overridden fields",
+                result: None,
+            },
         ]
     }
 
     fn run(
         &self,
         plugin: &Self::Plugin,
-        _engine: &EngineInterface,
+        engine: &EngineInterface,
         call: &EvaluatedCall,
         _input: PipelineData,
     ) -> Result<PipelineData, LabeledError> {
@@ -148,6 +337,31 @@ impl PluginCommand for SecretConfigShowCommand {
             return Ok(PipelineData::Value(Value::string(toml_content, span), None));
         }
 
+        if call.has_flag("effective")? {
+            let mut vars = HashMap::new();
+            for name in crate::config::ConfigManager::ENV_OVERRIDE_VARS {
+                if let Some(value) = engine.get_env_var(name)? {
+                    let value = value.coerce_string().map_err(|e| {
+                        LabeledError::new("Invalid environment variable")
+                            .with_label(format!("{} must be a string: {}", name, e), span)
+                    })?;
+                    vars.insert(name.to_string(), value);
+                }
+            }
+
+            let record = build_effective_config(
+                config_manager.config(),
+                |name| vars.get(name).cloned(),
+                span,
+            )?;
+            return Ok(PipelineData::Value(Value::record(record, span), None));
+        }
+
+        if call.has_flag("structured")? {
+            let record = config_to_record(config_manager.config(), span);
+            return Ok(PipelineData::Value(Value::record(record, span), None));
+        }
+
         let record = build_structured_config_display(&config_manager, span);
 
         Ok(PipelineData::Value(Value::record(record, span), None))
@@ -157,6 +371,9 @@ impl PluginCommand for SecretConfigShowCommand {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::{ConfigManager, PluginConfig, SecurityLevel};
+    use nu_protocol::Span;
+    use serial_test::serial;
 
     #[test]
     fn test_command_name() {
@@ -170,5 +387,149 @@ mod tests {
         let signature = command.signature();
 
         assert_eq!(signature.name, "secret config show");
+        assert!(signature.get_long_flag("structured").is_some());
+        assert!(signature.get_long_flag("effective").is_some());
+    }
+
+    #[test]
+    fn test_effective_config_annotates_overrides() {
+        let span = Span::test_data();
+        let config = PluginConfig::default();
+        let vars: HashMap<String, String> = [(
+            "NU_PLUGIN_SECRET_SECURITY_LEVEL".to_string(),
+            "paranoid".to_string(),
+        )]
+        .into();
+
+        let record = build_effective_config(&config, |name| vars.get(name).cloned(), span).unwrap();
+        let security = record.get("security").unwrap().as_record().unwrap();
+        assert_eq!(security.get("level").unwrap().as_str().unwrap(), "paranoid");
+
+        let overrides = record.get("env_overrides").unwrap().as_list().unwrap();
+        assert_eq!(overrides.len(), 1);
+        let row = overrides[0].as_record().unwrap();
+        assert_eq!(
+            row.get("field").unwrap().as_str().unwrap(),
+            "security.level"
+        );
+        assert_eq!(row.get("configured").unwrap().as_str().unwrap(), "standard");
+        assert_eq!(row.get("effective").unwrap().as_str().unwrap(), "paranoid");
+
+        // The configuration the overrides were applied to is unchanged
+        assert_eq!(config, PluginConfig::default());
+    }
+
+    #[test]
+    #[cfg_attr(not(miri), serial(env))]
+    fn test_effective_config_reads_process_env() {
+        let span = Span::test_data();
+        let mut config = PluginConfig::default();
+        config.security.level = SecurityLevel::Minimal;
+
+        std::env::set_var("NU_PLUGIN_SECRET_SECURITY_LEVEL", "standard");
+        let record =
+            build_effective_config(&config, |name| std::env::var(name).ok(), span).unwrap();
+        std::env::remove_var("NU_PLUGIN_SECRET_SECURITY_LEVEL");
+
+        let security = record.get("security").unwrap().as_record().unwrap();
+        assert_eq!(security.get("level").unwrap().as_str().unwrap(), "standard");
+        let file_record = config_to_record(&config, span);
+        let file_security = file_record.get("security").unwrap().as_record().unwrap();
+        assert_eq!(
+            file_security.get("level").unwrap().as_str().unwrap(),
+            "minimal"
+        );
+    }
+
+    #[test]
+    fn test_effective_config_without_overrides_or_invalid() {
+        let span = Span::test_data();
+        let config = PluginConfig::default();
+
+        let record = build_effective_config(&config, |_| None, span).unwrap();
+        assert!(record
+            .get("env_overrides")
+            .unwrap()
+            .as_list()
+            .unwrap()
+            .is_empty());
+
+        let err = build_effective_config(
+            &config,
+            |name| (name == "SHOW_UNREDACTED").then(|| "maybe".to_string()),
+            span,
+        )
+        .unwrap_err();
+        assert_eq!(err.msg, "Invalid environment override");
+    }
+
+    /// Convert a Nushell value into TOML the way `to toml` would for plain data.
+    fn to_toml_value(value: &Value) -> toml::Value {
+        match value {
+            Value::Bool { val, .. } => toml::Value::Boolean(*val),
+            Value::Int { val, .. } => toml::Value::Integer(*val),
+            Value::String { val, .. } => toml::Value::String(val.clone()),
+            Value::List { vals, .. } => {
+                toml::Value::Array(vals.iter().map(to_toml_value).collect())
+            }
+            Value::Record { val, .. } => toml::Value::Table(
+                val.iter()
+                    .map(|(k, v)| (k.clone(), to_toml_value(v)))
+                    .collect(),
+            ),
+            other => panic!("Unexpected value in config record: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_structured_config_keys() {
+        let mut config = PluginConfig::default();
+        config.redaction.redaction_template = Some("[HIDDEN:{{secret_type}}]".to_string());
+
+        let record = config_to_record(&config, Span::test_data());
+        assert!(record.contains("version"));
+
+        let redaction = record.get("redaction").unwrap().as_record().unwrap();
+        for key in ["show_unredacted", "mask_secret", "redaction_template"] {
+            assert!(redaction.contains(key), "missing redaction.{}", key);
+        }
+
+        let security = record.get("security").unwrap().as_record().unwrap();
+        assert_eq!(security.get("level").unwrap().as_str().unwrap(), "standard");
+        for key in ["audit_config_changes", "max_custom_text_length"] {
+            assert!(security.contains(key), "missing security.{}", key);
+        }
+
+        // Unset template is omitted rather than emitted as null
+        let default_record = config_to_record(&PluginConfig::default(), Span::test_data());
+        let default_redaction = default_record
+            .get("redaction")
+            .unwrap()
+            .as_record()
+            .unwrap();
+        assert!(!default_redaction.contains("redaction_template"));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_structured_config_round_trips_through_import() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("edited.toml");
+
+        let mut record = config_to_record(&PluginConfig::default(), Span::test_data());
+
+        // Manual edit, as a user would do with `update security.level paranoid`
+        let mut security = record.get("security").unwrap().as_record().unwrap().clone();
+        security.insert("level", Value::test_string("paranoid"));
+        record.insert("security", Value::test_record(security));
+
+        let toml_text =
+            toml::to_string_pretty(&to_toml_value(&Value::test_record(record))).unwrap();
+        std::fs::write(&path, toml_text).unwrap();
+
+        let imported = ConfigManager::load_from_path(&path).unwrap();
+        let mut expected = PluginConfig::default();
+        expected.security.level = SecurityLevel::Paranoid;
+        assert_eq!(imported.config(), &expected);
     }
 }
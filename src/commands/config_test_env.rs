@@ -0,0 +1,194 @@
+//! Environment override dry-run command for nu_plugin_secret
+
+use std::collections::HashMap;
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, Record, Signature, Span, Type, Value,
+};
+
+use crate::config::{ConfigManager, PluginConfig};
+
+/// Command to check environment variable overrides without applying them
+pub struct SecretConfigTestEnvCommand;
+
+/// Apply `vars` as environment overrides to a fresh default configuration.
+///
+/// The result reports the effective values on success, or the environment
+/// error message on failure. The live configuration is never touched.
+fn test_env_overrides(vars: &HashMap<String, String>, span: Span) -> Value {
+    let mut variables = Record::new();
+    for name in ConfigManager::ENV_OVERRIDE_VARS {
+        if let Some(value) = vars.get(*name) {
+            variables.push(*name, Value::string(value.clone(), span));
+        }
+    }
+
+    let mut config = PluginConfig::default();
+    let outcome =
+        ConfigManager::apply_env_overrides_from(&mut config, |name| vars.get(name).cloned());
+
+    let mut record = Record::new();
+    record.push("valid", Value::bool(outcome.is_ok(), span));
+    record.push("variables", Value::record(variables, span));
+    match outcome {
+        Ok(()) => {
+            record.push(
+                "security_level",
+                Value::string(format!("{:?}", config.security.level).to_lowercase(), span),
+            );
+            record.push(
+                "show_unredacted",
+                Value::bool(config.redaction.show_unredacted, span),
+            );
+        }
+        Err(e) => record.push("error", Value::string(e.to_string(), span)),
+    }
+
+    Value::record(record, span)
+}
+
+impl PluginCommand for SecretConfigTestEnvCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret config test-env"
+    }
+
+    fn description(&self) -> &str {
+        "Check how environment variable overrides would be parsed without applying them"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Record(Box::new([])))])
+            .category(Category::Custom("secret".into()))
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![Example {
+            example:
+                "with-env { NU_PLUGIN_SECRET_SECURITY_LEVEL: paranoid } { secret config test-env }",
+            description: "Check that a security level override is accepted",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        _input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let span = call.head;
+
+        let mut vars = HashMap::new();
+        for name in ConfigManager::ENV_OVERRIDE_VARS {
+            if let Some(value) = engine.get_env_var(name)? {
+                let value = value.coerce_string().map_err(|e| {
+                    LabeledError::new("Invalid environment variable")
+                        .with_label(format!("{} must be a string: {}", name, e), span)
+                })?;
+                vars.insert(name.to_string(), value);
+            }
+        }
+
+        Ok(PipelineData::Value(test_env_overrides(&vars, span), None))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_with(vars: &[(&str, &str)]) -> Record {
+        let vars = vars
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+        test_env_overrides(&vars, Span::test_data())
+            .into_record()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_command_name() {
+        let command = SecretConfigTestEnvCommand;
+        assert_eq!(command.name(), "secret config test-env");
+    }
+
+    #[test]
+    fn test_signature() {
+        let command = SecretConfigTestEnvCommand;
+        let signature = command.signature();
+
+        assert_eq!(signature.name, "secret config test-env");
+        assert_eq!(signature.required_positional.len(), 0);
+    }
+
+    #[test]
+    fn test_valid_boolean() {
+        let record = run_with(&[("SHOW_UNREDACTED", "true")]);
+
+        assert!(record.get("valid").unwrap().as_bool().unwrap());
+        assert!(record.get("show_unredacted").unwrap().as_bool().unwrap());
+        assert_eq!(
+            record.get("security_level").unwrap().as_str().unwrap(),
+            "standard"
+        );
+        assert!(record.get("error").is_none());
+    }
+
+    #[test]
+    fn test_invalid_boolean_surfaces_error() {
+        let record = run_with(&[("SHOW_UNREDACTED", "maybe")]);
+
+        assert!(!record.get("valid").unwrap().as_bool().unwrap());
+        assert!(record
+            .get("error")
+            .unwrap()
+            .as_str()
+            .unwrap()
+            .contains("SHOW_UNREDACTED"));
+        assert_eq!(
+            record
+                .get("variables")
+                .unwrap()
+                .as_record()
+                .unwrap()
+                .get("SHOW_UNREDACTED")
+                .unwrap()
+                .as_str()
+                .unwrap(),
+            "maybe"
+        );
+    }
+
+    #[test]
+    fn test_unknown_security_level() {
+        let record = run_with(&[("NU_PLUGIN_SECRET_SECURITY_LEVEL", "extreme")]);
+
+        assert!(!record.get("valid").unwrap().as_bool().unwrap());
+        assert!(record
+            .get("error")
+            .unwrap()
+            .as_str()
+            .unwrap()
+            .contains("Invalid security level"));
+    }
+
+    #[test]
+    fn test_no_variables_reports_defaults() {
+        let record = run_with(&[]);
+
+        assert!(record.get("valid").unwrap().as_bool().unwrap());
+        assert!(!record.get("show_unredacted").unwrap().as_bool().unwrap());
+        assert!(record
+            .get("variables")
+            .unwrap()
+            .as_record()
+            .unwrap()
+            .is_empty());
+    }
+}
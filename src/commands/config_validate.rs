@@ -1,9 +1,14 @@
 //! Configuration validation command for nu_plugin_secret
 
+use std::path::{Path, PathBuf};
+
 use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
-use nu_protocol::{Category, Example, LabeledError, PipelineData, Record, Signature, Type, Value};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, Record, Signature, Span, SyntaxShape, Type,
+    Value,
+};
 
-use crate::config::{ConfigManager, PluginConfig};
+use crate::config::{security_level_violations, ConfigFormat, ConfigManager, PluginConfig};
 
 /// Command to validate configuration settings
 pub struct SecretConfigValidateCommand;
@@ -107,6 +112,49 @@ fn run_validation_checks(
     (results, has_errors, has_warnings)
 }
 
+/// Validate the configuration file at `path` without touching the active configuration.
+///
+/// Loading stops at the first problem, so when the file parses, every security
+/// level violation is reported as well rather than only the first one.
+fn validate_config_file(path: &Path) -> Vec<String> {
+    let error = match ConfigManager::load_from_path(path) {
+        Ok(_) => return Vec::new(),
+        Err(e) => e.to_string(),
+    };
+
+    let mut errors = vec![error];
+    let parsed = std::fs::read_to_string(path)
+        .ok()
+        .and_then(|content| ConfigFormat::Toml.parse(&content).ok());
+    if let Some(config) = parsed {
+        for violation in security_level_violations(&config) {
+            if !errors.iter().any(|e| e.contains(&violation.reason)) {
+                errors.push(violation.reason);
+            }
+        }
+    }
+
+    errors
+}
+
+/// Build the `{valid, errors, file}` record for a file validated with `--file`.
+fn build_file_validation_record(path: &Path, errors: Vec<String>, span: Span) -> Record {
+    let mut record = Record::new();
+    record.push("valid", Value::bool(errors.is_empty(), span));
+    record.push(
+        "errors",
+        Value::list(
+            errors
+                .into_iter()
+                .map(|error| Value::string(error, span))
+                .collect(),
+            span,
+        ),
+    );
+    record.push("file", Value::string(path.display().to_string(), span));
+    record
+}
+
 /// Build the final validation result record from the collected validation data.
 fn build_validation_record(
     validation_results: Vec<(&str, &str, &str)>,
@@ -180,6 +228,12 @@ impl PluginCommand for SecretConfigValidateCommand {
         Signature::build(self.name())
             .input_output_types(vec![(Type::Nothing, Type::Record(Box::new([])))])
             .switch("verbose", "Show detailed validation results", Some('v'))
+            .named(
+                "file",
+                SyntaxShape::Filepath,
+                "Validate this configuration file instead of the active configuration",
+                Some('f'),
+            )
             .category(Category::Custom("secret".into()))
     }
 
@@ -195,6 +249,11 @@ impl PluginCommand for SecretConfigValidateCommand {
                 description: "Show detailed validation results",
                 result: None,
             },
+            Example {
+                example: "secret config validate --file ./production.toml",
+                description: "Lint a candidate configuration file before deploying it",
+                result: None,
+            },
         ]
     }
 
@@ -208,6 +267,12 @@ impl PluginCommand for SecretConfigValidateCommand {
         let span = call.head;
         let verbose = call.has_flag("verbose")?;
 
+        if let Some(path) = call.get_flag::<PathBuf>("file")? {
+            let errors = validate_config_file(&path);
+            let record = build_file_validation_record(&path, errors, span);
+            return Ok(PipelineData::Value(Value::record(record, span), None));
+        }
+
         // Load and validate configuration
         let manager = match ConfigManager::load() {
             Ok(manager) => manager,
@@ -262,7 +327,7 @@ mod tests {
     fn test_examples_count() {
         let command = SecretConfigValidateCommand;
         let examples = command.examples();
-        assert_eq!(examples.len(), 2);
+        assert_eq!(examples.len(), 3);
     }
 
     #[test]
@@ -779,4 +844,50 @@ mod tests {
         // Note: In real usage, this creates a memory leak, but it's acceptable
         // for validation messages that live for the duration of the program
     }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_validate_file_valid() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("valid.toml");
+        std::fs::write(
+            &path,
+            "[redaction]\nredaction_template = \"<hidden:{{secret_type}}>\"\n",
+        )
+        .unwrap();
+
+        let record =
+            build_file_validation_record(&path, validate_config_file(&path), Span::test_data());
+        assert!(record.get("valid").unwrap().as_bool().unwrap());
+        assert!(record.get("errors").unwrap().as_list().unwrap().is_empty());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_validate_file_paranoid_violation() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("paranoid.toml");
+        std::fs::write(
+            &path,
+            "[security]\nlevel = \"paranoid\"\naudit_config_changes = false\n",
+        )
+        .unwrap();
+
+        let errors = validate_config_file(&path);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].contains("Paranoid security level requires audit logging"));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_validate_file_nonexistent_path() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("missing.toml");
+
+        let record =
+            build_file_validation_record(&path, validate_config_file(&path), Span::test_data());
+        assert!(!record.get("valid").unwrap().as_bool().unwrap());
+        let errors = record.get("errors").unwrap().as_list().unwrap();
+        assert!(errors[0].as_str().unwrap().contains("not found"));
+    }
 }
@@ -17,23 +17,12 @@ use crate::config::{ConfigManager, PluginConfig, SecurityLevel};
 /// Main configuration command that provides subcommands for config management
 pub struct SecretConfigureCommand;
 
-/// Parse a security level string and apply it to the current config, returning
-/// the updated config ready for validation and persistence.
+/// Parse a security level string and apply it to `config`.
 fn apply_security_level_change(
-    plugin: &crate::SecretPlugin,
+    config: &mut PluginConfig,
     level_str: &str,
     span: nu_protocol::Span,
-) -> Result<PluginConfig, LabeledError> {
-    let mut config = plugin
-        .config_manager()
-        .read()
-        .map_err(|e| {
-            LabeledError::new("Configuration Error")
-                .with_label(format!("Failed to read configuration: {}", e), span)
-        })?
-        .config()
-        .clone();
-
+) -> Result<(), LabeledError> {
     let level = match level_str {
         "minimal" => SecurityLevel::Minimal,
         "standard" => SecurityLevel::Standard,
@@ -50,7 +39,21 @@ fn apply_security_level_change(
     };
     config.security.level = level;
 
-    Ok(config)
+    Ok(())
+}
+
+/// Validate a changed configuration before it is applied and saved.
+///
+/// Catches problems such as a redaction template that does not compile, which
+/// would otherwise silently fall back to the default at render time.
+fn validate_config_change(
+    config: &PluginConfig,
+    span: nu_protocol::Span,
+) -> Result<(), LabeledError> {
+    ConfigManager::validate_config(config).map_err(|e| {
+        LabeledError::new("Configuration Validation Failed")
+            .with_label(format!("Invalid configuration: {}", e), span)
+    })
 }
 
 /// Build a summary record of the current configuration state for output.
@@ -109,15 +112,28 @@ impl PluginCommand for SecretConfigureCommand {
                 "Set security level (minimal, standard, paranoid)",
                 Some('s'),
             )
+            .named(
+                "redaction-template",
+                SyntaxShape::String,
+                "Set the Tera template used to render redacted values",
+                Some('t'),
+            )
             .category(Category::Custom("secret".into()))
     }
 
     fn examples(&self) -> Vec<Example<'_>> {
-        vec![Example {
-            example: "secret configure --security-level paranoid",
-            description: "Set security level to paranoid (maximum security)",
-            result: None,
-        }]
+        vec![
+            Example {
+                example: "secret configure --security-level paranoid",
+                description: "Set security level to paranoid (maximum security)",
+                result: None,
+            },
+            Example {
+                example: "secret configure --redaction-template '[HIDDEN:{{secret_type}}]'",
+                description: "Set the redaction template, rejecting templates that do not compile",
+                result: None,
+            },
+        ]
     }
 
     fn run(
@@ -129,22 +145,32 @@ impl PluginCommand for SecretConfigureCommand {
     ) -> Result<PipelineData, LabeledError> {
         let span = call.head;
 
+        let mut config = plugin
+            .config_manager()
+            .read()
+            .map_err(|e| {
+                LabeledError::new("Configuration Error")
+                    .with_label(format!("Failed to read configuration: {}", e), span)
+            })?
+            .config()
+            .clone();
         let mut config_changed = false;
-        let mut new_config = None;
 
         // Handle security level changes
         if let Some(level_str) = call.get_flag::<String>("security-level")? {
-            new_config = Some(apply_security_level_change(plugin, &level_str, span)?);
+            apply_security_level_change(&mut config, &level_str, span)?;
+            config_changed = true;
+        }
+
+        // Handle redaction template changes
+        if let Some(template) = call.get_flag::<String>("redaction-template")? {
+            config.redaction.redaction_template = Some(template);
             config_changed = true;
         }
 
         // Validate and apply configuration changes
-        if let Some(config) = new_config {
-            // Validate configuration
-            if let Err(e) = ConfigManager::validate_config(&config) {
-                return Err(LabeledError::new("Configuration Validation Failed")
-                    .with_label(format!("Invalid configuration: {}", e), span));
-            }
+        if config_changed {
+            validate_config_change(&config, span)?;
 
             // Audit the change if enabled
             if let Ok(current_manager) = plugin.config_manager().read() {
@@ -159,7 +185,7 @@ impl PluginCommand for SecretConfigureCommand {
                     .with_label(format!("Failed to acquire write lock: {}", e), span)
             })?;
 
-            *config_manager.config_mut() = config;
+            config_manager.replace_config(config);
 
             // Save to disk
             config_manager.save().map_err(|e| {
@@ -197,5 +223,51 @@ mod tests {
 
         assert_eq!(signature.name, "secret configure");
         assert!(signature.named.iter().any(|n| n.long == "security-level"));
+        assert!(signature
+            .named
+            .iter()
+            .any(|n| n.long == "redaction-template"));
+    }
+
+    #[test]
+    fn test_valid_template_passes_validation() {
+        let mut config = PluginConfig::default();
+        config.redaction.redaction_template = Some("[HIDDEN:{{secret_type}}]".to_string());
+
+        assert!(validate_config_change(&config, nu_protocol::Span::test_data()).is_ok());
+    }
+
+    #[test]
+    fn test_malformed_template_rejected() {
+        let mut config = PluginConfig::default();
+        config.redaction.redaction_template = Some("{{unclosed".to_string());
+
+        let err = validate_config_change(&config, nu_protocol::Span::test_data()).unwrap_err();
+        assert_eq!(err.msg, "Configuration Validation Failed");
+        assert!(format!("{:?}", err).contains("Invalid Tera template syntax"));
+    }
+
+    #[test]
+    fn test_paranoid_change_rejected_with_unredacted_types() {
+        let span = nu_protocol::Span::test_data();
+        let mut config = PluginConfig::default();
+        config.redaction.unredacted_types = vec!["int".to_string()];
+        assert!(validate_config_change(&config, span).is_ok());
+
+        apply_security_level_change(&mut config, "paranoid", span).unwrap();
+        let err = validate_config_change(&config, span).unwrap_err();
+        assert_eq!(err.msg, "Configuration Validation Failed");
+        assert!(format!("{:?}", err).contains("forbids displaying any secret type unredacted"));
+    }
+
+    #[test]
+    fn test_apply_security_level_change() {
+        let span = nu_protocol::Span::test_data();
+        let mut config = PluginConfig::default();
+
+        apply_security_level_change(&mut config, "paranoid", span).unwrap();
+        assert_eq!(config.security.level, SecurityLevel::Paranoid);
+
+        assert!(apply_security_level_change(&mut config, "extreme", span).is_err());
     }
 }
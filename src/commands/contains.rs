@@ -5,6 +5,7 @@ use nu_protocol::{
     Category, Example, LabeledError, PipelineData, Signature, SyntaxShape, Type, Value,
 };
 
+use super::unwrap::{reveal_secret_value, with_origin_label};
 use crate::{
     SecretBinary, SecretBool, SecretDate, SecretFloat, SecretInt, SecretList, SecretRecord,
     SecretString,
@@ -15,18 +16,41 @@ pub struct SecretContainsCommand;
 
 /// Compare a secret custom value against a search value, returning a boolean result.
 ///
+/// Errors also point at the value the secret was wrapped from, when known.
+fn compare_secret_value(
+    val: &dyn nu_protocol::CustomValue,
+    search_value: &Value,
+    exact: bool,
+    span: nu_protocol::Span,
+) -> Result<Value, LabeledError> {
+    compare_by_type(val, search_value, exact, span).map_err(|error| with_origin_label(error, val))
+}
+
 /// Handles type dispatch for all eight secret types. Returns a type mismatch error
 /// if the search value's type does not match the secret's inner type.
-fn compare_secret_value(
+///
+/// A `SecretString` matches when it contains the search string as a substring,
+/// or only when it equals it if `exact` is set. A `SecretList` searched with a
+/// non-list value matches when any element equals it, with secret elements
+/// compared by content. Every other combination compares by equality.
+fn compare_by_type(
     val: &dyn nu_protocol::CustomValue,
     search_value: &Value,
+    exact: bool,
     span: nu_protocol::Span,
 ) -> Result<Value, LabeledError> {
     if let Some(secret_string) = val.as_any().downcast_ref::<SecretString>() {
         match search_value {
             Value::String {
                 val: search_str, ..
-            } => Ok(Value::bool(secret_string.reveal() == search_str, span)),
+            } => {
+                let found = if exact {
+                    secret_string.reveal() == search_str
+                } else {
+                    secret_string.reveal().contains(search_str.as_str())
+                };
+                Ok(Value::bool(found, span))
+            }
             _ => Err(LabeledError::new("Type mismatch")
                 .with_label("Expected string value to compare with SecretString", span)),
         }
@@ -87,8 +111,14 @@ fn compare_secret_value(
                 let search_value_list = Value::list(search_list.clone(), span);
                 Ok(Value::bool(secret_value == search_value_list, span))
             }
-            _ => Err(LabeledError::new("Type mismatch")
-                .with_label("Expected list value to compare with SecretList", span)),
+            _ => {
+                let found = secret_list.reveal().iter().any(|element| match element {
+                    Value::Custom { val, .. } => reveal_secret_value(val.as_ref(), span)
+                        .is_some_and(|revealed| revealed == *search_value),
+                    _ => element == search_value,
+                });
+                Ok(Value::bool(found, span))
+            }
         }
     } else if let Some(secret_record) = val.as_any().downcast_ref::<SecretRecord>() {
         match search_value {
@@ -125,6 +155,11 @@ impl PluginCommand for SecretContainsCommand {
                 SyntaxShape::Any,
                 "The value to search for in the secret",
             )
+            .switch(
+                "exact",
+                "Require a secret string to equal the value instead of containing it",
+                Some('e'),
+            )
             .input_output_types(vec![
                 (Type::Custom("secret_string".into()), Type::Bool),
                 (Type::Custom("secret_int".into()), Type::Bool),
@@ -139,16 +174,21 @@ impl PluginCommand for SecretContainsCommand {
     }
 
     fn description(&self) -> &str {
-        "Check if a secret contains a specific value by comparing with the embedded secret data"
+        "Check if a secret string contains a substring, or if any other secret equals a value"
     }
 
     fn examples(&self) -> Vec<Example<'_>> {
         vec![
             Example {
-                example: r#""my-secret-key" | secret wrap | secret contains "my-secret-key""#,
-                description: "Check if a secret string contains a specific string value",
+                example: r#""long-api-key-xyz" | secret wrap | secret contains "api""#,
+                description: "Check if a secret string contains a substring",
                 result: Some(Value::bool(true, nu_protocol::Span::test_data())),
             },
+            Example {
+                example: r#""long-api-key-xyz" | secret wrap | secret contains --exact "api""#,
+                description: "Require the secret string to equal the value exactly",
+                result: Some(Value::bool(false, nu_protocol::Span::test_data())),
+            },
             Example {
                 example: r#"42 | secret wrap | secret contains 42"#,
                 description: "Check if a secret integer contains a specific integer value",
@@ -164,6 +204,11 @@ impl PluginCommand for SecretContainsCommand {
                 description: "Check if a secret float contains a specific float value",
                 result: Some(Value::bool(true, nu_protocol::Span::test_data())),
             },
+            Example {
+                example: r#"[1, 2, 3] | secret wrap | secret contains 2"#,
+                description: "Check if a secret list has an element equal to a value",
+                result: Some(Value::bool(true, nu_protocol::Span::test_data())),
+            },
             Example {
                 example: r#"[1, 2, 3] | secret wrap | secret contains [1, 2, 3]"#,
                 description: "Check if a secret list contains a specific list value",
@@ -190,12 +235,13 @@ impl PluginCommand for SecretContainsCommand {
         input: PipelineData,
     ) -> Result<PipelineData, LabeledError> {
         let search_value = call.req(0)?;
+        let exact = call.has_flag("exact")?;
 
         match input {
             PipelineData::Value(value, metadata) => {
                 let result = match value {
                     Value::Custom { val, .. } => {
-                        compare_secret_value(val.as_ref(), &search_value, call.head)?
+                        compare_secret_value(val.as_ref(), &search_value, exact, call.head)?
                     }
                     _ => {
                         return Err(LabeledError::new("Invalid input")
@@ -238,7 +284,7 @@ mod tests {
         let command = SecretContainsCommand;
         assert_eq!(
             command.description(),
-            "Check if a secret contains a specific value by comparing with the embedded secret data"
+            "Check if a secret string contains a substring, or if any other secret equals a value"
         );
     }
 
@@ -246,7 +292,7 @@ mod tests {
     fn test_examples_count() {
         let command = SecretContainsCommand;
         let examples = command.examples();
-        assert_eq!(examples.len(), 7);
+        assert_eq!(examples.len(), 9);
     }
 
     #[test]
@@ -535,4 +581,131 @@ mod tests {
         let epsilon = SecretFloat::new(f64::EPSILON);
         assert_eq!(epsilon.reveal(), f64::EPSILON);
     }
+
+    #[test]
+    fn test_type_mismatch_points_at_wrap_site() {
+        let head = nu_protocol::Span::test_data();
+        let origin = nu_protocol::Span::new(5, 14);
+        let wrapped =
+            super::super::wrap::wrap_value(Value::string("my-secret", origin), head).unwrap();
+        let secret = wrapped.as_custom_value().unwrap();
+
+        let err = compare_secret_value(secret, &Value::int(42, head), false, head).unwrap_err();
+        assert_eq!(err.msg, "Type mismatch");
+        assert!(err.labels.iter().any(|label| label.span == head));
+        assert!(err.labels.iter().any(|label| label.span == origin));
+
+        let unwrapped_origin = SecretString::new("my-secret".to_string());
+        let err = compare_secret_value(&unwrapped_origin, &Value::int(42, head), false, head)
+            .unwrap_err();
+        assert_eq!(err.labels.len(), 1);
+    }
+
+    #[test]
+    fn test_string_substring_match() {
+        let span = nu_protocol::Span::test_data();
+        let secret = SecretString::new("long-api-key-xyz".to_string());
+
+        let result = compare_secret_value(&secret, &Value::string("api", span), false, span);
+        assert_eq!(result.unwrap(), Value::bool(true, span));
+
+        let result = compare_secret_value(&secret, &Value::string("", span), false, span);
+        assert_eq!(result.unwrap(), Value::bool(true, span));
+    }
+
+    #[test]
+    fn test_string_substring_no_match() {
+        let span = nu_protocol::Span::test_data();
+        let secret = SecretString::new("long-api-key-xyz".to_string());
+
+        let result = compare_secret_value(&secret, &Value::string("API", span), false, span);
+        assert_eq!(result.unwrap(), Value::bool(false, span));
+
+        let result = compare_secret_value(
+            &secret,
+            &Value::string("long-api-key-xyz-extra", span),
+            false,
+            span,
+        );
+        assert_eq!(result.unwrap(), Value::bool(false, span));
+    }
+
+    #[test]
+    fn test_string_exact_match() {
+        let span = nu_protocol::Span::test_data();
+        let secret = SecretString::new("long-api-key-xyz".to_string());
+
+        let result = compare_secret_value(&secret, &Value::string("api", span), true, span);
+        assert_eq!(result.unwrap(), Value::bool(false, span));
+
+        let result = compare_secret_value(
+            &secret,
+            &Value::string("long-api-key-xyz", span),
+            true,
+            span,
+        );
+        assert_eq!(result.unwrap(), Value::bool(true, span));
+    }
+
+    #[test]
+    fn test_list_element_membership() {
+        let span = nu_protocol::Span::test_data();
+        let secret = SecretList::new(vec![
+            Value::int(1, span),
+            Value::int(2, span),
+            Value::int(3, span),
+        ]);
+
+        let result = compare_secret_value(&secret, &Value::int(2, span), false, span);
+        assert_eq!(result.unwrap(), Value::bool(true, span));
+
+        let result = compare_secret_value(&secret, &Value::int(4, span), false, span);
+        assert_eq!(result.unwrap(), Value::bool(false, span));
+
+        let result = compare_secret_value(&secret, &Value::string("2", span), false, span);
+        assert_eq!(result.unwrap(), Value::bool(false, span));
+    }
+
+    #[test]
+    fn test_list_membership_of_secret_elements() {
+        let span = nu_protocol::Span::test_data();
+        let secret = SecretList::new(vec![
+            Value::custom(Box::new(SecretString::new("alpha".to_string())), span),
+            Value::custom(Box::new(SecretString::new("beta".to_string())), span),
+        ]);
+
+        let result = compare_secret_value(&secret, &Value::string("beta", span), false, span);
+        assert_eq!(result.unwrap(), Value::bool(true, span));
+
+        let result = compare_secret_value(&secret, &Value::string("gamma", span), false, span);
+        assert_eq!(result.unwrap(), Value::bool(false, span));
+    }
+
+    #[test]
+    fn test_list_whole_list_equality() {
+        let span = nu_protocol::Span::test_data();
+        let elements = vec![Value::int(1, span), Value::int(2, span)];
+        let secret = SecretList::new(elements.clone());
+
+        let result = compare_secret_value(&secret, &Value::list(elements, span), false, span);
+        assert_eq!(result.unwrap(), Value::bool(true, span));
+
+        let result = compare_secret_value(
+            &secret,
+            &Value::list(vec![Value::int(2, span)], span),
+            false,
+            span,
+        );
+        assert_eq!(result.unwrap(), Value::bool(false, span));
+    }
+
+    #[test]
+    fn test_non_string_types_ignore_exact() {
+        let span = nu_protocol::Span::test_data();
+        let secret = SecretInt::new(42);
+        for exact in [false, true] {
+            let result = compare_secret_value(&secret, &Value::int(42, span), exact, span);
+            assert_eq!(result.unwrap(), Value::bool(true, span));
+        }
+    }
 }
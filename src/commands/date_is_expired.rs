@@ -0,0 +1,157 @@
+//! Implements `secret date is-expired` — checks whether a secret date used as an
+//! expiry timestamp has passed, without revealing it.
+
+use chrono::{DateTime, FixedOffset};
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, Signature, SyntaxShape, Type, Value,
+};
+
+use crate::SecretDate;
+
+#[derive(Clone)]
+pub struct SecretDateIsExpiredCommand;
+
+/// Check whether a secret date is at or before `as_of`, returning a boolean result.
+fn secret_date_is_expired(
+    val: &dyn nu_protocol::CustomValue,
+    as_of: &DateTime<FixedOffset>,
+    span: nu_protocol::Span,
+) -> Result<Value, LabeledError> {
+    if let Some(secret_date) = val.as_any().downcast_ref::<SecretDate>() {
+        Ok(Value::bool(secret_date.reveal() <= as_of, span))
+    } else {
+        Err(LabeledError::new("Type mismatch").with_label(
+            format!("Expected secret_date, found {}", val.type_name()),
+            span,
+        ))
+    }
+}
+
+impl PluginCommand for SecretDateIsExpiredCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret date is-expired"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Custom("secret_date".into()), Type::Bool)])
+            .named(
+                "as-of",
+                SyntaxShape::DateTime,
+                "Check expiry against this date instead of the current time",
+                None,
+            )
+            .category(Category::Date)
+    }
+
+    fn description(&self) -> &str {
+        "Check if a secret expiry date has passed without revealing it"
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                example: r#"$token.expires_at | secret wrap | secret date is-expired"#,
+                description: "Check whether a token's expiry date is in the past",
+                result: None,
+            },
+            Example {
+                example: r#"2024-01-01 | secret wrap | secret date is-expired --as-of 2025-01-01"#,
+                description: "Check expiry against a fixed date",
+                result: Some(Value::test_bool(true)),
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let as_of = match call.get_flag::<DateTime<FixedOffset>>("as-of")? {
+            Some(as_of) => as_of,
+            None => chrono::Utc::now().fixed_offset(),
+        };
+
+        match input {
+            PipelineData::Value(value, metadata) => {
+                let result = match value {
+                    Value::Custom { val, .. } => {
+                        secret_date_is_expired(val.as_ref(), &as_of, call.head)?
+                    }
+                    _ => {
+                        return Err(LabeledError::new("Invalid input").with_label(
+                            "Input must be a secret date. Use 'secret wrap' to create a secret first",
+                            call.head,
+                        ));
+                    }
+                };
+
+                Ok(PipelineData::Value(result, metadata))
+            }
+            _ => Err(LabeledError::new("Invalid input")
+                .with_label("Expected a single secret value", call.head)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SecretInt;
+    use chrono::{TimeZone, Utc};
+    use nu_protocol::Span;
+
+    fn date(timestamp: i64) -> DateTime<FixedOffset> {
+        Utc.timestamp_opt(timestamp, 0).unwrap().into()
+    }
+
+    fn is_expired(expiry: i64, as_of: i64) -> bool {
+        let secret = SecretDate::new(date(expiry));
+        secret_date_is_expired(&secret, &date(as_of), Span::test_data())
+            .unwrap()
+            .as_bool()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_command_name() {
+        let command = SecretDateIsExpiredCommand;
+        assert_eq!(command.name(), "secret date is-expired");
+    }
+
+    #[test]
+    fn test_signature() {
+        let command = SecretDateIsExpiredCommand;
+        let signature = command.signature();
+        assert_eq!(signature.name, "secret date is-expired");
+        assert!(signature.get_long_flag("as-of").is_some());
+    }
+
+    #[test]
+    fn test_past_date_is_expired() {
+        assert!(is_expired(1_699_123_200, 1_699_123_201));
+    }
+
+    #[test]
+    fn test_future_date_is_not_expired() {
+        assert!(!is_expired(1_699_123_200, 1_699_000_000));
+    }
+
+    #[test]
+    fn test_expiry_at_as_of_is_expired() {
+        assert!(is_expired(1_699_123_200, 1_699_123_200));
+    }
+
+    #[test]
+    fn test_rejects_non_date_secret() {
+        let err =
+            secret_date_is_expired(&SecretInt::new(1), &date(0), Span::test_data()).unwrap_err();
+        assert_eq!(err.msg, "Type mismatch");
+    }
+}
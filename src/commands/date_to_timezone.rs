@@ -0,0 +1,232 @@
+//! Implements `secret date to-timezone` — converts a secret date to another timezone.
+
+use chrono::FixedOffset;
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, Signature, SyntaxShape, Type, Value,
+};
+
+use crate::SecretDate;
+
+#[derive(Clone)]
+pub struct SecretDateToTimezoneCommand;
+
+/// Parse a timezone argument into a fixed UTC offset.
+///
+/// Accepts `UTC`/`Z` (case-insensitive) and fixed offsets of the form `+HH`,
+/// `+HHMM`, or `+HH:MM` (and the `-` equivalents). Named IANA zones are not
+/// supported because they require a timezone database.
+fn parse_timezone(tz: &str) -> Result<FixedOffset, String> {
+    let invalid = || {
+        format!(
+            "Invalid timezone '{}'. Use UTC or a fixed offset such as +05:30 or -0800",
+            tz
+        )
+    };
+
+    if tz.eq_ignore_ascii_case("utc") || tz.eq_ignore_ascii_case("z") {
+        return FixedOffset::east_opt(0).ok_or_else(invalid);
+    }
+
+    let (sign, rest) = match tz.chars().next() {
+        Some('+') => (1, &tz[1..]),
+        Some('-') => (-1, &tz[1..]),
+        _ => return Err(invalid()),
+    };
+
+    let digits: String = rest.chars().filter(|c| *c != ':').collect();
+    if !digits.chars().all(|c| c.is_ascii_digit()) || !matches!(digits.len(), 2 | 4) {
+        return Err(invalid());
+    }
+    if rest.contains(':') && rest.find(':') != Some(2) {
+        return Err(invalid());
+    }
+
+    let hours: i32 = digits[..2].parse().map_err(|_| invalid())?;
+    let minutes: i32 = if digits.len() == 4 {
+        digits[2..].parse().map_err(|_| invalid())?
+    } else {
+        0
+    };
+    if hours > 23 || minutes > 59 {
+        return Err(invalid());
+    }
+
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60)).ok_or_else(invalid)
+}
+
+/// Convert a secret custom value to the given timezone.
+fn convert_secret_date(
+    val: &dyn nu_protocol::CustomValue,
+    offset: &FixedOffset,
+    span: nu_protocol::Span,
+) -> Result<Value, LabeledError> {
+    if let Some(secret_date) = val.as_any().downcast_ref::<SecretDate>() {
+        Ok(Value::custom(
+            Box::new(secret_date.with_timezone(offset)),
+            span,
+        ))
+    } else {
+        Err(LabeledError::new("Unsupported secret type")
+            .with_label("Only SecretDate supports timezone conversion", span))
+    }
+}
+
+impl PluginCommand for SecretDateToTimezoneCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret date to-timezone"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(
+                Type::Custom("secret_date".into()),
+                Type::Custom("secret_date".into()),
+            )])
+            .required(
+                "timezone",
+                SyntaxShape::String,
+                "Target timezone: UTC or a fixed offset such as +05:30",
+            )
+            .category(Category::Date)
+    }
+
+    fn description(&self) -> &str {
+        "Convert a secret date to another timezone without revealing it"
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                example: r#"(date now) | secret wrap | secret date to-timezone UTC"#,
+                description: "Normalize a secret date to UTC",
+                result: None,
+            },
+            Example {
+                example: r#"(date now) | secret wrap | secret date to-timezone "+05:30""#,
+                description: "Convert a secret date to a fixed offset",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let timezone: String = call.req(0)?;
+        let offset = parse_timezone(&timezone)
+            .map_err(|e| LabeledError::new("Invalid timezone").with_label(e, call.head))?;
+
+        match input {
+            PipelineData::Value(value, metadata) => {
+                let result = match value {
+                    Value::Custom { val, .. } => {
+                        convert_secret_date(val.as_ref(), &offset, call.head)?
+                    }
+                    _ => {
+                        return Err(LabeledError::new("Invalid input").with_label(
+                            "Input must be a secret date. Use 'secret wrap' to create a secret first",
+                            call.head,
+                        ));
+                    }
+                };
+
+                Ok(PipelineData::Value(result, metadata))
+            }
+            _ => Err(LabeledError::new("Invalid input")
+                .with_label("Expected a single secret value", call.head)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SecretInt;
+    use chrono::{TimeZone, Utc};
+    use nu_protocol::{CustomValue, Span};
+
+    #[test]
+    fn test_command_name() {
+        let command = SecretDateToTimezoneCommand;
+        assert_eq!(command.name(), "secret date to-timezone");
+    }
+
+    #[test]
+    fn test_signature() {
+        let command = SecretDateToTimezoneCommand;
+        let signature = command.signature();
+        assert_eq!(signature.name, "secret date to-timezone");
+        assert_eq!(signature.required_positional.len(), 1);
+        assert_eq!(signature.input_output_types.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_timezone_valid() {
+        assert_eq!(parse_timezone("UTC").unwrap().local_minus_utc(), 0);
+        assert_eq!(parse_timezone("z").unwrap().local_minus_utc(), 0);
+        assert_eq!(parse_timezone("+05:30").unwrap().local_minus_utc(), 19800);
+        assert_eq!(parse_timezone("-0800").unwrap().local_minus_utc(), -28800);
+        assert_eq!(parse_timezone("+02").unwrap().local_minus_utc(), 7200);
+    }
+
+    #[test]
+    fn test_parse_timezone_invalid() {
+        for tz in [
+            "Mars/Olympus",
+            "",
+            "+5",
+            "+25:00",
+            "+05:75",
+            "05:00",
+            "+0:530",
+        ] {
+            assert!(
+                parse_timezone(tz).is_err(),
+                "Expected '{}' to be rejected",
+                tz
+            );
+        }
+    }
+
+    #[test]
+    fn test_convert_utc_to_fixed_offset_and_back() {
+        let span = Span::test_data();
+        let utc: chrono::DateTime<FixedOffset> = Utc.timestamp_opt(1699123200, 0).unwrap().into();
+        let secret = SecretDate::new(utc);
+
+        let offset = parse_timezone("+05:30").unwrap();
+        let converted = convert_secret_date(&secret, &offset, span).unwrap();
+        let Value::Custom { val, .. } = converted else {
+            panic!("Expected custom value");
+        };
+        let shifted = val.as_any().downcast_ref::<SecretDate>().unwrap();
+        assert_eq!(shifted.reveal(), &utc);
+        assert_eq!(shifted.reveal().offset().local_minus_utc(), 19800);
+
+        // Result stays redacted
+        let base = shifted.to_base_value(span).unwrap();
+        assert!(base.as_str().unwrap().contains("redacted"));
+
+        let back = convert_secret_date(shifted, &parse_timezone("UTC").unwrap(), span).unwrap();
+        let Value::Custom { val, .. } = back else {
+            panic!("Expected custom value");
+        };
+        let restored = val.as_any().downcast_ref::<SecretDate>().unwrap();
+        assert_eq!(restored.reveal().offset().local_minus_utc(), 0);
+        assert_eq!(restored.reveal(), &utc);
+    }
+
+    #[test]
+    fn test_convert_rejects_non_date_secret() {
+        let secret = SecretInt::new(42);
+        let offset = parse_timezone("UTC").unwrap();
+        assert!(convert_secret_date(&secret, &offset, Span::test_data()).is_err());
+    }
+}
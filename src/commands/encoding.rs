@@ -0,0 +1,298 @@
+//! Implements `secret to-base64` and `secret from-base64` — encode and decode
+//! secrets as base64 without revealing them.
+
+use base64::engine::general_purpose::{STANDARD, URL_SAFE};
+use base64::Engine;
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{Category, Example, LabeledError, PipelineData, Signature, Span, Type, Value};
+
+use crate::{SecretBinary, SecretString};
+
+#[derive(Clone)]
+pub struct SecretToBase64Command;
+
+#[derive(Clone)]
+pub struct SecretFromBase64Command;
+
+/// Select the standard or URL-safe base64 alphabet (both padded).
+fn base64_engine(url_safe: bool) -> &'static base64::engine::GeneralPurpose {
+    if url_safe {
+        &URL_SAFE
+    } else {
+        &STANDARD
+    }
+}
+
+/// Encode a `SecretString` or `SecretBinary` as base64, producing a new `SecretString`.
+fn secret_to_base64(
+    val: &dyn nu_protocol::CustomValue,
+    url_safe: bool,
+    span: Span,
+) -> Result<Value, LabeledError> {
+    let engine = base64_engine(url_safe);
+    let encoded = if let Some(secret_string) = val.as_any().downcast_ref::<SecretString>() {
+        engine.encode(secret_string.reveal().as_bytes())
+    } else if let Some(secret_binary) = val.as_any().downcast_ref::<SecretBinary>() {
+        engine.encode(secret_binary.reveal())
+    } else {
+        return Err(LabeledError::new("Unsupported secret type").with_label(
+            "Only SecretString and SecretBinary support base64 encoding",
+            span,
+        ));
+    };
+
+    Ok(Value::custom(Box::new(SecretString::new(encoded)), span))
+}
+
+/// Decode the base64 text of a `SecretString`, producing a new `SecretBinary`.
+///
+/// Decode errors never echo the secret's content.
+fn secret_from_base64(
+    val: &dyn nu_protocol::CustomValue,
+    url_safe: bool,
+    span: Span,
+) -> Result<Value, LabeledError> {
+    if let Some(secret_string) = val.as_any().downcast_ref::<SecretString>() {
+        let bytes = base64_engine(url_safe)
+            .decode(secret_string.reveal().trim())
+            .map_err(|e| {
+                let reason = match e {
+                    base64::DecodeError::InvalidByte(offset, _) => {
+                        format!("invalid character at offset {}", offset)
+                    }
+                    base64::DecodeError::InvalidLength(_) => "invalid length".to_string(),
+                    base64::DecodeError::InvalidLastSymbol(offset, _) => {
+                        format!("invalid trailing symbol at offset {}", offset)
+                    }
+                    base64::DecodeError::InvalidPadding => "invalid padding".to_string(),
+                };
+                LabeledError::new("Invalid base64").with_label(
+                    format!("Secret string is not valid base64 ({})", reason),
+                    span,
+                )
+            })?;
+        Ok(Value::custom(Box::new(SecretBinary::new(bytes)), span))
+    } else {
+        Err(LabeledError::new("Unsupported secret type")
+            .with_label("Only SecretString supports base64 decoding", span))
+    }
+}
+
+/// Shared `run` implementation for both base64 commands.
+fn run_base64(
+    call: &EvaluatedCall,
+    input: PipelineData,
+    convert: fn(&dyn nu_protocol::CustomValue, bool, Span) -> Result<Value, LabeledError>,
+) -> Result<PipelineData, LabeledError> {
+    let url_safe = call.has_flag("url-safe")?;
+
+    match input {
+        PipelineData::Value(value, metadata) => {
+            let result = match value {
+                Value::Custom { val, .. } => convert(val.as_ref(), url_safe, call.head)?,
+                _ => {
+                    return Err(LabeledError::new("Invalid input").with_label(
+                        "Input must be a secret value. Use 'secret wrap' to create a secret first",
+                        call.head,
+                    ));
+                }
+            };
+
+            Ok(PipelineData::Value(result, metadata))
+        }
+        _ => Err(LabeledError::new("Invalid input")
+            .with_label("Expected a single secret value", call.head)),
+    }
+}
+
+impl PluginCommand for SecretToBase64Command {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret to-base64"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![
+                (
+                    Type::Custom("secret_string".into()),
+                    Type::Custom("secret_string".into()),
+                ),
+                (
+                    Type::Custom("secret_binary".into()),
+                    Type::Custom("secret_string".into()),
+                ),
+            ])
+            .switch("url-safe", "Use the URL-safe base64 alphabet", Some('u'))
+            .category(Category::Formats)
+    }
+
+    fn description(&self) -> &str {
+        "Encode a secret string or binary as base64 into a secret string without revealing it"
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                example: r#""hunter2" | secret wrap | secret to-base64"#,
+                description: "Base64-encode a secret string",
+                result: None,
+            },
+            Example {
+                example: r#"0x[fb ff] | secret wrap | secret to-base64 --url-safe"#,
+                description: "Base64-encode a secret binary with the URL-safe alphabet",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        run_base64(call, input, secret_to_base64)
+    }
+}
+
+impl PluginCommand for SecretFromBase64Command {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret from-base64"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(
+                Type::Custom("secret_string".into()),
+                Type::Custom("secret_binary".into()),
+            )])
+            .switch("url-safe", "Use the URL-safe base64 alphabet", Some('u'))
+            .category(Category::Formats)
+    }
+
+    fn description(&self) -> &str {
+        "Decode a base64 secret string into a secret binary without revealing it"
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![Example {
+            example: r#""aHVudGVyMg==" | secret wrap | secret from-base64"#,
+            description: "Decode a base64 secret string into a secret binary",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        run_base64(call, input, secret_from_base64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SecretInt;
+
+    fn reveal_string(value: &Value) -> String {
+        value
+            .as_custom_value()
+            .unwrap()
+            .as_any()
+            .downcast_ref::<SecretString>()
+            .unwrap()
+            .reveal()
+            .to_string()
+    }
+
+    fn reveal_binary(value: &Value) -> Vec<u8> {
+        value
+            .as_custom_value()
+            .unwrap()
+            .as_any()
+            .downcast_ref::<SecretBinary>()
+            .unwrap()
+            .reveal()
+            .into_owned()
+    }
+
+    #[test]
+    fn test_command_names() {
+        assert_eq!(SecretToBase64Command.name(), "secret to-base64");
+        assert_eq!(SecretFromBase64Command.name(), "secret from-base64");
+    }
+
+    #[test]
+    fn test_signature() {
+        let signature = SecretToBase64Command.signature();
+        assert_eq!(signature.input_output_types.len(), 2);
+        assert!(signature.named.iter().any(|flag| flag.long == "url-safe"));
+
+        let signature = SecretFromBase64Command.signature();
+        assert_eq!(signature.input_output_types.len(), 1);
+        assert!(signature.named.iter().any(|flag| flag.long == "url-safe"));
+    }
+
+    #[test]
+    fn test_encode_string() {
+        let secret = SecretString::new("hunter2".to_string());
+        let result = secret_to_base64(&secret, false, Span::test_data()).unwrap();
+        assert_eq!(reveal_string(&result), "aHVudGVyMg==");
+    }
+
+    #[test]
+    fn test_encode_binary_alphabets() {
+        let secret = SecretBinary::new(vec![0xfb, 0xff]);
+        let standard = secret_to_base64(&secret, false, Span::test_data()).unwrap();
+        let url_safe = secret_to_base64(&secret, true, Span::test_data()).unwrap();
+        assert_eq!(reveal_string(&standard), "+/8=");
+        assert_eq!(reveal_string(&url_safe), "-_8=");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let span = Span::test_data();
+        let data: Vec<u8> = (0..=255).collect();
+        for url_safe in [false, true] {
+            let encoded =
+                secret_to_base64(&SecretBinary::new(data.clone()), url_safe, span).unwrap();
+            let decoded =
+                secret_from_base64(encoded.as_custom_value().unwrap(), url_safe, span).unwrap();
+            assert_eq!(reveal_binary(&decoded), data);
+        }
+
+        let encoded =
+            secret_to_base64(&SecretString::new("🔑 clé".to_string()), false, span).unwrap();
+        let decoded = secret_from_base64(encoded.as_custom_value().unwrap(), false, span).unwrap();
+        assert_eq!(reveal_binary(&decoded), "🔑 clé".as_bytes());
+    }
+
+    #[test]
+    fn test_invalid_base64_rejected() {
+        let secret = SecretString::new("not*base64".to_string());
+        let err = secret_from_base64(&secret, false, Span::test_data()).unwrap_err();
+        assert_eq!(err.msg, "Invalid base64");
+        assert!(!format!("{:?}", err).contains("not*base64"));
+
+        // URL-safe characters are invalid in the standard alphabet.
+        let secret = SecretString::new("-_8=".to_string());
+        assert!(secret_from_base64(&secret, false, Span::test_data()).is_err());
+    }
+
+    #[test]
+    fn test_rejects_unsupported_secrets() {
+        let span = Span::test_data();
+        assert!(secret_to_base64(&SecretInt::new(1), false, span).is_err());
+        let binary = SecretBinary::new(vec![1, 2, 3]);
+        assert!(secret_from_base64(&binary, false, span).is_err());
+    }
+}
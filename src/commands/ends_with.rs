@@ -0,0 +1,140 @@
+//! Implements `secret ends-with` — checks a secret string's suffix without revealing it.
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, Signature, SyntaxShape, Type, Value,
+};
+
+use crate::SecretString;
+
+#[derive(Clone)]
+pub struct SecretEndsWithCommand;
+
+/// Check whether a secret custom value ends with `suffix`, returning a boolean result.
+///
+/// Returns a type mismatch error for secrets that are not a `SecretString`.
+fn secret_ends_with(
+    val: &dyn nu_protocol::CustomValue,
+    suffix: &str,
+    span: nu_protocol::Span,
+) -> Result<Value, LabeledError> {
+    if let Some(secret_string) = val.as_any().downcast_ref::<SecretString>() {
+        Ok(Value::bool(secret_string.reveal().ends_with(suffix), span))
+    } else {
+        Err(LabeledError::new("Type mismatch").with_label(
+            format!("Expected secret_string, found {}", val.type_name()),
+            span,
+        ))
+    }
+}
+
+impl PluginCommand for SecretEndsWithCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret ends-with"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Custom("secret_string".into()), Type::Bool)])
+            .required("suffix", SyntaxShape::String, "Suffix to check for")
+            .category(Category::Strings)
+    }
+
+    fn description(&self) -> &str {
+        "Check if a secret string ends with the given suffix without revealing it"
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![Example {
+            example: r#""user@example.com" | secret wrap | secret ends-with "@example.com""#,
+            description: "Check whether a secret email belongs to a domain",
+            result: Some(Value::test_bool(true)),
+        }]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let suffix: String = call.req(0)?;
+
+        match input {
+            PipelineData::Value(value, metadata) => {
+                let result = match value {
+                    Value::Custom { val, .. } => {
+                        secret_ends_with(val.as_ref(), &suffix, call.head)?
+                    }
+                    _ => {
+                        return Err(LabeledError::new("Invalid input").with_label(
+                            "Input must be a secret string. Use 'secret wrap' to create a secret first",
+                            call.head,
+                        ));
+                    }
+                };
+
+                Ok(PipelineData::Value(result, metadata))
+            }
+            _ => Err(LabeledError::new("Invalid input")
+                .with_label("Expected a single secret value", call.head)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SecretInt;
+    use nu_protocol::Span;
+
+    fn check(secret: &str, suffix: &str) -> bool {
+        let secret = SecretString::new(secret.to_string());
+        secret_ends_with(&secret, suffix, Span::test_data())
+            .unwrap()
+            .as_bool()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_command_name() {
+        let command = SecretEndsWithCommand;
+        assert_eq!(command.name(), "secret ends-with");
+    }
+
+    #[test]
+    fn test_signature() {
+        let command = SecretEndsWithCommand;
+        let signature = command.signature();
+        assert_eq!(signature.name, "secret ends-with");
+        assert_eq!(signature.required_positional.len(), 1);
+    }
+
+    #[test]
+    fn test_ends_with() {
+        assert!(check("user@example.com", "@example.com"));
+        assert!(!check("user@example.com", "@example.org"));
+    }
+
+    #[test]
+    fn test_ends_with_unicode() {
+        assert!(check("token-é🔑", "é🔑"));
+        assert!(!check("token-é🔑", "🔒"));
+    }
+
+    #[test]
+    fn test_ends_with_empty_suffix() {
+        assert!(check("anything", ""));
+        assert!(check("", ""));
+        assert!(!check("", "x"));
+    }
+
+    #[test]
+    fn test_ends_with_rejects_non_string_secret() {
+        let err = secret_ends_with(&SecretInt::new(42), "4", Span::test_data()).unwrap_err();
+        assert_eq!(err.msg, "Type mismatch");
+    }
+}
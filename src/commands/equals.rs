@@ -0,0 +1,173 @@
+//! Implements `secret equals` — compares two secrets without revealing either.
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    ast::{Comparison, Operator},
+    Category, Example, LabeledError, PipelineData, Signature, Span, SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct SecretEqualsCommand;
+
+/// Compare two secret custom values of the same type, returning a boolean.
+///
+/// Equality goes through the secret type's constant-time `PartialEq` (via
+/// `CustomValue::operation`), so neither side is revealed. A plain value or a
+/// secret of a different type is an error naming only the types involved.
+fn secrets_equal(
+    val: &dyn nu_protocol::CustomValue,
+    other: &Value,
+    span: Span,
+) -> Result<Value, LabeledError> {
+    let Value::Custom { val: other_val, .. } = other else {
+        return Err(LabeledError::new("Invalid argument").with_label(
+            format!(
+                "Expected a secret to compare against, got '{}'. Use 'secret contains' to compare with a plain value",
+                other.get_type()
+            ),
+            span,
+        ));
+    };
+
+    if other_val.type_name() != val.type_name() {
+        return Err(LabeledError::new("Type mismatch").with_label(
+            format!(
+                "Cannot compare '{}' with '{}'",
+                val.type_name(),
+                other_val.type_name()
+            ),
+            span,
+        ));
+    }
+
+    val.operation(span, Operator::Comparison(Comparison::Equal), span, other)
+        .map_err(LabeledError::from)
+}
+
+impl PluginCommand for SecretEqualsCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret equals"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Any, Type::Bool)])
+            .required(
+                "other",
+                SyntaxShape::Any,
+                "Secret of the same type to compare against",
+            )
+            .category(Category::Filters)
+    }
+
+    fn description(&self) -> &str {
+        "Check whether two secrets of the same type are equal without revealing either"
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![Example {
+            example: r#"$provided_token | secret equals $stored_token"#,
+            description: "Compare a provided secret token with a stored one in constant time",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let other: Value = call.req(0)?;
+
+        match input {
+            PipelineData::Value(Value::Custom { val, .. }, metadata) => {
+                let result = secrets_equal(val.as_ref(), &other, call.head)?;
+                Ok(PipelineData::Value(result, metadata))
+            }
+            PipelineData::Value(value, _) => Err(LabeledError::new("Invalid input").with_label(
+                format!(
+                    "Expected a secret value, got '{}'. Use 'secret wrap' to create a secret first",
+                    value.get_type()
+                ),
+                call.head,
+            )),
+            _ => Err(LabeledError::new("Invalid input")
+                .with_label("Expected a single secret value", call.head)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{SecretBinary, SecretInt, SecretString};
+
+    fn secret<T: nu_protocol::CustomValue>(value: T) -> Value {
+        Value::custom(Box::new(value), Span::test_data())
+    }
+
+    #[test]
+    fn test_command_name() {
+        let command = SecretEqualsCommand;
+        assert_eq!(command.name(), "secret equals");
+    }
+
+    #[test]
+    fn test_signature() {
+        let command = SecretEqualsCommand;
+        let signature = command.signature();
+        assert_eq!(signature.name, "secret equals");
+        assert_eq!(signature.required_positional.len(), 1);
+    }
+
+    #[test]
+    fn test_equal_secrets() {
+        let span = Span::test_data();
+        let provided = SecretString::new("token-123".to_string());
+        let stored = secret(SecretString::new("token-123".to_string()));
+
+        let result = secrets_equal(&provided, &stored, span).unwrap();
+        assert_eq!(result, Value::bool(true, span));
+
+        let bytes = SecretBinary::new(vec![1, 2, 3]);
+        let result = secrets_equal(&bytes, &secret(SecretBinary::new(vec![1, 2, 3])), span);
+        assert_eq!(result.unwrap(), Value::bool(true, span));
+    }
+
+    #[test]
+    fn test_unequal_secrets() {
+        let span = Span::test_data();
+        let provided = SecretString::new("token-123".to_string());
+
+        let result = secrets_equal(
+            &provided,
+            &secret(SecretString::new("token-124".to_string())),
+            span,
+        );
+        assert_eq!(result.unwrap(), Value::bool(false, span));
+
+        let result = secrets_equal(
+            &provided,
+            &secret(SecretString::new("token".to_string())),
+            span,
+        );
+        assert_eq!(result.unwrap(), Value::bool(false, span));
+    }
+
+    #[test]
+    fn test_mismatched_types_are_rejected() {
+        let span = Span::test_data();
+        let provided = SecretString::new("42".to_string());
+
+        let err = secrets_equal(&provided, &secret(SecretInt::new(42)), span).unwrap_err();
+        assert_eq!(err.msg, "Type mismatch");
+        assert!(!format!("{:?}", err).contains("42"));
+
+        let err = secrets_equal(&provided, &Value::string("42", span), span).unwrap_err();
+        assert_eq!(err.msg, "Invalid argument");
+    }
+}
@@ -0,0 +1,219 @@
+//! Implements `secret float to-int` — converts a secret float to a secret int.
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{Category, Example, LabeledError, PipelineData, Signature, Span, Type, Value};
+
+use crate::{SecretFloat, SecretInt};
+
+#[derive(Clone)]
+pub struct SecretFloatToIntCommand;
+
+/// How a fractional value is turned into an integer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum RoundingMode {
+    Round,
+    Truncate,
+    Ceil,
+    Floor,
+}
+
+impl RoundingMode {
+    fn apply(self, value: f64) -> f64 {
+        match self {
+            RoundingMode::Round => value.round(),
+            RoundingMode::Truncate => value.trunc(),
+            RoundingMode::Ceil => value.ceil(),
+            RoundingMode::Floor => value.floor(),
+        }
+    }
+}
+
+/// Read the rounding mode flags, defaulting to truncation.
+fn parse_rounding_mode(call: &EvaluatedCall) -> Result<RoundingMode, LabeledError> {
+    let mut selected = Vec::new();
+    for (flag, mode) in [
+        ("round", RoundingMode::Round),
+        ("truncate", RoundingMode::Truncate),
+        ("ceil", RoundingMode::Ceil),
+        ("floor", RoundingMode::Floor),
+    ] {
+        if call.has_flag(flag)? {
+            selected.push(mode);
+        }
+    }
+
+    match selected.as_slice() {
+        [] => Ok(RoundingMode::Truncate),
+        [mode] => Ok(*mode),
+        _ => Err(LabeledError::new("Conflicting flags").with_label(
+            "Use only one of --round, --truncate, --ceil or --floor",
+            call.head,
+        )),
+    }
+}
+
+/// Convert a float to an integer using `mode`, failing if the result does not fit in an `i64`.
+///
+/// The error message never includes the value itself.
+fn float_to_int(value: f64, mode: RoundingMode) -> Result<i64, String> {
+    let rounded = mode.apply(value);
+    // i64::MAX is not exactly representable as f64; 2^63 is the first value out of range.
+    if !rounded.is_finite() || rounded < i64::MIN as f64 || rounded >= 9_223_372_036_854_775_808.0 {
+        return Err("Secret float is out of range for an int".to_string());
+    }
+    Ok(rounded as i64)
+}
+
+/// Convert a secret custom value to a `SecretInt`.
+fn convert_secret_float(
+    val: &dyn nu_protocol::CustomValue,
+    mode: RoundingMode,
+    span: Span,
+) -> Result<Value, LabeledError> {
+    if let Some(secret_float) = val.as_any().downcast_ref::<SecretFloat>() {
+        let int = float_to_int(secret_float.reveal(), mode)
+            .map_err(|e| LabeledError::new("Conversion failed").with_label(e, span))?;
+        Ok(Value::custom(Box::new(SecretInt::new(int)), span))
+    } else {
+        Err(LabeledError::new("Unsupported secret type")
+            .with_label("Only SecretFloat can be converted to a secret int", span))
+    }
+}
+
+impl PluginCommand for SecretFloatToIntCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret float to-int"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(
+                Type::Custom("secret_float".into()),
+                Type::Custom("secret_int".into()),
+            )])
+            .switch("round", "Round to the nearest integer", Some('r'))
+            .switch("truncate", "Drop the fractional part (default)", Some('t'))
+            .switch("ceil", "Round up towards positive infinity", Some('c'))
+            .switch("floor", "Round down towards negative infinity", Some('f'))
+            .category(Category::Conversions)
+    }
+
+    fn description(&self) -> &str {
+        "Convert a secret float to a secret int without revealing it"
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                example: r#"3.7 | secret wrap | secret float to-int"#,
+                description: "Truncate a secret float to a secret int",
+                result: None,
+            },
+            Example {
+                example: r#"3.7 | secret wrap | secret float to-int --round"#,
+                description: "Round a secret float to the nearest secret int",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let mode = parse_rounding_mode(call)?;
+
+        match input {
+            PipelineData::Value(value, metadata) => {
+                let result = match value {
+                    Value::Custom { val, .. } => {
+                        convert_secret_float(val.as_ref(), mode, call.head)?
+                    }
+                    _ => {
+                        return Err(LabeledError::new("Invalid input").with_label(
+                            "Input must be a secret float. Use 'secret wrap' to create a secret first",
+                            call.head,
+                        ));
+                    }
+                };
+
+                Ok(PipelineData::Value(result, metadata))
+            }
+            _ => Err(LabeledError::new("Invalid input")
+                .with_label("Expected a single secret value", call.head)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_command_name() {
+        let command = SecretFloatToIntCommand;
+        assert_eq!(command.name(), "secret float to-int");
+    }
+
+    #[test]
+    fn test_signature() {
+        let command = SecretFloatToIntCommand;
+        let signature = command.signature();
+        assert_eq!(signature.name, "secret float to-int");
+        for flag in ["round", "truncate", "ceil", "floor"] {
+            assert!(
+                signature.get_long_flag(flag).is_some(),
+                "missing --{}",
+                flag
+            );
+        }
+    }
+
+    #[test]
+    fn test_rounding_modes() {
+        assert_eq!(float_to_int(2.5, RoundingMode::Round), Ok(3));
+        assert_eq!(float_to_int(-2.5, RoundingMode::Round), Ok(-3));
+        assert_eq!(float_to_int(2.7, RoundingMode::Truncate), Ok(2));
+        assert_eq!(float_to_int(-2.7, RoundingMode::Truncate), Ok(-2));
+        assert_eq!(float_to_int(2.1, RoundingMode::Ceil), Ok(3));
+        assert_eq!(float_to_int(-2.1, RoundingMode::Ceil), Ok(-2));
+        assert_eq!(float_to_int(2.9, RoundingMode::Floor), Ok(2));
+        assert_eq!(float_to_int(-2.1, RoundingMode::Floor), Ok(-3));
+    }
+
+    #[test]
+    fn test_overflow_errors() {
+        assert!(float_to_int(1e19, RoundingMode::Truncate).is_err());
+        assert!(float_to_int(-1e19, RoundingMode::Truncate).is_err());
+        assert!(float_to_int(f64::NAN, RoundingMode::Round).is_err());
+        assert!(float_to_int(f64::INFINITY, RoundingMode::Floor).is_err());
+        assert_eq!(
+            float_to_int(i64::MIN as f64, RoundingMode::Truncate),
+            Ok(i64::MIN)
+        );
+    }
+
+    #[test]
+    fn test_convert_secret_float() {
+        let span = Span::test_data();
+        let secret = SecretFloat::new(9.99);
+        let result = convert_secret_float(&secret, RoundingMode::Floor, span).unwrap();
+        let custom = result.as_custom_value().unwrap();
+        assert_eq!(
+            custom
+                .as_any()
+                .downcast_ref::<SecretInt>()
+                .unwrap()
+                .reveal(),
+            9
+        );
+
+        let not_float = SecretInt::new(1);
+        assert!(convert_secret_float(&not_float, RoundingMode::Floor, span).is_err());
+    }
+}
@@ -0,0 +1,191 @@
+//! Implements `secret get` — extracts a single field of a secret record as a
+//! secret of the field's type.
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, Signature, Span, SyntaxShape, Type, Value,
+};
+
+use super::wrap::wrap_value;
+use crate::SecretRecord;
+
+#[derive(Clone)]
+pub struct SecretGetCommand;
+
+/// Look up `field` in a secret record and return it wrapped in its secret type.
+///
+/// Fields that are already secrets are returned as-is. A missing field is an
+/// error listing the available field names, or `nothing` when `optional` is set.
+fn get_secret_field(
+    record: &SecretRecord,
+    field: &str,
+    optional: bool,
+    span: Span,
+) -> Result<Value, LabeledError> {
+    match record.get_field(field) {
+        Some(value @ Value::Custom { .. }) => Ok(value.clone().with_span(span)),
+        Some(value) => wrap_value(value.clone(), span),
+        None if optional => Ok(Value::nothing(span)),
+        None => {
+            let available = record
+                .fields()
+                .map(String::as_str)
+                .collect::<Vec<_>>()
+                .join(", ");
+            Err(LabeledError::new("Field not found").with_label(
+                format!(
+                    "Secret record has no field '{}'. Available fields: {}",
+                    field, available
+                ),
+                span,
+            ))
+        }
+    }
+}
+
+impl PluginCommand for SecretGetCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret get"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Custom("secret_record".into()), Type::Any)])
+            .required("field", SyntaxShape::String, "Name of the field to extract")
+            .switch(
+                "optional",
+                "Return null instead of an error when the field is missing",
+                Some('o'),
+            )
+            .category(Category::Filters)
+    }
+
+    fn description(&self) -> &str {
+        "Extract a field of a secret record as a secret without revealing it"
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                example: r#"{user: "admin", password: "hunter2"} | secret wrap | secret get password"#,
+                description: "Extract the password field as a secret string",
+                result: None,
+            },
+            Example {
+                example: r#"{user: "admin"} | secret wrap | secret get token --optional"#,
+                description: "Return null when the field is missing",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let field: String = call.req(0)?;
+        let optional = call.has_flag("optional")?;
+
+        match input {
+            PipelineData::Value(Value::Custom { val, .. }, metadata) => {
+                let secret_record =
+                    val.as_any().downcast_ref::<SecretRecord>().ok_or_else(|| {
+                        LabeledError::new("Unsupported secret type")
+                            .with_label("Only SecretRecord supports get", call.head)
+                    })?;
+
+                let result = get_secret_field(secret_record, &field, optional, call.head)?;
+                Ok(PipelineData::Value(result, metadata))
+            }
+            PipelineData::Value(_, _) => Err(LabeledError::new("Invalid input").with_label(
+                "Input must be a secret record. Use 'secret wrap' to create a secret first",
+                call.head,
+            )),
+            _ => Err(LabeledError::new("Invalid input")
+                .with_label("Expected a single secret value", call.head)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{SecretBool, SecretInt, SecretString};
+    use nu_protocol::Record;
+
+    fn credentials() -> SecretRecord {
+        let span = Span::test_data();
+        let mut record = Record::new();
+        record.push("user", Value::string("admin", span));
+        record.push("port", Value::int(5432, span));
+        record.push("tls", Value::bool(true, span));
+        record.push(
+            "token",
+            Value::custom(Box::new(SecretString::new("abc".to_string())), span),
+        );
+        SecretRecord::new(record)
+    }
+
+    #[test]
+    fn test_command_name() {
+        let command = SecretGetCommand;
+        assert_eq!(command.name(), "secret get");
+    }
+
+    #[test]
+    fn test_signature() {
+        let command = SecretGetCommand;
+        let signature = command.signature();
+        assert_eq!(signature.name, "secret get");
+        assert_eq!(signature.required_positional.len(), 1);
+        assert!(signature.get_long_flag("optional").is_some());
+    }
+
+    #[test]
+    fn test_get_field_types() {
+        let span = Span::test_data();
+        let record = credentials();
+
+        let user = get_secret_field(&record, "user", false, span).unwrap();
+        let user = user.as_custom_value().unwrap().as_any();
+        assert_eq!(
+            user.downcast_ref::<SecretString>().unwrap().reveal(),
+            "admin"
+        );
+
+        let port = get_secret_field(&record, "port", false, span).unwrap();
+        let port = port.as_custom_value().unwrap().as_any();
+        assert_eq!(port.downcast_ref::<SecretInt>().unwrap().reveal(), 5432);
+
+        let tls = get_secret_field(&record, "tls", false, span).unwrap();
+        let tls = tls.as_custom_value().unwrap().as_any();
+        assert!(tls.downcast_ref::<SecretBool>().unwrap().reveal());
+
+        let token = get_secret_field(&record, "token", false, span).unwrap();
+        let token = token.as_custom_value().unwrap().as_any();
+        assert_eq!(
+            token.downcast_ref::<SecretString>().unwrap().reveal(),
+            "abc"
+        );
+    }
+
+    #[test]
+    fn test_missing_field() {
+        let span = Span::test_data();
+        let record = credentials();
+
+        let err = get_secret_field(&record, "password", false, span).unwrap_err();
+        assert_eq!(err.msg, "Field not found");
+        let message = format!("{:?}", err);
+        assert!(message.contains("user, port, tls, token"));
+        assert!(!message.contains("admin"));
+
+        let result = get_secret_field(&record, "password", true, span).unwrap();
+        assert!(result.is_nothing());
+    }
+}
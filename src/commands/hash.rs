@@ -12,27 +12,23 @@ use crate::{SecretBinary, SecretList, SecretRecord, SecretString};
 #[derive(Clone)]
 pub struct SecretHashCommand;
 
-/// Compute the hash of a secret custom value using the given algorithm.
+/// Run `f` over the bytes of a secret custom value.
 ///
 /// Dispatches to the appropriate inner data extraction for each supported
 /// secret type (SecretString, SecretBinary, SecretList, SecretRecord).
-fn hash_secret_value(
+fn with_secret_bytes<R>(
     val: &dyn nu_protocol::CustomValue,
-    algorithm: &HashAlgorithm,
     span: nu_protocol::Span,
-) -> Result<Value, LabeledError> {
+    f: impl FnOnce(&[u8]) -> R,
+) -> Result<R, LabeledError> {
     if let Some(secret_string) = val.as_any().downcast_ref::<SecretString>() {
-        let data = secret_string.reveal().as_bytes();
-        Ok(Value::string(compute_hash(algorithm, data), span))
+        Ok(f(secret_string.reveal().as_bytes()))
     } else if let Some(secret_binary) = val.as_any().downcast_ref::<SecretBinary>() {
-        let data = secret_binary.reveal();
-        Ok(Value::string(compute_hash(algorithm, &data), span))
+        Ok(f(secret_binary.reveal().as_ref()))
     } else if let Some(secret_list) = val.as_any().downcast_ref::<SecretList>() {
-        let data = serialize_list_for_hash(secret_list)?;
-        Ok(Value::string(compute_hash(algorithm, &data), span))
+        Ok(f(serialize_list_for_hash(secret_list)?.as_slice()))
     } else if let Some(secret_record) = val.as_any().downcast_ref::<SecretRecord>() {
-        let data = serialize_record_for_hash(secret_record)?;
-        Ok(Value::string(compute_hash(algorithm, &data), span))
+        Ok(f(serialize_record_for_hash(secret_record)?.as_slice()))
     } else {
         Err(LabeledError::new("Unsupported secret type").with_label(
             "Only SecretString, SecretBinary, SecretList, and SecretRecord support hash operation",
@@ -41,7 +37,65 @@ fn hash_secret_value(
     }
 }
 
-#[derive(Clone, Debug)]
+/// Compute the hex hash of a secret custom value using the given algorithm.
+///
+/// A non-empty `salt` is prepended to the secret bytes before hashing.
+fn hash_secret_value(
+    val: &dyn nu_protocol::CustomValue,
+    algorithm: &HashAlgorithm,
+    salt: &[u8],
+    span: nu_protocol::Span,
+) -> Result<Value, LabeledError> {
+    with_secret_bytes(val, span, |data| {
+        Value::string(compute_salted_hash(algorithm, salt, data), span)
+    })
+}
+
+/// Compute the hash of a secret custom value in the requested output form.
+///
+/// `Binary` wraps the raw digest bytes in a `SecretBinary`, so the digest is
+/// never placed in the pipeline as plain data.
+fn hash_secret_output(
+    val: &dyn nu_protocol::CustomValue,
+    algorithm: &HashAlgorithm,
+    salt: &[u8],
+    output: HashOutput,
+    span: nu_protocol::Span,
+) -> Result<Value, LabeledError> {
+    match output {
+        HashOutput::Hex => hash_secret_value(val, algorithm, salt, span),
+        HashOutput::Binary => with_secret_bytes(val, span, |data| {
+            let digest = compute_salted_digest(algorithm, salt, data);
+            Value::custom(Box::new(SecretBinary::new(digest)), span)
+        }),
+    }
+}
+
+/// Form of the hash produced by `secret hash`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum HashOutput {
+    /// Lowercase hex string
+    Hex,
+    /// Raw digest bytes wrapped in a `SecretBinary`
+    Binary,
+}
+
+impl std::str::FromStr for HashOutput {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "hex" => Ok(HashOutput::Hex),
+            "binary" => Ok(HashOutput::Binary),
+            _ => Err(format!(
+                "Unknown output '{}'. Valid options: hex, binary",
+                s
+            )),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum HashAlgorithm {
     Sha256,
     Sha512,
@@ -74,6 +128,73 @@ impl std::str::FromStr for HashAlgorithm {
     }
 }
 
+/// Extract the salt bytes from a string, binary, or secret string/binary value.
+///
+/// Errors only name the salt's type, never its content.
+fn salt_bytes(salt: &Value, span: nu_protocol::Span) -> Result<Vec<u8>, LabeledError> {
+    let mismatch = |type_name: String| {
+        LabeledError::new("Invalid salt").with_label(
+            format!("Salt must be a string or binary, found {}", type_name),
+            span,
+        )
+    };
+
+    match salt {
+        Value::String { val, .. } => Ok(val.as_bytes().to_vec()),
+        Value::Binary { val, .. } => Ok(val.clone()),
+        Value::Custom { val, .. } => {
+            if let Some(secret_string) = val.as_any().downcast_ref::<SecretString>() {
+                Ok(secret_string.reveal().as_bytes().to_vec())
+            } else if let Some(secret_binary) = val.as_any().downcast_ref::<SecretBinary>() {
+                Ok(secret_binary.reveal().into_owned())
+            } else {
+                Err(mismatch(val.type_name()))
+            }
+        }
+        _ => Err(mismatch(salt.get_type().to_string())),
+    }
+}
+
+/// Resolve the hash algorithm from the `--algorithm` flag or the positional argument.
+///
+/// Defaults to SHA-256 when neither is given. Giving both is rejected unless
+/// they name the same algorithm.
+fn resolve_algorithm(
+    positional: Option<&Value>,
+    flag: Option<String>,
+    span: nu_protocol::Span,
+) -> Result<HashAlgorithm, LabeledError> {
+    let parse = |name: &str| {
+        name.parse::<HashAlgorithm>().map_err(|e| {
+            LabeledError::new(format!("Invalid hash algorithm: {}", e))
+                .with_label("Supported algorithms: sha256, sha512, blake3", span)
+        })
+    };
+
+    let positional = match positional {
+        Some(Value::String { val, .. }) => Some(parse(val)?),
+        Some(_) => {
+            return Err(LabeledError::new("Invalid algorithm parameter")
+                .with_label("Algorithm must be a string", span))
+        }
+        None => None,
+    };
+    let flag = flag.as_deref().map(parse).transpose()?;
+
+    match (positional, flag) {
+        (Some(positional), Some(flag)) if positional != flag => {
+            Err(LabeledError::new("Conflicting hash algorithms").with_label(
+                format!(
+                    "Algorithm given as both '{}' and --algorithm '{}'",
+                    positional, flag
+                ),
+                span,
+            ))
+        }
+        (positional, flag) => Ok(flag.or(positional).unwrap_or(HashAlgorithm::Sha256)),
+    }
+}
+
 impl PluginCommand for SecretHashCommand {
     type Plugin = crate::SecretPlugin;
 
@@ -88,12 +209,46 @@ impl PluginCommand for SecretHashCommand {
                 (Type::Custom("secret_binary".into()), Type::String),
                 (Type::Custom("secret_list".into()), Type::String),
                 (Type::Custom("secret_record".into()), Type::String),
+                (
+                    Type::Custom("secret_string".into()),
+                    Type::Custom("secret_binary".into()),
+                ),
+                (
+                    Type::Custom("secret_binary".into()),
+                    Type::Custom("secret_binary".into()),
+                ),
+                (
+                    Type::Custom("secret_list".into()),
+                    Type::Custom("secret_binary".into()),
+                ),
+                (
+                    Type::Custom("secret_record".into()),
+                    Type::Custom("secret_binary".into()),
+                ),
             ])
             .optional(
                 "algorithm",
                 SyntaxShape::String,
                 "Hash algorithm to use (sha256, sha512, blake3). Defaults to sha256",
             )
+            .named(
+                "algorithm",
+                SyntaxShape::String,
+                "Hash algorithm to use (sha256, sha512, blake3). Defaults to sha256",
+                Some('a'),
+            )
+            .named(
+                "salt",
+                SyntaxShape::Any,
+                "String or binary salt. The hash is computed over the salt bytes followed by the secret bytes",
+                Some('s'),
+            )
+            .named(
+                "output",
+                SyntaxShape::String,
+                "Output form: hex (default string) or binary (digest bytes as a secret binary)",
+                Some('o'),
+            )
             .category(Category::Hash)
     }
 
@@ -135,6 +290,24 @@ impl PluginCommand for SecretHashCommand {
                     nu_protocol::Span::test_data(),
                 )),
             },
+            Example {
+                example: r#""my-secret-password" | secret wrap | secret hash --algorithm sha512"#,
+                description: "Select the algorithm with a named flag",
+                result: Some(Value::string(
+                    "b109f3bbbc244eb82441917ed06d618b9008dd09b3befd1b5e07394c706a8bb980b1d7785e5976ec049b46df5f1326af5a2ea6d103fd07c95385ffab0cacbc86",
+                    nu_protocol::Span::test_data(),
+                )),
+            },
+            Example {
+                example: r#""my-secret-password" | secret wrap | secret hash --salt "dataset-42""#,
+                description: "Hash a secret with a salt prepended, to resist precomputed lookups",
+                result: None,
+            },
+            Example {
+                example: r#""my-secret-password" | secret wrap | secret hash --output binary"#,
+                description: "Keep the raw digest bytes protected as a secret binary",
+                result: None,
+            },
             Example {
                 example: r#"0x[deadbeef] | secret wrap | secret hash"#,
                 description: "Hash secret binary data",
@@ -153,27 +326,27 @@ impl PluginCommand for SecretHashCommand {
         call: &EvaluatedCall,
         input: PipelineData,
     ) -> Result<PipelineData, LabeledError> {
-        // Parse algorithm parameter
-        let algorithm = if let Some(algo_value) = call.positional.first() {
-            match algo_value {
-                Value::String { val, .. } => val.parse::<HashAlgorithm>().map_err(|e| {
-                    LabeledError::new(format!("Invalid hash algorithm: {}", e))
-                        .with_label("Supported algorithms: sha256, sha512, blake3", call.head)
-                })?,
-                _ => {
-                    return Err(LabeledError::new("Invalid algorithm parameter")
-                        .with_label("Algorithm must be a string", call.head))
-                }
-            }
-        } else {
-            HashAlgorithm::Sha256 // Default algorithm
+        let algorithm = resolve_algorithm(
+            call.positional.first(),
+            call.get_flag::<String>("algorithm")?,
+            call.head,
+        )?;
+        let salt = match call.get_flag::<Value>("salt")? {
+            Some(salt) => salt_bytes(&salt, call.head)?,
+            None => Vec::new(),
+        };
+        let output = match call.get_flag::<String>("output")? {
+            Some(output) => output
+                .parse::<HashOutput>()
+                .map_err(|e| LabeledError::new("Invalid output").with_label(e, call.head))?,
+            None => HashOutput::Hex,
         };
 
         match input {
             PipelineData::Value(value, metadata) => {
                 let result = match value {
                     Value::Custom { val, .. } => {
-                        hash_secret_value(val.as_ref(), &algorithm, call.head)?
+                        hash_secret_output(val.as_ref(), &algorithm, &salt, output, call.head)?
                     }
                     _ => {
                         return Err(LabeledError::new("Invalid input").with_label(
@@ -192,21 +365,34 @@ impl PluginCommand for SecretHashCommand {
 }
 
 fn compute_hash(algorithm: &HashAlgorithm, data: &[u8]) -> String {
+    compute_salted_hash(algorithm, &[], data)
+}
+
+/// Hash `salt` followed by `data`; an empty salt gives the unsalted hash.
+fn compute_salted_hash(algorithm: &HashAlgorithm, salt: &[u8], data: &[u8]) -> String {
+    hex::encode(compute_salted_digest(algorithm, salt, data))
+}
+
+/// Raw digest bytes of `salt` followed by `data`.
+fn compute_salted_digest(algorithm: &HashAlgorithm, salt: &[u8], data: &[u8]) -> Vec<u8> {
     match algorithm {
         HashAlgorithm::Sha256 => {
             let mut hasher = Sha256::new();
+            hasher.update(salt);
             hasher.update(data);
-            hex::encode(hasher.finalize())
+            hasher.finalize().to_vec()
         }
         HashAlgorithm::Sha512 => {
             let mut hasher = Sha512::new();
+            hasher.update(salt);
             hasher.update(data);
-            hex::encode(hasher.finalize())
+            hasher.finalize().to_vec()
         }
         HashAlgorithm::Blake3 => {
             let mut hasher = blake3::Hasher::new();
+            hasher.update(salt);
             hasher.update(data);
-            hex::encode(hasher.finalize().as_bytes())
+            hasher.finalize().as_bytes().to_vec()
         }
     }
 }
@@ -240,7 +426,10 @@ mod tests {
         let signature = command.signature();
         assert_eq!(signature.name, "secret hash");
         assert_eq!(signature.optional_positional.len(), 1);
-        assert_eq!(signature.input_output_types.len(), 4);
+        assert_eq!(signature.input_output_types.len(), 8);
+        assert!(signature.get_long_flag("algorithm").is_some());
+        assert!(signature.get_long_flag("salt").is_some());
+        assert!(signature.get_long_flag("output").is_some());
     }
 
     #[test]
@@ -256,7 +445,7 @@ mod tests {
     fn test_examples_count() {
         let command = SecretHashCommand;
         let examples = command.examples();
-        assert_eq!(examples.len(), 5);
+        assert_eq!(examples.len(), 8);
     }
 
     #[test]
@@ -281,6 +470,122 @@ mod tests {
         assert!("invalid".parse::<HashAlgorithm>().is_err());
     }
 
+    #[test]
+    fn test_salted_hash_differs_by_salt() {
+        let span = Span::test_data();
+        let secret = SecretString::new("my-secret-password".to_string());
+        let hash = |salt: &[u8]| {
+            hash_secret_value(&secret, &HashAlgorithm::Sha256, salt, span)
+                .unwrap()
+                .as_str()
+                .unwrap()
+                .to_string()
+        };
+
+        let salted_a = hash(b"salt-a");
+        let salted_b = hash(b"salt-b");
+        assert_ne!(salted_a, salted_b);
+        assert_ne!(salted_a, hash(&[]));
+
+        // Salt is prepended to the secret bytes
+        assert_eq!(
+            salted_a,
+            compute_hash(&HashAlgorithm::Sha256, b"salt-amy-secret-password")
+        );
+    }
+
+    #[test]
+    fn test_empty_salt_matches_unsalted() {
+        let span = Span::test_data();
+        let secret = SecretString::new("my-secret-password".to_string());
+        let result = hash_secret_value(&secret, &HashAlgorithm::Sha256, &[], span).unwrap();
+        assert_eq!(
+            result.as_str().unwrap(),
+            "a665a45920422f9d417e4867efdc4fb8a04a1f3fff1fa07e998e86f7f7a27ae3"
+        );
+    }
+
+    #[test]
+    fn test_salt_bytes() {
+        let span = Span::test_data();
+        assert_eq!(
+            salt_bytes(&Value::string("abc", span), span).unwrap(),
+            b"abc"
+        );
+        assert_eq!(
+            salt_bytes(&Value::binary(vec![1, 2], span), span).unwrap(),
+            vec![1, 2]
+        );
+        let secret_salt = Value::custom(Box::new(SecretString::new("abc".to_string())), span);
+        assert_eq!(salt_bytes(&secret_salt, span).unwrap(), b"abc");
+
+        let err = salt_bytes(&Value::int(42, span), span).unwrap_err();
+        assert_eq!(err.msg, "Invalid salt");
+        assert!(!format!("{:?}", err).contains("42"));
+    }
+
+    #[test]
+    fn test_resolve_algorithm_flag_known_vectors() {
+        let span = Span::test_data();
+        let data = b"hello world";
+        let vectors = [
+            (
+                "sha256",
+                "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9",
+            ),
+            (
+                "sha512",
+                "309ecc489c12d6eb4cc40f50c902f2b4d0ed77ee511a7c7a9bcd3ca86d4cd86f989dd35bc5ff499670da34255b45b0cfd830e81f605dcf7dc5542e93ae9cd76f",
+            ),
+            (
+                "blake3",
+                "d74981efa70a0c880b8d8c1985d075dbcbf679b99a5f9914e5aaf96b831a9e24",
+            ),
+        ];
+
+        for (name, expected) in vectors {
+            let algorithm = resolve_algorithm(None, Some(name.to_string()), span).unwrap();
+            assert_eq!(compute_hash(&algorithm, data), expected);
+        }
+    }
+
+    #[test]
+    fn test_resolve_algorithm_default_and_positional() {
+        let span = Span::test_data();
+        assert_eq!(
+            resolve_algorithm(None, None, span).unwrap(),
+            HashAlgorithm::Sha256
+        );
+        assert_eq!(
+            resolve_algorithm(Some(&Value::test_string("blake3")), None, span).unwrap(),
+            HashAlgorithm::Blake3
+        );
+        assert_eq!(
+            resolve_algorithm(
+                Some(&Value::test_string("sha512")),
+                Some("SHA512".to_string()),
+                span
+            )
+            .unwrap(),
+            HashAlgorithm::Sha512
+        );
+    }
+
+    #[test]
+    fn test_resolve_algorithm_errors() {
+        let span = Span::test_data();
+        let err = resolve_algorithm(None, Some("md5".to_string()), span).unwrap_err();
+        assert!(err.msg.contains("Invalid hash algorithm"));
+
+        let err = resolve_algorithm(
+            Some(&Value::test_string("sha256")),
+            Some("blake3".to_string()),
+            span,
+        )
+        .unwrap_err();
+        assert_eq!(err.msg, "Conflicting hash algorithms");
+    }
+
     #[test]
     fn test_compute_hash_sha256() {
         let data = b"hello world";
@@ -436,4 +741,52 @@ mod tests {
         let hash2 = compute_hash(&HashAlgorithm::Sha256, unicode_string.as_bytes());
         assert_eq!(hash, hash2);
     }
+
+    #[test]
+    fn test_binary_output_matches_hex_output() {
+        let span = Span::test_data();
+        let secret = SecretString::new("my-secret-password".to_string());
+
+        for algorithm in [
+            HashAlgorithm::Sha256,
+            HashAlgorithm::Sha512,
+            HashAlgorithm::Blake3,
+        ] {
+            let hex_output =
+                hash_secret_output(&secret, &algorithm, b"salt", HashOutput::Hex, span).unwrap();
+            let binary_output =
+                hash_secret_output(&secret, &algorithm, b"salt", HashOutput::Binary, span).unwrap();
+
+            let digest = binary_output
+                .as_custom_value()
+                .unwrap()
+                .as_any()
+                .downcast_ref::<SecretBinary>()
+                .expect("binary output should be a SecretBinary")
+                .reveal()
+                .into_owned();
+            assert_eq!(hex::encode(digest), hex_output.as_str().unwrap());
+        }
+    }
+
+    #[test]
+    fn test_binary_output_is_redacted_and_output_parsing() {
+        let span = Span::test_data();
+        let secret = SecretBinary::new(vec![0xde, 0xad, 0xbe, 0xef]);
+        let hex_digest = compute_hash(&HashAlgorithm::Sha256, &[0xde, 0xad, 0xbe, 0xef]);
+
+        let output = hash_secret_output(
+            &secret,
+            &HashAlgorithm::Sha256,
+            &[],
+            HashOutput::Binary,
+            span,
+        )
+        .unwrap();
+        assert!(!format!("{:?}", output).contains(&hex_digest));
+
+        assert_eq!("hex".parse::<HashOutput>(), Ok(HashOutput::Hex));
+        assert_eq!("binary".parse::<HashOutput>(), Ok(HashOutput::Binary));
+        assert!("base64".parse::<HashOutput>().is_err());
+    }
 }
@@ -1,11 +1,136 @@
 //! Implements `secret info` — displays plugin version and metadata.
 
+use std::collections::BTreeMap;
+
 use nu_plugin::{EngineInterface, EvaluatedCall, Plugin, PluginCommand};
-use nu_protocol::{Category, Example, LabeledError, PipelineData, Record, Signature, Type, Value};
+use nu_protocol::{
+    Category, CustomValue, Example, LabeledError, PipelineData, Record, Signature, Span, Type,
+    Value,
+};
+
+use crate::{
+    SecretBinary, SecretBool, SecretCellPath, SecretDate, SecretDuration, SecretFilesize,
+    SecretFloat, SecretInt, SecretList, SecretRange, SecretRecord, SecretString,
+};
 
 #[derive(Clone)]
 pub struct SecretInfoCommand;
 
+/// Whether a secret custom value carries its own redaction template.
+///
+/// Returns `None` for custom values that are not secrets.
+fn has_custom_template(val: &dyn CustomValue) -> Option<bool> {
+    let any = val.as_any();
+    if let Some(secret) = any.downcast_ref::<SecretString>() {
+        Some(secret.has_custom_template())
+    } else if let Some(secret) = any.downcast_ref::<SecretInt>() {
+        Some(secret.has_custom_template())
+    } else if let Some(secret) = any.downcast_ref::<SecretBool>() {
+        Some(secret.has_custom_template())
+    } else if let Some(secret) = any.downcast_ref::<SecretFloat>() {
+        Some(secret.has_custom_template())
+    } else if let Some(secret) = any.downcast_ref::<SecretDate>() {
+        Some(secret.has_custom_template())
+    } else if let Some(secret) = any.downcast_ref::<SecretBinary>() {
+        Some(secret.has_custom_template())
+    } else if let Some(secret) = any.downcast_ref::<SecretDuration>() {
+        Some(secret.has_custom_template())
+    } else if let Some(secret) = any.downcast_ref::<SecretFilesize>() {
+        Some(secret.has_custom_template())
+    } else if let Some(secret) = any.downcast_ref::<SecretList>() {
+        Some(secret.has_custom_template())
+    } else if let Some(secret) = any.downcast_ref::<SecretRange>() {
+        Some(secret.has_custom_template())
+    } else if let Some(secret) = any.downcast_ref::<SecretCellPath>() {
+        Some(secret.has_custom_template())
+    } else {
+        any.downcast_ref::<SecretRecord>()
+            .map(|secret| secret.has_custom_template())
+    }
+}
+
+/// Build a machine-readable metadata record describing a secret.
+///
+/// Only metadata is included: `byte_length` is reported for strings and binary
+/// data and is `nothing` for every other type.
+fn secret_metadata(val: &dyn CustomValue, span: Span) -> Result<Value, LabeledError> {
+    let custom_template = has_custom_template(val).ok_or_else(|| {
+        LabeledError::new("Unsupported secret type")
+            .with_label("Expected a secret value to describe", span)
+    })?;
+
+    let byte_length = if let Some(secret) = val.as_any().downcast_ref::<SecretString>() {
+        Value::int(secret.reveal().len() as i64, span)
+    } else if let Some(secret) = val.as_any().downcast_ref::<SecretBinary>() {
+        Value::int(secret.reveal().len() as i64, span)
+    } else {
+        Value::nothing(span)
+    };
+
+    let redaction_style = if custom_template {
+        "custom_template"
+    } else {
+        "default_template"
+    };
+
+    let mut record = Record::new();
+    record.push("type_name", Value::string(val.type_name(), span));
+    record.push("byte_length", byte_length);
+    record.push("has_custom_template", Value::bool(custom_template, span));
+    record.push("redaction_style", Value::string(redaction_style, span));
+
+    Ok(Value::record(record, span))
+}
+
+/// Summarize a collection of secrets without revealing any of them.
+///
+/// Reports the number of secrets per type, the total `byte_length` of the
+/// string and binary secrets, how many carry a custom template, and how many
+/// values were not secrets at all.
+fn aggregate_secret_info(values: impl IntoIterator<Item = Value>, span: Span) -> Value {
+    let mut by_type: BTreeMap<String, i64> = BTreeMap::new();
+    let mut total = 0i64;
+    let mut total_byte_length = 0i64;
+    let mut custom_templates = 0i64;
+    let mut non_secret = 0i64;
+
+    for value in values {
+        let Value::Custom { val, .. } = &value else {
+            non_secret += 1;
+            continue;
+        };
+        let Some(custom_template) = has_custom_template(val.as_ref()) else {
+            non_secret += 1;
+            continue;
+        };
+
+        total += 1;
+        *by_type.entry(val.type_name()).or_insert(0) += 1;
+        if custom_template {
+            custom_templates += 1;
+        }
+        if let Some(secret) = val.as_any().downcast_ref::<SecretString>() {
+            total_byte_length += secret.reveal().len() as i64;
+        } else if let Some(secret) = val.as_any().downcast_ref::<SecretBinary>() {
+            total_byte_length += secret.len() as i64;
+        }
+    }
+
+    let mut type_counts = Record::new();
+    for (type_name, count) in by_type {
+        type_counts.push(type_name, Value::int(count, span));
+    }
+
+    let mut record = Record::new();
+    record.push("total", Value::int(total, span));
+    record.push("by_type", Value::record(type_counts, span));
+    record.push("total_byte_length", Value::int(total_byte_length, span));
+    record.push("custom_templates", Value::int(custom_templates, span));
+    record.push("non_secret", Value::int(non_secret, span));
+
+    Value::record(record, span)
+}
+
 impl PluginCommand for SecretInfoCommand {
     type Plugin = crate::SecretPlugin;
 
@@ -15,7 +140,24 @@ impl PluginCommand for SecretInfoCommand {
 
     fn signature(&self) -> Signature {
         Signature::build(self.name())
-            .input_output_types(vec![(Type::Nothing, Type::Record(Box::new([])))])
+            .input_output_types(vec![
+                (Type::Nothing, Type::Record(Box::new([]))),
+                (Type::Any, Type::Record(Box::new([]))),
+                (
+                    Type::List(Box::new(Type::Any)),
+                    Type::Record(Box::new([])),
+                ),
+            ])
+            .switch(
+                "json",
+                "Describe the piped secret as a machine-readable metadata record",
+                Some('j'),
+            )
+            .switch(
+                "all",
+                "Summarize a list of secrets: counts per type, total byte length and custom templates",
+                Some('a'),
+            )
             .category(Category::System)
     }
 
@@ -24,11 +166,23 @@ impl PluginCommand for SecretInfoCommand {
     }
 
     fn examples(&self) -> Vec<Example<'_>> {
-        vec![Example {
-            example: "secret info",
-            description: "Show plugin information and security guidance",
-            result: None,
-        }]
+        vec![
+            Example {
+                example: "secret info",
+                description: "Show plugin information and security guidance",
+                result: None,
+            },
+            Example {
+                example: r#""my-api-key" | secret wrap | secret info --json"#,
+                description: "Get machine-readable metadata about a secret without revealing it",
+                result: None,
+            },
+            Example {
+                example: r#"$credentials | get password | secret info --all"#,
+                description: "Summarize a column of secrets without revealing any of them",
+                result: None,
+            },
+        ]
     }
 
     fn run(
@@ -36,8 +190,27 @@ impl PluginCommand for SecretInfoCommand {
         plugin: &Self::Plugin,
         _engine: &EngineInterface,
         call: &EvaluatedCall,
-        _input: PipelineData,
+        input: PipelineData,
     ) -> Result<PipelineData, LabeledError> {
+        if call.has_flag("all")? {
+            return Ok(PipelineData::Value(
+                aggregate_secret_info(input, call.head),
+                None,
+            ));
+        }
+
+        if call.has_flag("json")? {
+            return match input {
+                PipelineData::Value(Value::Custom { val, .. }, metadata) => Ok(
+                    PipelineData::Value(secret_metadata(val.as_ref(), call.head)?, metadata),
+                ),
+                _ => Err(LabeledError::new("Invalid input").with_label(
+                    "--json requires a secret value as input. Use 'secret wrap' to create a secret first",
+                    call.head,
+                )),
+            };
+        }
+
         let mut record = Record::new();
 
         record.push("name", Value::string("nu_plugin_secret", call.head));
@@ -122,7 +295,131 @@ mod tests {
         let command = SecretInfoCommand;
         let sig = command.signature();
         assert_eq!(sig.name, "secret info");
-        assert_eq!(sig.input_output_types.len(), 1);
+        assert_eq!(sig.input_output_types.len(), 3);
         assert_eq!(sig.input_output_types[0].0, Type::Nothing);
+        assert!(sig.get_long_flag("json").is_some());
+        assert!(sig.get_long_flag("all").is_some());
+    }
+
+    #[test]
+    fn test_secret_metadata_keys_do_not_reveal_value() {
+        let span = Span::test_data();
+        let secret =
+            SecretString::new_with_template("hunter2".to_string(), "[{{secret_type}}]".to_string());
+
+        let metadata = secret_metadata(&secret, span).unwrap();
+        let record = metadata.as_record().unwrap();
+
+        assert_eq!(
+            record.columns().collect::<Vec<_>>(),
+            vec![
+                "type_name",
+                "byte_length",
+                "has_custom_template",
+                "redaction_style"
+            ]
+        );
+        assert_eq!(
+            record.get("type_name").unwrap().as_str().unwrap(),
+            "secret_string"
+        );
+        assert_eq!(record.get("byte_length").unwrap().as_int().unwrap(), 7);
+        assert!(record
+            .get("has_custom_template")
+            .unwrap()
+            .as_bool()
+            .unwrap());
+        assert_eq!(
+            record.get("redaction_style").unwrap().as_str().unwrap(),
+            "custom_template"
+        );
+
+        for value in record.values() {
+            assert!(!format!("{:?}", value).contains("hunter2"));
+        }
+    }
+
+    #[test]
+    fn test_secret_metadata_omits_length_for_bool_and_date() {
+        let span = Span::test_data();
+
+        let bool_metadata = secret_metadata(&SecretBool::new(true), span).unwrap();
+        let bool_record = bool_metadata.as_record().unwrap();
+        assert!(bool_record.get("byte_length").unwrap().is_nothing());
+        assert!(!bool_record
+            .get("has_custom_template")
+            .unwrap()
+            .as_bool()
+            .unwrap());
+        assert_eq!(
+            bool_record
+                .get("redaction_style")
+                .unwrap()
+                .as_str()
+                .unwrap(),
+            "default_template"
+        );
+
+        let date = chrono::DateTime::parse_from_rfc3339("2024-01-01T00:00:00+00:00").unwrap();
+        let date_metadata = secret_metadata(&SecretDate::new(date), span).unwrap();
+        assert!(date_metadata
+            .as_record()
+            .unwrap()
+            .get("byte_length")
+            .unwrap()
+            .is_nothing());
+    }
+
+    #[test]
+    fn test_aggregate_mixed_secrets() {
+        let span = Span::test_data();
+        let values = vec![
+            Value::custom(Box::new(SecretString::new("hunter2".to_string())), span),
+            Value::custom(
+                Box::new(SecretString::new_with_template(
+                    "abc".to_string(),
+                    "[HIDDEN]".to_string(),
+                )),
+                span,
+            ),
+            Value::custom(Box::new(SecretInt::new(42)), span),
+            Value::custom(Box::new(SecretInt::new(7)), span),
+            Value::custom(Box::new(SecretInt::new(9)), span),
+            Value::nothing(span),
+        ];
+
+        let summary = aggregate_secret_info(values, span);
+        let record = summary.as_record().unwrap();
+
+        assert_eq!(record.get("total").unwrap().as_int().unwrap(), 5);
+        let by_type = record.get("by_type").unwrap().as_record().unwrap();
+        assert_eq!(by_type.get("secret_string").unwrap().as_int().unwrap(), 2);
+        assert_eq!(by_type.get("secret_int").unwrap().as_int().unwrap(), 3);
+        assert_eq!(by_type.len(), 2);
+        assert_eq!(
+            record.get("total_byte_length").unwrap().as_int().unwrap(),
+            10
+        );
+        assert_eq!(record.get("custom_templates").unwrap().as_int().unwrap(), 1);
+        assert_eq!(record.get("non_secret").unwrap().as_int().unwrap(), 1);
+
+        let rendered = format!("{:?}", summary);
+        assert!(!rendered.contains("hunter2"));
+        assert!(!rendered.contains("42"));
+    }
+
+    #[test]
+    fn test_aggregate_empty_input() {
+        let span = Span::test_data();
+        let summary = aggregate_secret_info(Vec::new(), span);
+        let record = summary.as_record().unwrap();
+
+        assert_eq!(record.get("total").unwrap().as_int().unwrap(), 0);
+        assert!(record
+            .get("by_type")
+            .unwrap()
+            .as_record()
+            .unwrap()
+            .is_empty());
     }
 }
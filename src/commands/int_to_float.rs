@@ -0,0 +1,117 @@
+//! Implements `secret int to-float` — converts a secret int to a secret float.
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{Category, Example, LabeledError, PipelineData, Signature, Type, Value};
+
+use crate::{SecretFloat, SecretInt};
+
+#[derive(Clone)]
+pub struct SecretIntToFloatCommand;
+
+/// Convert a secret custom value to a `SecretFloat`.
+fn convert_secret_int(
+    val: &dyn nu_protocol::CustomValue,
+    span: nu_protocol::Span,
+) -> Result<Value, LabeledError> {
+    if let Some(secret_int) = val.as_any().downcast_ref::<SecretInt>() {
+        Ok(Value::custom(
+            Box::new(SecretFloat::new(secret_int.reveal() as f64)),
+            span,
+        ))
+    } else {
+        Err(LabeledError::new("Unsupported secret type")
+            .with_label("Only SecretInt can be converted to a secret float", span))
+    }
+}
+
+impl PluginCommand for SecretIntToFloatCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret int to-float"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(
+                Type::Custom("secret_int".into()),
+                Type::Custom("secret_float".into()),
+            )])
+            .category(Category::Conversions)
+    }
+
+    fn description(&self) -> &str {
+        "Convert a secret int to a secret float without revealing it"
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![Example {
+            example: r#"42 | secret wrap | secret int to-float"#,
+            description: "Convert a secret int to a secret float",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        match input {
+            PipelineData::Value(value, metadata) => {
+                let result = match value {
+                    Value::Custom { val, .. } => convert_secret_int(val.as_ref(), call.head)?,
+                    _ => {
+                        return Err(LabeledError::new("Invalid input").with_label(
+                            "Input must be a secret int. Use 'secret wrap' to create a secret first",
+                            call.head,
+                        ));
+                    }
+                };
+
+                Ok(PipelineData::Value(result, metadata))
+            }
+            _ => Err(LabeledError::new("Invalid input")
+                .with_label("Expected a single secret value", call.head)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nu_protocol::Span;
+
+    #[test]
+    fn test_command_name() {
+        let command = SecretIntToFloatCommand;
+        assert_eq!(command.name(), "secret int to-float");
+    }
+
+    #[test]
+    fn test_signature() {
+        let command = SecretIntToFloatCommand;
+        let signature = command.signature();
+        assert_eq!(signature.name, "secret int to-float");
+        assert_eq!(signature.input_output_types.len(), 1);
+    }
+
+    #[test]
+    fn test_convert_secret_int() {
+        let span = Span::test_data();
+        let result = convert_secret_int(&SecretInt::new(-7), span).unwrap();
+        let custom = result.as_custom_value().unwrap();
+        assert_eq!(
+            custom
+                .as_any()
+                .downcast_ref::<SecretFloat>()
+                .unwrap()
+                .reveal(),
+            -7.0
+        );
+
+        assert!(convert_secret_int(&SecretFloat::new(1.0), span).is_err());
+    }
+}
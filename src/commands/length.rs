@@ -3,11 +3,50 @@
 use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
 use nu_protocol::{Category, Example, LabeledError, PipelineData, Signature, Type, Value};
 
-use crate::{SecretBinary, SecretList, SecretString};
+use crate::{SecretBinary, SecretList, SecretRecord, SecretString};
 
 #[derive(Clone)]
 pub struct SecretLengthCommand;
 
+/// Length of a secret custom value, or `None` for types without a length.
+///
+/// Strings report their byte length, or their character count when `chars` is
+/// set. Lists report their element count, records their field count and binary
+/// data its byte length.
+pub(crate) fn secret_len(val: &dyn nu_protocol::CustomValue, chars: bool) -> Option<usize> {
+    if let Some(secret_string) = val.as_any().downcast_ref::<SecretString>() {
+        if chars {
+            Some(secret_string.reveal().chars().count())
+        } else {
+            Some(secret_string.reveal().len())
+        }
+    } else if let Some(secret_list) = val.as_any().downcast_ref::<SecretList>() {
+        Some(secret_list.reveal().len())
+    } else if let Some(secret_record) = val.as_any().downcast_ref::<SecretRecord>() {
+        Some(secret_record.reveal().len())
+    } else {
+        val.as_any()
+            .downcast_ref::<SecretBinary>()
+            .map(|secret_binary| secret_binary.reveal().len())
+    }
+}
+
+/// Compute the length of a secret custom value without revealing it.
+fn secret_length(
+    val: &dyn nu_protocol::CustomValue,
+    chars: bool,
+    span: nu_protocol::Span,
+) -> Result<Value, LabeledError> {
+    let length = secret_len(val, chars).ok_or_else(|| {
+        LabeledError::new("Unsupported secret type").with_label(
+            "Only SecretString, SecretList, SecretRecord, and SecretBinary support length operation",
+            span,
+        )
+    })?;
+
+    Ok(Value::int(length as i64, span))
+}
+
 impl PluginCommand for SecretLengthCommand {
     type Plugin = crate::SecretPlugin;
 
@@ -20,13 +59,19 @@ impl PluginCommand for SecretLengthCommand {
             .input_output_types(vec![
                 (Type::Custom("secret_string".into()), Type::Int),
                 (Type::Custom("secret_list".into()), Type::Int),
+                (Type::Custom("secret_record".into()), Type::Int),
                 (Type::Custom("secret_binary".into()), Type::Int),
             ])
+            .switch(
+                "chars",
+                "Count unicode characters instead of bytes for secret strings",
+                Some('c'),
+            )
             .category(Category::Filters)
     }
 
     fn description(&self) -> &str {
-        "Get the length of a secret string, list, or binary data without exposing the content"
+        "Get the length of a secret string, list, record, or binary data without exposing the content"
     }
 
     fn examples(&self) -> Vec<Example<'_>> {
@@ -41,6 +86,11 @@ impl PluginCommand for SecretLengthCommand {
                 description: "Get the length of a secret list",
                 result: Some(Value::int(5, nu_protocol::Span::test_data())),
             },
+            Example {
+                example: r#"{user: "admin", password: "hunter2"} | secret wrap | secret length"#,
+                description: "Get the number of fields in a secret record",
+                result: Some(Value::int(2, nu_protocol::Span::test_data())),
+            },
             Example {
                 example: r#"0x[deadbeef] | secret wrap | secret length"#,
                 description: "Get the length of secret binary data",
@@ -51,6 +101,11 @@ impl PluginCommand for SecretLengthCommand {
                 description: "Get the length of an empty secret string",
                 result: Some(Value::int(0, nu_protocol::Span::test_data())),
             },
+            Example {
+                example: r#""пароль" | secret wrap | secret length --chars"#,
+                description: "Count the characters of a secret string instead of its bytes",
+                result: Some(Value::int(6, nu_protocol::Span::test_data())),
+            },
         ]
     }
 
@@ -65,26 +120,12 @@ impl PluginCommand for SecretLengthCommand {
             PipelineData::Value(value, metadata) => {
                 let result = match value {
                     Value::Custom { val, .. } => {
-                        if let Some(secret_string) = val.as_any().downcast_ref::<SecretString>() {
-                            Value::int(secret_string.reveal().len() as i64, call.head)
-                        } else if let Some(secret_list) = val.as_any().downcast_ref::<SecretList>()
-                        {
-                            Value::int(secret_list.reveal().len() as i64, call.head)
-                        } else if let Some(secret_binary) =
-                            val.as_any().downcast_ref::<SecretBinary>()
-                        {
-                            Value::int(secret_binary.reveal().len() as i64, call.head)
-                        } else {
-                            return Err(LabeledError::new("Unsupported secret type").with_label(
-                                "Only SecretString, SecretList, and SecretBinary support length operation",
-                                call.head,
-                            ));
-                        }
+                        secret_length(val.as_ref(), call.has_flag("chars")?, call.head)?
                     }
                     _ => {
                         return Err(LabeledError::new("Invalid input")
                             .with_label(
-                                "Input must be a secret string, list, or binary. Use 'secret wrap' to create a secret first",
+                                "Input must be a secret string, list, record, or binary. Use 'secret wrap' to create a secret first",
                                 call.head,
                             ));
                     }
@@ -115,7 +156,8 @@ mod tests {
         let signature = command.signature();
         assert_eq!(signature.name, "secret length");
         assert_eq!(signature.required_positional.len(), 0);
-        assert_eq!(signature.input_output_types.len(), 3);
+        assert_eq!(signature.input_output_types.len(), 4);
+        assert!(signature.get_long_flag("chars").is_some());
     }
 
     #[test]
@@ -123,7 +165,7 @@ mod tests {
         let command = SecretLengthCommand;
         assert_eq!(
             command.description(),
-            "Get the length of a secret string, list, or binary data without exposing the content"
+            "Get the length of a secret string, list, record, or binary data without exposing the content"
         );
     }
 
@@ -131,7 +173,7 @@ mod tests {
     fn test_examples_count() {
         let command = SecretLengthCommand;
         let examples = command.examples();
-        assert_eq!(examples.len(), 4);
+        assert_eq!(examples.len(), 6);
     }
 
     #[test]
@@ -279,4 +321,42 @@ mod tests {
         let secret_nested = SecretList::new(nested_list);
         assert_eq!(secret_nested.reveal().len(), 3); // Only top-level elements
     }
+
+    fn length_of(val: &dyn nu_protocol::CustomValue, chars: bool) -> i64 {
+        secret_length(val, chars, Span::test_data())
+            .unwrap()
+            .as_int()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_secret_length_per_type() {
+        let span = Span::test_data();
+
+        assert_eq!(length_of(&SecretString::new("héllo".to_string()), false), 6);
+        assert_eq!(length_of(&SecretString::new("héllo".to_string()), true), 5);
+
+        let list = SecretList::new(vec![Value::int(1, span), Value::int(2, span)]);
+        assert_eq!(length_of(&list, false), 2);
+
+        let mut record = nu_protocol::Record::new();
+        record.push("user", Value::string("admin", span));
+        record.push("password", Value::string("hunter2", span));
+        record.push("port", Value::int(5432, span));
+        assert_eq!(length_of(&SecretRecord::new(record), false), 3);
+        assert_eq!(
+            length_of(&SecretRecord::new(nu_protocol::Record::new()), false),
+            0
+        );
+
+        let binary = SecretBinary::new(vec![0xde, 0xad, 0xbe, 0xef]);
+        assert_eq!(length_of(&binary, false), 4);
+        assert_eq!(length_of(&binary, true), 4);
+    }
+
+    #[test]
+    fn test_secret_length_rejects_unsupported_type() {
+        let err = secret_length(&crate::SecretInt::new(42), false, Span::test_data()).unwrap_err();
+        assert_eq!(err.msg, "Unsupported secret type");
+    }
 }
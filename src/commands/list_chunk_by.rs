@@ -0,0 +1,231 @@
+//! Implements `secret list chunk-by` — groups consecutive elements of a secret
+//! list that share a field value into secret lists.
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, Signature, Span, SyntaxShape, Type, Value,
+};
+
+use super::list_sum_by::element_field;
+use super::unwrap::reveal_secret_value;
+use crate::SecretList;
+
+#[derive(Clone)]
+pub struct SecretListChunkByCommand;
+
+/// Comparable form of a field value; secret field values are compared by content.
+fn chunk_key(value: &Value, span: Span) -> Value {
+    match value {
+        Value::Custom { val, .. } => {
+            reveal_secret_value(val.as_ref(), span).unwrap_or_else(|| value.clone())
+        }
+        _ => value.clone(),
+    }
+}
+
+/// Split a secret list of records into runs of consecutive elements whose
+/// `field` values are equal.
+///
+/// Each run is wrapped as its own `SecretList` and the runs are collected into
+/// an outer `SecretList`. The field values only drive the grouping and never
+/// appear in the output. A missing field is an error naming the element index.
+fn chunk_secret_list_by(list: &SecretList, field: &str, span: Span) -> Result<Value, LabeledError> {
+    let mut chunks = Vec::new();
+    let mut current: Vec<Value> = Vec::new();
+    let mut current_key: Option<Value> = None;
+
+    for (index, element) in list.reveal().iter().enumerate() {
+        let key = element_field(element, field)
+            .map(|value| chunk_key(value, span))
+            .ok_or_else(|| {
+                LabeledError::new("Field not found")
+                    .with_label(format!("Element {} has no field '{}'", index, field), span)
+            })?;
+
+        if current_key
+            .as_ref()
+            .is_some_and(|previous| *previous != key)
+        {
+            let chunk = std::mem::take(&mut current);
+            chunks.push(Value::custom(Box::new(SecretList::new(chunk)), span));
+        }
+        current_key = Some(key);
+        current.push(element.clone());
+    }
+
+    if !current.is_empty() {
+        chunks.push(Value::custom(Box::new(SecretList::new(current)), span));
+    }
+
+    Ok(Value::custom(Box::new(SecretList::new(chunks)), span))
+}
+
+impl PluginCommand for SecretListChunkByCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret list chunk-by"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(
+                Type::Custom("secret_list".into()),
+                Type::Custom("secret_list".into()),
+            )])
+            .required(
+                "field",
+                SyntaxShape::String,
+                "Field whose value groups consecutive records",
+            )
+            .category(Category::Filters)
+    }
+
+    fn description(&self) -> &str {
+        "Group consecutive records of a secret list sharing a field value into secret lists"
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![Example {
+            example: r#"[{env: "prod", v: 1} {env: "prod", v: 2} {env: "dev", v: 3}] | secret wrap | secret list chunk-by env"#,
+            description: "Produce two secret lists: the two prod records and the dev record",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let field: String = call.req(0)?;
+
+        match input {
+            PipelineData::Value(Value::Custom { val, .. }, metadata) => {
+                let secret_list = val.as_any().downcast_ref::<SecretList>().ok_or_else(|| {
+                    LabeledError::new("Unsupported secret type")
+                        .with_label("Only SecretList supports chunk-by", call.head)
+                })?;
+
+                let result = chunk_secret_list_by(secret_list, &field, call.head)?;
+                Ok(PipelineData::Value(result, metadata))
+            }
+            PipelineData::Value(_, _) => Err(LabeledError::new("Invalid input").with_label(
+                "Input must be a secret list. Use 'secret wrap' to create a secret first",
+                call.head,
+            )),
+            _ => Err(LabeledError::new("Invalid input")
+                .with_label("Expected a single secret value", call.head)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{SecretRecord, SecretString};
+    use nu_protocol::Record;
+
+    fn reading(env: Value, v: i64) -> Value {
+        let span = Span::test_data();
+        let mut record = Record::new();
+        record.push("env", env);
+        record.push("v", Value::int(v, span));
+        Value::record(record, span)
+    }
+
+    fn as_secret_list(value: &Value) -> &SecretList {
+        value
+            .as_custom_value()
+            .unwrap()
+            .as_any()
+            .downcast_ref::<SecretList>()
+            .unwrap()
+    }
+
+    fn chunk_values(chunk: &Value) -> Vec<i64> {
+        as_secret_list(chunk)
+            .reveal()
+            .iter()
+            .map(|element| element_field(element, "v").unwrap().as_int().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_command_name() {
+        let command = SecretListChunkByCommand;
+        assert_eq!(command.name(), "secret list chunk-by");
+    }
+
+    #[test]
+    fn test_signature() {
+        let command = SecretListChunkByCommand;
+        let signature = command.signature();
+        assert_eq!(signature.name, "secret list chunk-by");
+        assert_eq!(signature.required_positional.len(), 1);
+    }
+
+    #[test]
+    fn test_chunk_alternating_field() {
+        let span = Span::test_data();
+        let env = |name: &str| Value::string(name, span);
+        let list = SecretList::new(vec![
+            reading(env("prod"), 1),
+            reading(env("prod"), 2),
+            reading(env("dev"), 3),
+            reading(env("prod"), 4),
+            reading(env("dev"), 5),
+            reading(env("dev"), 6),
+        ]);
+
+        let result = chunk_secret_list_by(&list, "env", span).unwrap();
+        let chunks = as_secret_list(&result).reveal();
+
+        assert_eq!(chunks.len(), 4);
+        assert_eq!(chunk_values(&chunks[0]), vec![1, 2]);
+        assert_eq!(chunk_values(&chunks[1]), vec![3]);
+        assert_eq!(chunk_values(&chunks[2]), vec![4]);
+        assert_eq!(chunk_values(&chunks[3]), vec![5, 6]);
+    }
+
+    #[test]
+    fn test_chunk_by_secret_field_in_secret_records() {
+        let span = Span::test_data();
+        let secret_env =
+            |name: &str| Value::custom(Box::new(SecretString::new(name.to_string())), span);
+        let secret_reading = |name: &str, v: i64| {
+            let record = reading(secret_env(name), v).into_record().unwrap();
+            Value::custom(Box::new(SecretRecord::new(record)), span)
+        };
+        let list = SecretList::new(vec![
+            secret_reading("a", 1),
+            secret_reading("a", 2),
+            secret_reading("b", 3),
+        ]);
+
+        let result = chunk_secret_list_by(&list, "env", span).unwrap();
+        let chunks = as_secret_list(&result).reveal();
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunk_values(&chunks[0]), vec![1, 2]);
+        assert_eq!(chunk_values(&chunks[1]), vec![3]);
+    }
+
+    #[test]
+    fn test_chunk_empty_list() {
+        let span = Span::test_data();
+        let result = chunk_secret_list_by(&SecretList::new(vec![]), "env", span).unwrap();
+        assert!(as_secret_list(&result).is_empty());
+    }
+
+    #[test]
+    fn test_chunk_missing_field() {
+        let span = Span::test_data();
+        let list = SecretList::new(vec![reading(Value::string("prod", span), 1)]);
+
+        let err = chunk_secret_list_by(&list, "region", span).unwrap_err();
+        assert_eq!(err.msg, "Field not found");
+        assert!(format!("{:?}", err).contains("Element 0"));
+    }
+}
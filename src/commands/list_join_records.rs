@@ -0,0 +1,227 @@
+//! Implements `secret list join-records` — merges a secret list of records into
+//! a single secret record.
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, Record, Signature, Span, Type, Value,
+};
+
+use crate::{SecretList, SecretRecord};
+
+#[derive(Clone)]
+pub struct SecretListJoinRecordsCommand;
+
+/// Merge every record in a secret list into one `SecretRecord`.
+///
+/// Elements may be plain records or secret records. Later elements override
+/// earlier ones on a key collision unless `error_on_collision` is set. Errors
+/// name the offending element index or key, never a value.
+fn join_secret_records(
+    list: &SecretList,
+    error_on_collision: bool,
+    span: Span,
+) -> Result<Value, LabeledError> {
+    let mut merged = Record::new();
+
+    for (index, element) in list.reveal().iter().enumerate() {
+        let record = match element {
+            Value::Record { val, .. } => val.as_ref(),
+            Value::Custom { val, .. } => val
+                .as_any()
+                .downcast_ref::<SecretRecord>()
+                .map(|secret_record| secret_record.reveal())
+                .ok_or_else(|| {
+                    LabeledError::new("Type mismatch").with_label(
+                        format!(
+                            "Element {} must be a record or secret_record, found {}",
+                            index,
+                            val.type_name()
+                        ),
+                        span,
+                    )
+                })?,
+            _ => {
+                return Err(LabeledError::new("Type mismatch").with_label(
+                    format!(
+                        "Element {} must be a record or secret_record, found {}",
+                        index,
+                        element.get_type()
+                    ),
+                    span,
+                ))
+            }
+        };
+
+        for (key, value) in record.iter() {
+            if error_on_collision && merged.contains(key) {
+                return Err(LabeledError::new("Key collision").with_label(
+                    format!("Key '{}' appears in more than one record", key),
+                    span,
+                ));
+            }
+            merged.insert(key.clone(), value.clone());
+        }
+    }
+
+    Ok(Value::custom(Box::new(SecretRecord::new(merged)), span))
+}
+
+impl PluginCommand for SecretListJoinRecordsCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret list join-records"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(
+                Type::Custom("secret_list".into()),
+                Type::Custom("secret_record".into()),
+            )])
+            .switch(
+                "error-on-collision",
+                "Fail if a key appears in more than one record instead of keeping the last value",
+                Some('e'),
+            )
+            .category(Category::Filters)
+    }
+
+    fn description(&self) -> &str {
+        "Merge a secret list of records into a single secret record"
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                example: r#"[{user: "admin"} {password: "hunter2"}] | secret wrap | secret list join-records"#,
+                description: "Combine partial credential records into one secret record",
+                result: None,
+            },
+            Example {
+                example: r#"[{token: "a"} {token: "b"}] | secret wrap | secret list join-records --error-on-collision"#,
+                description: "Refuse to merge records that share a key",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let error_on_collision = call.has_flag("error-on-collision")?;
+
+        match input {
+            PipelineData::Value(Value::Custom { val, .. }, metadata) => {
+                let secret_list = val.as_any().downcast_ref::<SecretList>().ok_or_else(|| {
+                    LabeledError::new("Unsupported secret type")
+                        .with_label("Only SecretList supports join-records", call.head)
+                })?;
+
+                let result = join_secret_records(secret_list, error_on_collision, call.head)?;
+                Ok(PipelineData::Value(result, metadata))
+            }
+            PipelineData::Value(_, _) => Err(LabeledError::new("Invalid input").with_label(
+                "Input must be a secret list. Use 'secret wrap' to create a secret first",
+                call.head,
+            )),
+            _ => Err(LabeledError::new("Invalid input")
+                .with_label("Expected a single secret value", call.head)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(fields: &[(&str, &str)]) -> Record {
+        let span = Span::test_data();
+        fields
+            .iter()
+            .map(|(key, value)| (key.to_string(), Value::string(*value, span)))
+            .collect()
+    }
+
+    fn as_secret_record(value: &Value) -> &SecretRecord {
+        value
+            .as_custom_value()
+            .unwrap()
+            .as_any()
+            .downcast_ref::<SecretRecord>()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_command_name() {
+        let command = SecretListJoinRecordsCommand;
+        assert_eq!(command.name(), "secret list join-records");
+    }
+
+    #[test]
+    fn test_signature() {
+        let command = SecretListJoinRecordsCommand;
+        let signature = command.signature();
+        assert_eq!(signature.name, "secret list join-records");
+        assert!(signature.get_long_flag("error-on-collision").is_some());
+    }
+
+    #[test]
+    fn test_join_three_records_without_collision() {
+        let span = Span::test_data();
+        let list = SecretList::new(vec![
+            Value::record(record(&[("user", "admin")]), span),
+            Value::custom(
+                Box::new(SecretRecord::new(record(&[("password", "hunter2")]))),
+                span,
+            ),
+            Value::record(record(&[("host", "db.local")]), span),
+        ]);
+
+        for error_on_collision in [false, true] {
+            let result = join_secret_records(&list, error_on_collision, span).unwrap();
+            let merged = as_secret_record(&result).reveal();
+
+            assert_eq!(merged.len(), 3);
+            assert_eq!(merged.get("user").unwrap().as_str().unwrap(), "admin");
+            assert_eq!(merged.get("password").unwrap().as_str().unwrap(), "hunter2");
+            assert_eq!(merged.get("host").unwrap().as_str().unwrap(), "db.local");
+        }
+    }
+
+    #[test]
+    fn test_join_three_records_with_collision() {
+        let span = Span::test_data();
+        let list = SecretList::new(vec![
+            Value::record(record(&[("user", "admin"), ("token", "old")]), span),
+            Value::record(record(&[("token", "newer")]), span),
+            Value::record(record(&[("token", "newest")]), span),
+        ]);
+
+        let result = join_secret_records(&list, false, span).unwrap();
+        let merged = as_secret_record(&result).reveal();
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged.get("token").unwrap().as_str().unwrap(), "newest");
+
+        let err = join_secret_records(&list, true, span).unwrap_err();
+        assert_eq!(err.msg, "Key collision");
+        assert!(!format!("{:?}", err).contains("newer"));
+    }
+
+    #[test]
+    fn test_join_rejects_non_record_element() {
+        let span = Span::test_data();
+        let list = SecretList::new(vec![
+            Value::record(record(&[("user", "admin")]), span),
+            Value::string("not-a-record", span),
+        ]);
+
+        let err = join_secret_records(&list, false, span).unwrap_err();
+        assert_eq!(err.msg, "Type mismatch");
+        assert!(!format!("{:?}", err).contains("not-a-record"));
+    }
+}
@@ -0,0 +1,290 @@
+//! Implements `secret list sum-by` — totals a numeric field across a secret list of records.
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, Signature, Span, SyntaxShape, Type, Value,
+};
+
+use crate::{SecretFloat, SecretInt, SecretList, SecretRecord};
+
+#[derive(Clone)]
+pub struct SecretListSumByCommand;
+
+/// Running total that stays an integer until a float is encountered.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Total {
+    Int(i64),
+    Float(f64),
+}
+
+impl Total {
+    fn add_int(self, n: i64, field: &str, span: Span) -> Result<Total, LabeledError> {
+        match self {
+            Total::Int(acc) => acc.checked_add(n).map(Total::Int).ok_or_else(|| {
+                LabeledError::new("Integer overflow")
+                    .with_label(format!("Sum of field '{}' overflows an int", field), span)
+            }),
+            Total::Float(acc) => Ok(Total::Float(acc + n as f64)),
+        }
+    }
+
+    fn add_float(self, n: f64) -> Total {
+        match self {
+            Total::Int(acc) => Total::Float(acc as f64 + n),
+            Total::Float(acc) => Total::Float(acc + n),
+        }
+    }
+}
+
+/// Look up `field` on a list element that is either a plain record or a `SecretRecord`.
+pub(crate) fn element_field<'a>(element: &'a Value, field: &str) -> Option<&'a Value> {
+    match element {
+        Value::Record { val, .. } => val.get(field),
+        Value::Custom { val, .. } => val
+            .as_any()
+            .downcast_ref::<SecretRecord>()
+            .and_then(|record| record.get_field(field)),
+        _ => None,
+    }
+}
+
+/// Sum `field` across every element of a secret list, returning a wrapped total.
+///
+/// Field values may be plain or secret ints/floats. Elements whose field is
+/// missing or non-numeric are skipped unless `strict` is set, in which case they
+/// produce an error that names the element index but never its content.
+fn sum_secret_list_field(
+    list: &SecretList,
+    field: &str,
+    strict: bool,
+    span: Span,
+) -> Result<Value, LabeledError> {
+    let mut total = Total::Int(0);
+
+    for (index, element) in list.reveal().iter().enumerate() {
+        let field_value = element_field(element, field);
+
+        let next = match field_value {
+            Some(Value::Int { val, .. }) => Some(total.add_int(*val, field, span)?),
+            Some(Value::Float { val, .. }) => Some(total.add_float(*val)),
+            Some(Value::Custom { val, .. }) => {
+                if let Some(secret_int) = val.as_any().downcast_ref::<SecretInt>() {
+                    Some(total.add_int(secret_int.reveal(), field, span)?)
+                } else {
+                    val.as_any()
+                        .downcast_ref::<SecretFloat>()
+                        .map(|secret_float| total.add_float(secret_float.reveal()))
+                }
+            }
+            _ => None,
+        };
+
+        match next {
+            Some(updated) => total = updated,
+            None if strict => {
+                let problem = if field_value.is_some() {
+                    "is not numeric"
+                } else {
+                    "is missing"
+                };
+                return Err(LabeledError::new("Invalid field").with_label(
+                    format!("Field '{}' {} in element {}", field, problem, index),
+                    span,
+                ));
+            }
+            None => {}
+        }
+    }
+
+    Ok(match total {
+        Total::Int(sum) => Value::custom(Box::new(SecretInt::new(sum)), span),
+        Total::Float(sum) => Value::custom(Box::new(SecretFloat::new(sum)), span),
+    })
+}
+
+impl PluginCommand for SecretListSumByCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret list sum-by"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![
+                (
+                    Type::Custom("secret_list".into()),
+                    Type::Custom("secret_int".into()),
+                ),
+                (
+                    Type::Custom("secret_list".into()),
+                    Type::Custom("secret_float".into()),
+                ),
+            ])
+            .required(
+                "field",
+                SyntaxShape::String,
+                "Numeric record field to sum across the list",
+            )
+            .switch(
+                "strict",
+                "Error on elements whose field is missing or non-numeric instead of skipping them",
+                Some('s'),
+            )
+            .category(Category::Math)
+    }
+
+    fn description(&self) -> &str {
+        "Sum a numeric field across a secret list of records, keeping the total secret"
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                example: r#"[{amount: 10} {amount: 20}] | secret wrap | secret list sum-by amount"#,
+                description: "Sum transaction amounts into a secret int",
+                result: None,
+            },
+            Example {
+                example: r#"[{amount: 10} {note: "x"}] | secret wrap | secret list sum-by amount --strict"#,
+                description: "Fail when an element lacks the field",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let field: String = call.req(0)?;
+        let strict = call.has_flag("strict")?;
+
+        match input {
+            PipelineData::Value(Value::Custom { val, .. }, metadata) => {
+                let secret_list = val.as_any().downcast_ref::<SecretList>().ok_or_else(|| {
+                    LabeledError::new("Unsupported secret type")
+                        .with_label("Only SecretList supports sum-by", call.head)
+                })?;
+
+                let result = sum_secret_list_field(secret_list, &field, strict, call.head)?;
+                Ok(PipelineData::Value(result, metadata))
+            }
+            PipelineData::Value(_, _) => Err(LabeledError::new("Invalid input").with_label(
+                "Input must be a secret list. Use 'secret wrap' to create a secret first",
+                call.head,
+            )),
+            _ => Err(LabeledError::new("Invalid input")
+                .with_label("Expected a single secret value", call.head)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nu_protocol::Record;
+
+    fn secret_record(amount: Value) -> Value {
+        let span = Span::test_data();
+        let mut record = Record::new();
+        record.push("amount", amount);
+        Value::custom(Box::new(SecretRecord::new(record)), span)
+    }
+
+    fn unwrap_total(value: Value) -> Total {
+        let Value::Custom { val, .. } = value else {
+            panic!("Expected custom value");
+        };
+        if let Some(secret_int) = val.as_any().downcast_ref::<SecretInt>() {
+            Total::Int(secret_int.reveal())
+        } else if let Some(secret_float) = val.as_any().downcast_ref::<SecretFloat>() {
+            Total::Float(secret_float.reveal())
+        } else {
+            panic!("Expected SecretInt or SecretFloat");
+        }
+    }
+
+    #[test]
+    fn test_command_name() {
+        let command = SecretListSumByCommand;
+        assert_eq!(command.name(), "secret list sum-by");
+    }
+
+    #[test]
+    fn test_signature() {
+        let command = SecretListSumByCommand;
+        let signature = command.signature();
+        assert_eq!(signature.required_positional[0].name, "field");
+        assert!(signature.get_long_flag("strict").is_some());
+    }
+
+    #[test]
+    fn test_sum_three_secret_records() {
+        let span = Span::test_data();
+        let list = SecretList::new(vec![
+            secret_record(Value::int(10, span)),
+            secret_record(Value::int(20, span)),
+            secret_record(Value::custom(Box::new(SecretInt::new(12)), span)),
+        ]);
+
+        let total = sum_secret_list_field(&list, "amount", false, span).unwrap();
+        assert_eq!(unwrap_total(total), Total::Int(42));
+    }
+
+    #[test]
+    fn test_sum_promotes_to_float() {
+        let span = Span::test_data();
+        let list = SecretList::new(vec![
+            secret_record(Value::int(1, span)),
+            secret_record(Value::float(0.5, span)),
+            secret_record(Value::int(2, span)),
+        ]);
+
+        let total = sum_secret_list_field(&list, "amount", false, span).unwrap();
+        assert_eq!(unwrap_total(total), Total::Float(3.5));
+    }
+
+    #[test]
+    fn test_sum_overflow_errors() {
+        let span = Span::test_data();
+        let list = SecretList::new(vec![
+            secret_record(Value::int(i64::MAX, span)),
+            secret_record(Value::int(1, span)),
+            secret_record(Value::int(1, span)),
+        ]);
+
+        let result = sum_secret_list_field(&list, "amount", false, span);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().msg.contains("overflow"));
+    }
+
+    #[test]
+    fn test_missing_field_skipped_or_strict_error() {
+        let span = Span::test_data();
+        let mut other = Record::new();
+        other.push("note", Value::string("super-secret-note", span));
+        let list = SecretList::new(vec![
+            secret_record(Value::int(5, span)),
+            Value::record(other, span),
+        ]);
+
+        let total = sum_secret_list_field(&list, "amount", false, span).unwrap();
+        assert_eq!(unwrap_total(total), Total::Int(5));
+
+        let err = sum_secret_list_field(&list, "amount", true, span).unwrap_err();
+        assert!(!format!("{:?}", err).contains("super-secret-note"));
+    }
+
+    #[test]
+    fn test_non_numeric_field_strict_error() {
+        let span = Span::test_data();
+        let list = SecretList::new(vec![secret_record(Value::string("ten", span))]);
+
+        let err = sum_secret_list_field(&list, "amount", true, span).unwrap_err();
+        assert!(format!("{:?}", err).contains("not numeric"));
+    }
+}
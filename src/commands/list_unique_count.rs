@@ -0,0 +1,134 @@
+//! Implements `secret list unique-count` — counts the distinct elements of a
+//! secret list without revealing them.
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{Category, Example, LabeledError, PipelineData, Signature, Span, Type, Value};
+
+use crate::SecretList;
+
+#[derive(Clone)]
+pub struct SecretListUniqueCountCommand;
+
+/// Count the distinct elements of a secret list by `Value` equality.
+fn unique_count(list: &SecretList, span: Span) -> Value {
+    let mut distinct: Vec<&Value> = Vec::new();
+    for element in list.reveal() {
+        if !distinct.contains(&element) {
+            distinct.push(element);
+        }
+    }
+
+    Value::int(distinct.len() as i64, span)
+}
+
+impl PluginCommand for SecretListUniqueCountCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret list unique-count"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Custom("secret_list".into()), Type::Int)])
+            .category(Category::Filters)
+    }
+
+    fn description(&self) -> &str {
+        "Count the distinct elements of a secret list without revealing them"
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![Example {
+            example: r#"["a" "b" "a"] | secret wrap | secret list unique-count"#,
+            description: "Count the distinct values in a secret list",
+            result: Some(Value::test_int(2)),
+        }]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        match input {
+            PipelineData::Value(Value::Custom { val, .. }, metadata) => {
+                let secret_list = val.as_any().downcast_ref::<SecretList>().ok_or_else(|| {
+                    LabeledError::new("Unsupported secret type")
+                        .with_label("Only SecretList supports unique-count", call.head)
+                })?;
+
+                Ok(PipelineData::Value(
+                    unique_count(secret_list, call.head),
+                    metadata,
+                ))
+            }
+            PipelineData::Value(_, _) => Err(LabeledError::new("Invalid input").with_label(
+                "Input must be a secret list. Use 'secret wrap' to create a secret first",
+                call.head,
+            )),
+            _ => Err(LabeledError::new("Invalid input")
+                .with_label("Expected a single secret value", call.head)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn count(values: Vec<Value>) -> i64 {
+        unique_count(&SecretList::new(values), Span::test_data())
+            .as_int()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_command_name() {
+        let command = SecretListUniqueCountCommand;
+        assert_eq!(command.name(), "secret list unique-count");
+    }
+
+    #[test]
+    fn test_signature() {
+        let command = SecretListUniqueCountCommand;
+        let signature = command.signature();
+        assert_eq!(signature.name, "secret list unique-count");
+        assert_eq!(signature.input_output_types.len(), 1);
+    }
+
+    #[test]
+    fn test_unique_count_with_duplicates() {
+        let span = Span::test_data();
+        assert_eq!(
+            count(vec![
+                Value::string("a", span),
+                Value::string("b", span),
+                Value::string("a", span),
+                Value::int(1, span),
+                Value::int(1, span),
+            ]),
+            3
+        );
+    }
+
+    #[test]
+    fn test_unique_count_all_unique() {
+        let span = Span::test_data();
+        assert_eq!(
+            count(vec![
+                Value::string("a", span),
+                Value::string("b", span),
+                Value::int(1, span),
+            ]),
+            3
+        );
+    }
+
+    #[test]
+    fn test_unique_count_empty() {
+        assert_eq!(count(vec![]), 0);
+    }
+}
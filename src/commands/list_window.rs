@@ -0,0 +1,166 @@
+//! Implements `secret list window` — splits a secret list into sliding windows
+//! that are themselves secret lists.
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, Signature, Span, SyntaxShape, Type, Value,
+};
+
+use crate::SecretList;
+
+#[derive(Clone)]
+pub struct SecretListWindowCommand;
+
+/// Build sliding windows of `size` elements over a secret list.
+///
+/// Each window is wrapped as its own `SecretList` and the windows are collected
+/// into an outer `SecretList`. A list shorter than `size` yields no windows.
+fn window_secret_list(list: &SecretList, size: usize, span: Span) -> Result<Value, LabeledError> {
+    if size == 0 {
+        return Err(LabeledError::new("Invalid window size")
+            .with_label("Window size must be greater than 0", span));
+    }
+
+    let windows = list
+        .reveal()
+        .windows(size)
+        .map(|window| Value::custom(Box::new(SecretList::new(window.to_vec())), span))
+        .collect();
+
+    Ok(Value::custom(Box::new(SecretList::new(windows)), span))
+}
+
+impl PluginCommand for SecretListWindowCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret list window"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(
+                Type::Custom("secret_list".into()),
+                Type::Custom("secret_list".into()),
+            )])
+            .required(
+                "size",
+                SyntaxShape::Int,
+                "Number of elements in each window (must be greater than 0)",
+            )
+            .category(Category::Filters)
+    }
+
+    fn description(&self) -> &str {
+        "Split a secret list into sliding windows that stay secret"
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                example: r#"[1 2 3 4] | secret wrap | secret list window 2"#,
+                description: "Produce the windows [1 2], [2 3] and [3 4], each as a secret list",
+                result: None,
+            },
+            Example {
+                example: r#"[1 2] | secret wrap | secret list window 3"#,
+                description: "A window larger than the list produces an empty secret list",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let size: i64 = call.req(0)?;
+        let size = usize::try_from(size).map_err(|_| {
+            LabeledError::new("Invalid window size")
+                .with_label("Window size must not be negative", call.head)
+        })?;
+
+        match input {
+            PipelineData::Value(Value::Custom { val, .. }, metadata) => {
+                let secret_list = val.as_any().downcast_ref::<SecretList>().ok_or_else(|| {
+                    LabeledError::new("Unsupported secret type")
+                        .with_label("Only SecretList supports window", call.head)
+                })?;
+
+                let result = window_secret_list(secret_list, size, call.head)?;
+                Ok(PipelineData::Value(result, metadata))
+            }
+            PipelineData::Value(_, _) => Err(LabeledError::new("Invalid input").with_label(
+                "Input must be a secret list. Use 'secret wrap' to create a secret first",
+                call.head,
+            )),
+            _ => Err(LabeledError::new("Invalid input")
+                .with_label("Expected a single secret value", call.head)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn as_secret_list(value: &Value) -> &SecretList {
+        value
+            .as_custom_value()
+            .unwrap()
+            .as_any()
+            .downcast_ref::<SecretList>()
+            .unwrap()
+    }
+
+    fn ints(list: &SecretList) -> Vec<i64> {
+        list.reveal().iter().map(|v| v.as_int().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_command_name() {
+        let command = SecretListWindowCommand;
+        assert_eq!(command.name(), "secret list window");
+    }
+
+    #[test]
+    fn test_signature() {
+        let command = SecretListWindowCommand;
+        let signature = command.signature();
+        assert_eq!(signature.name, "secret list window");
+        assert_eq!(signature.required_positional.len(), 1);
+    }
+
+    #[test]
+    fn test_window_four_elements_size_two() {
+        let span = Span::test_data();
+        let list = SecretList::new((1..=4).map(|i| Value::int(i, span)).collect());
+
+        let result = window_secret_list(&list, 2, span).unwrap();
+        let windows = as_secret_list(&result).reveal();
+
+        assert_eq!(windows.len(), 3);
+        assert_eq!(ints(as_secret_list(&windows[0])), vec![1, 2]);
+        assert_eq!(ints(as_secret_list(&windows[1])), vec![2, 3]);
+        assert_eq!(ints(as_secret_list(&windows[2])), vec![3, 4]);
+    }
+
+    #[test]
+    fn test_window_larger_than_list_is_empty() {
+        let span = Span::test_data();
+        let list = SecretList::new(vec![Value::int(1, span), Value::int(2, span)]);
+
+        let result = window_secret_list(&list, 3, span).unwrap();
+        assert!(as_secret_list(&result).is_empty());
+    }
+
+    #[test]
+    fn test_window_size_zero_errors() {
+        let span = Span::test_data();
+        let list = SecretList::new(vec![Value::int(1, span)]);
+        assert!(window_secret_list(&list, 0, span).is_err());
+    }
+}
@@ -0,0 +1,269 @@
+//! Implements `secret map` — transforms a secret's inner value with a closure
+//! and re-wraps the result as the same secret type.
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    engine::Closure, Category, Example, LabeledError, PipelineData, Signature, Span, Spanned,
+    SyntaxShape, Type, Value,
+};
+
+use super::unwrap::reveal_secret_value;
+use crate::{
+    SecretBinary, SecretBool, SecretCellPath, SecretDate, SecretDuration, SecretFilesize,
+    SecretFloat, SecretInt, SecretList, SecretRange, SecretRecord, SecretString,
+};
+
+#[derive(Clone)]
+pub struct SecretMapCommand;
+
+/// Wrap `output` as the same secret type as `val`, keeping its redaction template
+/// and origin span.
+///
+/// Returns `None` when `output` does not match the secret's type.
+fn rewrap_like(val: &dyn nu_protocol::CustomValue, output: Value, span: Span) -> Option<Value> {
+    let any = val.as_any();
+    let secret: Box<dyn nu_protocol::CustomValue> = match output {
+        Value::String { val, .. } => Box::new(any.downcast_ref::<SecretString>()?.with_value(val)),
+        Value::Int { val, .. } => Box::new(any.downcast_ref::<SecretInt>()?.with_value(val)),
+        Value::Bool { val, .. } => Box::new(any.downcast_ref::<SecretBool>()?.with_value(val)),
+        Value::Float { val, .. } => Box::new(any.downcast_ref::<SecretFloat>()?.with_value(val)),
+        Value::Date { val, .. } => Box::new(any.downcast_ref::<SecretDate>()?.with_value(val)),
+        Value::Binary { val, .. } => Box::new(any.downcast_ref::<SecretBinary>()?.with_value(val)),
+        Value::Duration { val, .. } => {
+            Box::new(any.downcast_ref::<SecretDuration>()?.with_value(val))
+        }
+        Value::Filesize { val, .. } => {
+            Box::new(any.downcast_ref::<SecretFilesize>()?.with_value(val.get()))
+        }
+        Value::List { vals, .. } => Box::new(any.downcast_ref::<SecretList>()?.with_value(vals)),
+        Value::Record { val, .. } => Box::new(
+            any.downcast_ref::<SecretRecord>()?
+                .with_value(val.into_owned()),
+        ),
+        Value::Range { val, .. } => Box::new(any.downcast_ref::<SecretRange>()?.with_value(*val)),
+        Value::CellPath { val, .. } => {
+            Box::new(any.downcast_ref::<SecretCellPath>()?.with_value(val))
+        }
+        _ => return None,
+    };
+    Some(Value::custom(secret, span))
+}
+
+/// Apply `transform` to the revealed value of a secret and re-wrap the result.
+///
+/// The revealed value is only handed to `transform`; the caller only ever sees
+/// the re-wrapped secret, which keeps the input's redaction template and origin
+/// span. The result must have the same type as the input's value, otherwise a
+/// type mismatch error is returned without revealing the value.
+fn map_secret<F>(
+    val: &dyn nu_protocol::CustomValue,
+    transform: F,
+    span: Span,
+) -> Result<Value, LabeledError>
+where
+    F: FnOnce(Value) -> Result<Value, LabeledError>,
+{
+    let revealed = reveal_secret_value(val, span).ok_or_else(|| {
+        LabeledError::new("Unsupported secret type")
+            .with_label("Expected a secret value to map", span)
+    })?;
+
+    let output = transform(revealed)?;
+    let output_type = output.get_type();
+    let mismatch = || {
+        LabeledError::new("Type mismatch").with_label(
+            format!(
+                "Closure must return a value for '{}', got '{}'",
+                val.type_name(),
+                output_type
+            ),
+            span,
+        )
+    };
+
+    rewrap_like(val, output, span).ok_or_else(mismatch)
+}
+
+impl PluginCommand for SecretMapCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret map"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Any, Type::Any)])
+            .required(
+                "closure",
+                SyntaxShape::Closure(Some(vec![SyntaxShape::Any])),
+                "Closure applied to the revealed value; its result is re-wrapped",
+            )
+            .category(Category::Conversions)
+    }
+
+    fn description(&self) -> &str {
+        "Transform the inner value of a secret with a closure, keeping the result wrapped"
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                example: r#""  my-api-key  " | secret wrap | secret map { str trim }"#,
+                description: "Trim whitespace from a secret string without unwrapping it",
+                result: None,
+            },
+            Example {
+                example: r#"41 | secret wrap | secret map {|n| $n + 1 }"#,
+                description: "Increment a secret integer",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let closure: Spanned<Closure> = call.req(0)?;
+
+        match input {
+            PipelineData::Value(Value::Custom { val, .. }, metadata) => {
+                let result = map_secret(
+                    val.as_ref(),
+                    |revealed| {
+                        engine
+                            .eval_closure(&closure, vec![revealed.clone()], Some(revealed))
+                            .map_err(LabeledError::from)
+                    },
+                    call.head,
+                )?;
+                Ok(PipelineData::Value(result, metadata))
+            }
+            PipelineData::Value(value, _) => Err(LabeledError::new("Invalid input").with_label(
+                format!(
+                    "Expected a secret value, got '{}'. Use 'secret wrap' to create a secret first",
+                    value.get_type()
+                ),
+                call.head,
+            )),
+            _ => Err(LabeledError::new("Invalid input")
+                .with_label("Expected a single secret value", call.head)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nu_protocol::CustomValue;
+
+    #[test]
+    fn test_command_name() {
+        let command = SecretMapCommand;
+        assert_eq!(command.name(), "secret map");
+    }
+
+    #[test]
+    fn test_signature() {
+        let command = SecretMapCommand;
+        let signature = command.signature();
+        assert_eq!(signature.name, "secret map");
+        assert_eq!(signature.required_positional.len(), 1);
+    }
+
+    #[test]
+    fn test_map_preserves_string_type() {
+        let span = Span::test_data();
+        let secret = SecretString::new("  API-KEY  ".to_string());
+
+        let result = map_secret(
+            &secret,
+            |value| {
+                Ok(Value::string(
+                    value.as_str().unwrap().trim().to_lowercase(),
+                    span,
+                ))
+            },
+            span,
+        )
+        .unwrap();
+
+        let custom = result.as_custom_value().unwrap();
+        assert_eq!(custom.type_name(), secret.type_name());
+        assert_eq!(
+            custom
+                .as_any()
+                .downcast_ref::<SecretString>()
+                .unwrap()
+                .reveal(),
+            "api-key"
+        );
+    }
+
+    #[test]
+    fn test_map_preserves_int_type() {
+        let span = Span::test_data();
+        let secret = SecretInt::new(41);
+
+        let result = map_secret(
+            &secret,
+            |value| Ok(Value::int(value.as_int().unwrap() + 1, span)),
+            span,
+        )
+        .unwrap();
+
+        let custom = result.as_custom_value().unwrap();
+        assert_eq!(custom.type_name(), "secret_int");
+        assert_eq!(
+            custom
+                .as_any()
+                .downcast_ref::<SecretInt>()
+                .unwrap()
+                .reveal(),
+            42
+        );
+    }
+
+    #[test]
+    fn test_map_keeps_template_and_origin_span() {
+        let span = Span::test_data();
+        let origin = Span::new(3, 9);
+        let secret = SecretString::new_with_template("api-key".to_string(), "[X]".to_string())
+            .with_origin_span(origin);
+
+        let result = map_secret(
+            &secret,
+            |value| Ok(Value::string(value.as_str().unwrap().to_uppercase(), span)),
+            span,
+        )
+        .unwrap();
+
+        let custom = result.as_custom_value().unwrap();
+        let mapped = custom.as_any().downcast_ref::<SecretString>().unwrap();
+        assert_eq!(mapped.reveal(), "API-KEY");
+        assert_eq!(mapped.origin_span(), Some(origin));
+        assert_eq!(custom.to_base_value(span).unwrap().as_str().unwrap(), "[X]");
+        assert_eq!(format!("{}", mapped), "[X]");
+    }
+
+    #[test]
+    fn test_map_rejects_type_change() {
+        let span = Span::test_data();
+        let secret = SecretString::new("hidden-value".to_string());
+
+        let err = map_secret(&secret, |_| Ok(Value::int(1, span)), span).unwrap_err();
+        assert_eq!(err.msg, "Type mismatch");
+        assert!(!format!("{:?}", err).contains("hidden-value"));
+    }
+
+    #[test]
+    fn test_map_rejects_unwrappable_result() {
+        let span = Span::test_data();
+        let secret = SecretString::new("x".to_string());
+        assert!(map_secret(&secret, |_| Ok(Value::nothing(span)), span).is_err());
+    }
+}
@@ -1,37 +1,136 @@
 //! Command implementations for the `secret` plugin.
 
+mod binary_concat;
+mod binary_hex;
+mod binary_int;
+mod binary_reverse;
+mod binary_to_string;
+mod concat;
+mod config_backup;
+mod config_check_conflicts;
+mod config_check_template_vars;
+mod config_diff_files;
+mod config_enforce_minimum;
 mod config_export;
+mod config_get;
 mod config_import;
+mod config_init;
+mod config_lint_templates;
+mod config_preset;
+mod config_preview_level;
+mod config_report;
 mod config_reset;
+mod config_restore;
 mod config_show;
+mod config_test_env;
 mod config_validate;
 mod configure;
 mod contains;
+mod date_is_expired;
+mod date_to_timezone;
+mod encoding;
+mod ends_with;
+mod equals;
+mod float_to_int;
+mod get;
 mod hash;
 mod info;
+mod int_to_float;
 mod is_empty;
 mod length;
+mod list_chunk_by;
+mod list_join_records;
+mod list_sum_by;
+mod list_unique_count;
+mod list_window;
+mod map;
+mod redact_with;
+mod reveal_into;
+mod select;
+mod slice;
+mod starts_with;
+mod string_case;
+mod string_contains_substring;
+mod string_count_chars;
+mod string_index_of;
+mod string_lines;
+mod string_matches;
+mod string_pad;
+mod string_to_bytes;
 mod type_of;
 mod unwrap;
 mod validate;
 mod validate_format;
+mod where_length;
 pub mod wrap;
 mod wrap_with;
 
+pub use binary_concat::SecretBinaryConcatCommand;
+pub use binary_hex::SecretBinaryFromHexCommand;
+pub use binary_hex::SecretBinaryToHexCommand;
+pub use binary_int::SecretBinaryToIntCommand;
+pub use binary_int::SecretIntToBinaryCommand;
+pub use binary_reverse::SecretBinaryReverseCommand;
+pub use binary_to_string::SecretBinaryToStringCommand;
+pub use concat::SecretConcatCommand;
+pub use config_backup::SecretConfigBackupCommand;
+pub use config_check_conflicts::SecretConfigCheckConflictsCommand;
+pub use config_check_template_vars::SecretConfigCheckTemplateVarsCommand;
+pub use config_diff_files::SecretConfigDiffFilesCommand;
+pub use config_enforce_minimum::SecretConfigEnforceMinimumCommand;
 pub use config_export::SecretConfigExportCommand;
+pub use config_get::SecretConfigGetCommand;
 pub use config_import::SecretConfigImportCommand;
+pub use config_init::SecretConfigInitCommand;
+pub use config_lint_templates::SecretConfigLintTemplatesCommand;
+pub use config_preset::SecretConfigPresetCommand;
+pub use config_preview_level::SecretConfigPreviewLevelCommand;
+pub use config_report::SecretConfigReportCommand;
 pub use config_reset::SecretConfigResetCommand;
+pub use config_restore::SecretConfigRestoreCommand;
 pub use config_show::SecretConfigShowCommand;
+pub use config_test_env::SecretConfigTestEnvCommand;
 pub use config_validate::SecretConfigValidateCommand;
 pub use configure::SecretConfigureCommand;
 pub use contains::SecretContainsCommand;
+pub use date_is_expired::SecretDateIsExpiredCommand;
+pub use date_to_timezone::SecretDateToTimezoneCommand;
+pub use encoding::SecretFromBase64Command;
+pub use encoding::SecretToBase64Command;
+pub use ends_with::SecretEndsWithCommand;
+pub use equals::SecretEqualsCommand;
+pub use float_to_int::SecretFloatToIntCommand;
+pub use get::SecretGetCommand;
 pub use hash::SecretHashCommand;
 pub use info::SecretInfoCommand;
+pub use int_to_float::SecretIntToFloatCommand;
 pub use is_empty::SecretIsEmptyCommand;
 pub use length::SecretLengthCommand;
+pub use list_chunk_by::SecretListChunkByCommand;
+pub use list_join_records::SecretListJoinRecordsCommand;
+pub use list_sum_by::SecretListSumByCommand;
+pub use list_unique_count::SecretListUniqueCountCommand;
+pub use list_window::SecretListWindowCommand;
+pub use map::SecretMapCommand;
+pub use redact_with::SecretRedactWithCommand;
+pub use reveal_into::SecretRevealIntoCommand;
+pub use select::SecretSelectCommand;
+pub use slice::SecretSliceCommand;
+pub use starts_with::SecretStartsWithCommand;
+pub use string_case::SecretStringCapitalizeCommand;
+pub use string_case::SecretStringTitleCaseCommand;
+pub use string_contains_substring::SecretStringContainsSubstringCommand;
+pub use string_count_chars::SecretStringCountCharsCommand;
+pub use string_index_of::SecretStringIndexOfCommand;
+pub use string_lines::SecretStringLinesCommand;
+pub use string_matches::SecretStringMatchesCommand;
+pub use string_pad::SecretStringPadLeftCommand;
+pub use string_pad::SecretStringPadRightCommand;
+pub use string_to_bytes::SecretStringToBytesCommand;
 pub use type_of::SecretTypeOfCommand;
 pub use unwrap::SecretUnwrapCommand;
 pub use validate::SecretValidateCommand;
 pub use validate_format::SecretValidateFormatCommand;
+pub use where_length::SecretWhereLengthCommand;
 pub use wrap::SecretWrapCommand;
 pub use wrap_with::SecretWrapWithCommand;
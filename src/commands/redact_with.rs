@@ -0,0 +1,292 @@
+//! Implements `secret redact-with` — attaches a custom redaction template to an
+//! existing secret.
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, Signature, Span, SyntaxShape, Type, Value,
+};
+
+use crate::config::ConfigManager;
+use crate::{
+    SecretBinary, SecretBool, SecretCellPath, SecretDate, SecretDuration, SecretFilesize,
+    SecretFloat, SecretInt, SecretList, SecretRange, SecretRecord, SecretString,
+};
+
+#[derive(Clone)]
+pub struct SecretRedactWithCommand;
+
+/// Restore `origin` on a secret rebuilt from another one, if it had one.
+fn keep_origin<T>(secret: T, origin: Option<Span>, with_origin_span: fn(T, Span) -> T) -> T {
+    match origin {
+        Some(origin) => with_origin_span(secret, origin),
+        None => secret,
+    }
+}
+
+/// Re-wrap a secret custom value with `template` as its redaction template.
+///
+/// Any template already attached to the secret is replaced; the origin span is kept.
+pub(crate) fn redact_secret_with(
+    val: &dyn nu_protocol::CustomValue,
+    template: String,
+    span: Span,
+) -> Result<Value, LabeledError> {
+    let any = val.as_any();
+    let secret: Box<dyn nu_protocol::CustomValue> =
+        if let Some(secret) = any.downcast_ref::<SecretString>() {
+            Box::new(keep_origin(
+                SecretString::new_with_template(secret.clone().into_inner(), template),
+                secret.origin_span(),
+                SecretString::with_origin_span,
+            ))
+        } else if let Some(secret) = any.downcast_ref::<SecretInt>() {
+            Box::new(keep_origin(
+                SecretInt::new_with_template(secret.reveal(), template),
+                secret.origin_span(),
+                SecretInt::with_origin_span,
+            ))
+        } else if let Some(secret) = any.downcast_ref::<SecretBool>() {
+            Box::new(keep_origin(
+                SecretBool::new_with_template(secret.reveal(), template),
+                secret.origin_span(),
+                SecretBool::with_origin_span,
+            ))
+        } else if let Some(secret) = any.downcast_ref::<SecretFloat>() {
+            Box::new(keep_origin(
+                SecretFloat::new_with_template(secret.reveal(), template),
+                secret.origin_span(),
+                SecretFloat::with_origin_span,
+            ))
+        } else if let Some(secret) = any.downcast_ref::<SecretDate>() {
+            Box::new(keep_origin(
+                SecretDate::new_with_template(*secret.reveal(), template),
+                secret.origin_span(),
+                SecretDate::with_origin_span,
+            ))
+        } else if let Some(secret) = any.downcast_ref::<SecretBinary>() {
+            Box::new(keep_origin(
+                SecretBinary::new_with_template(secret.reveal().into_owned(), template),
+                secret.origin_span(),
+                SecretBinary::with_origin_span,
+            ))
+        } else if let Some(secret) = any.downcast_ref::<SecretList>() {
+            Box::new(keep_origin(
+                SecretList::new_with_template(secret.reveal().clone(), template),
+                secret.origin_span(),
+                SecretList::with_origin_span,
+            ))
+        } else if let Some(secret) = any.downcast_ref::<SecretRecord>() {
+            Box::new(keep_origin(
+                SecretRecord::new_with_template(secret.reveal().clone(), template),
+                secret.origin_span(),
+                SecretRecord::with_origin_span,
+            ))
+        } else if let Some(secret) = any.downcast_ref::<SecretDuration>() {
+            Box::new(keep_origin(
+                SecretDuration::new_with_template(secret.reveal(), template),
+                secret.origin_span(),
+                SecretDuration::with_origin_span,
+            ))
+        } else if let Some(secret) = any.downcast_ref::<SecretFilesize>() {
+            Box::new(keep_origin(
+                SecretFilesize::new_with_template(secret.reveal(), template),
+                secret.origin_span(),
+                SecretFilesize::with_origin_span,
+            ))
+        } else if let Some(secret) = any.downcast_ref::<SecretRange>() {
+            Box::new(keep_origin(
+                SecretRange::new_with_template(*secret.reveal(), template),
+                secret.origin_span(),
+                SecretRange::with_origin_span,
+            ))
+        } else if let Some(secret) = any.downcast_ref::<SecretCellPath>() {
+            Box::new(keep_origin(
+                SecretCellPath::new_with_template(secret.reveal().clone(), template),
+                secret.origin_span(),
+                SecretCellPath::with_origin_span,
+            ))
+        } else {
+            return Err(LabeledError::new("Unsupported secret type").with_label(
+                format!("Cannot attach a template to {}", val.type_name()),
+                span,
+            ));
+        };
+
+    Ok(Value::custom(secret, span))
+}
+
+impl PluginCommand for SecretRedactWithCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret redact-with"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Any, Type::Any)])
+            .required(
+                "template",
+                SyntaxShape::String,
+                "The redaction template to use for this secret",
+            )
+            .category(Category::Conversions)
+    }
+
+    fn description(&self) -> &str {
+        "Attach a custom redaction template to an existing secret"
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                example: r#""my-api-key" | secret wrap | secret redact-with "[HIDDEN:{{secret_type}}]""#,
+                description: "Change how an already wrapped secret is displayed",
+                result: None,
+            },
+            Example {
+                example: r#"$env.DB_CONFIG | secret redact-with "<db config>""#,
+                description: "Give a secret loaded elsewhere a descriptive redaction",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let template: String = call.req(0)?;
+        ConfigManager::validate_redaction_template(&template).map_err(|e| {
+            LabeledError::new("Invalid template").with_label(e.to_string(), call.head)
+        })?;
+
+        match input {
+            PipelineData::Value(value, metadata) => {
+                let result = match value {
+                    Value::Custom { val, .. } => {
+                        redact_secret_with(val.as_ref(), template, call.head)?
+                    }
+                    _ => {
+                        return Err(LabeledError::new("Invalid input").with_label(
+                            "Input must be a secret value. Use 'secret wrap' to create a secret first",
+                            call.head,
+                        ));
+                    }
+                };
+
+                Ok(PipelineData::Value(result, metadata))
+            }
+            _ => Err(LabeledError::new("Invalid input")
+                .with_label("Expected a single secret value", call.head)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::unwrap::{reveal_secret_value, secret_origin_span};
+    use super::*;
+
+    #[test]
+    fn test_command_name() {
+        let command = SecretRedactWithCommand;
+        assert_eq!(command.name(), "secret redact-with");
+    }
+
+    #[test]
+    fn test_signature() {
+        let command = SecretRedactWithCommand;
+        let signature = command.signature();
+        assert_eq!(signature.name, "secret redact-with");
+        assert_eq!(signature.required_positional.len(), 1);
+    }
+
+    #[test]
+    fn test_display_uses_custom_template() {
+        let span = Span::test_data();
+        let secret = SecretString::new("my-api-key".to_string());
+
+        let result =
+            redact_secret_with(&secret, "[HIDDEN:{{secret_type}}]".to_string(), span).unwrap();
+        let custom = result.as_custom_value().unwrap();
+        let redacted = custom.as_any().downcast_ref::<SecretString>().unwrap();
+
+        assert!(redacted.has_custom_template());
+        assert_eq!(format!("{}", redacted), "[HIDDEN:string]");
+        assert_eq!(
+            reveal_secret_value(custom, span).unwrap().as_str().unwrap(),
+            "my-api-key"
+        );
+    }
+
+    #[test]
+    fn test_replaces_existing_template() {
+        let span = Span::test_data();
+        let secret = SecretInt::new_with_template(42, "old".to_string());
+
+        let result = redact_secret_with(&secret, "new-{{secret_type}}".to_string(), span).unwrap();
+        let redacted = result
+            .as_custom_value()
+            .unwrap()
+            .as_any()
+            .downcast_ref::<SecretInt>()
+            .unwrap();
+
+        assert_eq!(format!("{}", redacted), "new-int");
+        assert_eq!(redacted.reveal(), 42);
+    }
+
+    #[test]
+    fn test_all_secret_types_supported() {
+        let span = Span::test_data();
+        let secrets: Vec<Box<dyn nu_protocol::CustomValue>> = vec![
+            Box::new(SecretBool::new(true)),
+            Box::new(SecretFloat::new(1.5)),
+            Box::new(SecretBinary::new(vec![1, 2])),
+            Box::new(SecretList::new(vec![Value::int(1, span)])),
+            Box::new(SecretRecord::new(nu_protocol::Record::new())),
+            Box::new(SecretDuration::new(1_000)),
+            Box::new(SecretFilesize::new(1_024)),
+        ];
+
+        for secret in secrets {
+            let result = redact_secret_with(secret.as_ref(), "X".to_string(), span).unwrap();
+            let custom = result.as_custom_value().unwrap();
+            assert_eq!(custom.type_name(), secret.type_name());
+            assert_eq!(custom.to_base_value(span).unwrap().as_str().unwrap(), "X");
+        }
+    }
+
+    #[test]
+    fn test_origin_span_survives() {
+        let span = Span::test_data();
+        let origin = Span::new(4, 12);
+        let secrets: Vec<Box<dyn nu_protocol::CustomValue>> = vec![
+            Box::new(SecretString::new("my-api-key".to_string()).with_origin_span(origin)),
+            Box::new(SecretInt::new(42).with_origin_span(origin)),
+            Box::new(SecretList::new(vec![Value::int(1, span)]).with_origin_span(origin)),
+        ];
+
+        for secret in secrets {
+            let result = redact_secret_with(secret.as_ref(), "X".to_string(), span).unwrap();
+            assert_eq!(
+                secret_origin_span(result.as_custom_value().unwrap()),
+                Some(origin)
+            );
+        }
+
+        let unknown = SecretInt::new(1);
+        let result = redact_secret_with(&unknown, "X".to_string(), span).unwrap();
+        assert_eq!(secret_origin_span(result.as_custom_value().unwrap()), None);
+    }
+
+    #[test]
+    fn test_invalid_template_rejected() {
+        assert!(ConfigManager::validate_redaction_template("{{ unclosed").is_err());
+        assert!(ConfigManager::validate_redaction_template("[{{secret_type}}]").is_ok());
+    }
+}
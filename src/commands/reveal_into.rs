@@ -0,0 +1,177 @@
+//! Implements `secret reveal-into` — hands a secret's revealed value to a
+//! closure and returns whatever the closure produces.
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    engine::Closure, Category, Example, LabeledError, PipelineData, Signature, Span, Spanned,
+    SyntaxShape, Type, Value,
+};
+
+use super::unwrap::reveal_secret_value;
+
+#[derive(Clone)]
+pub struct SecretRevealIntoCommand;
+
+/// Reveal a secret and pass the plaintext to `consume`, returning its result.
+///
+/// Unlike `secret unwrap`, the plaintext never becomes a pipeline value of its
+/// own: it exists only as the closure argument, and the closure's output is
+/// returned unchanged (not re-wrapped), so the closure decides what escapes.
+fn reveal_into<F>(
+    val: &dyn nu_protocol::CustomValue,
+    consume: F,
+    span: Span,
+) -> Result<Value, LabeledError>
+where
+    F: FnOnce(Value) -> Result<Value, LabeledError>,
+{
+    let revealed = reveal_secret_value(val, span).ok_or_else(|| {
+        LabeledError::new("Unsupported secret type")
+            .with_label("Expected a secret value to reveal", span)
+    })?;
+
+    consume(revealed)
+}
+
+impl PluginCommand for SecretRevealIntoCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret reveal-into"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Any, Type::Any)])
+            .required(
+                "closure",
+                SyntaxShape::Closure(Some(vec![SyntaxShape::Any])),
+                "Closure that receives the revealed value; its result is returned as-is",
+            )
+            .category(Category::Conversions)
+    }
+
+    fn description(&self) -> &str {
+        "Pass the revealed value of a secret to a closure and return the closure's result"
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![Example {
+            example: r#"$env.API_TOKEN | secret wrap | secret reveal-into {|token| http get -H {Authorization: $"Bearer ($token)"} https://api.example.com/me }"#,
+            description:
+                "Use a secret token in a request header without unwrapping it into the pipeline",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let closure: Spanned<Closure> = call.req(0)?;
+
+        match input {
+            PipelineData::Value(Value::Custom { val, .. }, metadata) => {
+                let result = reveal_into(
+                    val.as_ref(),
+                    |revealed| {
+                        engine
+                            .eval_closure(&closure, vec![revealed.clone()], Some(revealed))
+                            .map_err(LabeledError::from)
+                    },
+                    call.head,
+                )?;
+                Ok(PipelineData::Value(result, metadata))
+            }
+            PipelineData::Value(value, _) => Err(LabeledError::new("Invalid input").with_label(
+                format!(
+                    "Expected a secret value, got '{}'. Use 'secret wrap' to create a secret first",
+                    value.get_type()
+                ),
+                call.head,
+            )),
+            _ => Err(LabeledError::new("Invalid input")
+                .with_label("Expected a single secret value", call.head)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{SecretInt, SecretString};
+
+    #[test]
+    fn test_command_name() {
+        let command = SecretRevealIntoCommand;
+        assert_eq!(command.name(), "secret reveal-into");
+    }
+
+    #[test]
+    fn test_signature() {
+        let command = SecretRevealIntoCommand;
+        let signature = command.signature();
+        assert_eq!(signature.name, "secret reveal-into");
+        assert_eq!(signature.required_positional.len(), 1);
+    }
+
+    #[test]
+    fn test_closure_receives_revealed_value() {
+        let span = Span::test_data();
+        let secret = SecretString::new("hunter2".to_string());
+
+        let mut received = None;
+        let result = reveal_into(
+            &secret,
+            |value| {
+                received = Some(value.as_str().unwrap().to_string());
+                Ok(Value::nothing(span))
+            },
+            span,
+        )
+        .unwrap();
+
+        assert_eq!(received.as_deref(), Some("hunter2"));
+        assert!(result.is_nothing());
+    }
+
+    #[test]
+    fn test_returns_closure_output_unwrapped() {
+        let span = Span::test_data();
+        let secret = SecretString::new("hunter2".to_string());
+
+        let result = reveal_into(
+            &secret,
+            |value| {
+                Ok(Value::string(
+                    format!("Bearer {}", value.as_str().unwrap()),
+                    span,
+                ))
+            },
+            span,
+        )
+        .unwrap();
+        assert_eq!(result.as_str().unwrap(), "Bearer hunter2");
+
+        let result = reveal_into(
+            &SecretInt::new(41),
+            |value| Ok(Value::int(value.as_int().unwrap() + 1, span)),
+            span,
+        )
+        .unwrap();
+        assert_eq!(result.as_int().unwrap(), 42);
+    }
+
+    #[test]
+    fn test_closure_error_is_propagated() {
+        let span = Span::test_data();
+        let secret = SecretString::new("hunter2".to_string());
+
+        let err =
+            reveal_into(&secret, |_| Err(LabeledError::new("Closure failed")), span).unwrap_err();
+        assert_eq!(err.msg, "Closure failed");
+    }
+}
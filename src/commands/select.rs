@@ -0,0 +1,190 @@
+//! Implements `secret select` — projects columns of a secret record into a
+//! smaller secret record.
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, Record, Signature, Span, SyntaxShape, Type,
+    Value,
+};
+
+use crate::SecretRecord;
+
+#[derive(Clone)]
+pub struct SecretSelectCommand;
+
+/// Build a `SecretRecord` holding only `columns` of `record`, in the requested order.
+///
+/// The result keeps the original redaction template. Unknown columns are an
+/// error listing the available field names.
+fn select_secret_columns(
+    record: &SecretRecord,
+    columns: &[String],
+    span: Span,
+) -> Result<Value, LabeledError> {
+    let mut selected = Record::new();
+
+    for column in columns {
+        let value = record.get_field(column).ok_or_else(|| {
+            let available = record
+                .fields()
+                .map(String::as_str)
+                .collect::<Vec<_>>()
+                .join(", ");
+            LabeledError::new("Field not found").with_label(
+                format!(
+                    "Secret record has no field '{}'. Available fields: {}",
+                    column, available
+                ),
+                span,
+            )
+        })?;
+        selected.insert(column.clone(), value.clone());
+    }
+
+    Ok(Value::custom(Box::new(record.with_value(selected)), span))
+}
+
+impl PluginCommand for SecretSelectCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret select"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(
+                Type::Custom("secret_record".into()),
+                Type::Custom("secret_record".into()),
+            )])
+            .rest("columns", SyntaxShape::String, "Columns to keep")
+            .category(Category::Filters)
+    }
+
+    fn description(&self) -> &str {
+        "Select columns of a secret record into a new secret record without revealing it"
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![Example {
+            example: r#"{host: "db.local", user: "admin", password: "hunter2"} | secret wrap | secret select user password"#,
+            description: "Keep only the credential fields of a secret record",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let columns: Vec<String> = call.rest(0)?;
+
+        match input {
+            PipelineData::Value(Value::Custom { val, .. }, metadata) => {
+                let secret_record =
+                    val.as_any().downcast_ref::<SecretRecord>().ok_or_else(|| {
+                        LabeledError::new("Unsupported secret type")
+                            .with_label("Only SecretRecord supports select", call.head)
+                    })?;
+
+                let result = select_secret_columns(secret_record, &columns, call.head)?;
+                Ok(PipelineData::Value(result, metadata))
+            }
+            PipelineData::Value(_, _) => Err(LabeledError::new("Invalid input").with_label(
+                "Input must be a secret record. Use 'secret wrap' to create a secret first",
+                call.head,
+            )),
+            _ => Err(LabeledError::new("Invalid input")
+                .with_label("Expected a single secret value", call.head)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn credentials() -> Record {
+        let span = Span::test_data();
+        let mut record = Record::new();
+        record.push("host", Value::string("db.local", span));
+        record.push("user", Value::string("admin", span));
+        record.push("password", Value::string("hunter2", span));
+        record
+    }
+
+    fn as_secret_record(value: &Value) -> &SecretRecord {
+        value
+            .as_custom_value()
+            .unwrap()
+            .as_any()
+            .downcast_ref::<SecretRecord>()
+            .unwrap()
+    }
+
+    fn columns(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn test_command_name() {
+        let command = SecretSelectCommand;
+        assert_eq!(command.name(), "secret select");
+    }
+
+    #[test]
+    fn test_signature() {
+        let command = SecretSelectCommand;
+        let signature = command.signature();
+        assert_eq!(signature.name, "secret select");
+        assert!(signature.rest_positional.is_some());
+    }
+
+    #[test]
+    fn test_select_subset_keeps_template() {
+        let span = Span::test_data();
+        let secret = SecretRecord::new_with_template(credentials(), "[HIDDEN]".to_string());
+
+        let result = select_secret_columns(&secret, &columns(&["password", "user"]), span).unwrap();
+        let selected = as_secret_record(&result);
+
+        assert!(selected.has_custom_template());
+        assert_eq!(
+            selected.fields().collect::<Vec<_>>(),
+            vec!["password", "user"]
+        );
+        assert_eq!(
+            selected.get_field("password").unwrap().as_str().unwrap(),
+            "hunter2"
+        );
+    }
+
+    #[test]
+    fn test_select_all() {
+        let span = Span::test_data();
+        let secret = SecretRecord::new(credentials());
+
+        let result =
+            select_secret_columns(&secret, &columns(&["host", "user", "password"]), span).unwrap();
+        let selected = as_secret_record(&result).reveal().clone();
+        assert_eq!(
+            Value::record(selected, span),
+            Value::record(credentials(), span)
+        );
+    }
+
+    #[test]
+    fn test_select_unknown_column() {
+        let span = Span::test_data();
+        let secret = SecretRecord::new(credentials());
+
+        let err = select_secret_columns(&secret, &columns(&["user", "token"]), span).unwrap_err();
+        assert_eq!(err.msg, "Field not found");
+        let message = format!("{:?}", err);
+        assert!(message.contains("host, user, password"));
+        assert!(!message.contains("hunter2"));
+    }
+}
@@ -0,0 +1,232 @@
+//! Implements `secret slice` — extracts a character or byte range of a secret
+//! into a new secret of the same type.
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, Signature, Span, SyntaxShape, Type, Value,
+};
+
+use crate::{SecretBinary, SecretString};
+
+#[derive(Clone)]
+pub struct SecretSliceCommand;
+
+/// Resolve a possibly negative index against `len`, clamping it into `0..=len`.
+///
+/// Negative indices count from the end, so `-1` is the position of the last element.
+fn resolve_index(index: i64, len: usize) -> usize {
+    if index < 0 {
+        len.saturating_sub(index.unsigned_abs() as usize)
+    } else {
+        (index as usize).min(len)
+    }
+}
+
+/// Resolve `start..end` against `len`; an inverted range becomes empty.
+fn resolve_range(start: i64, end: i64, len: usize) -> std::ops::Range<usize> {
+    let start = resolve_index(start, len);
+    let end = resolve_index(end, len).max(start);
+    start..end
+}
+
+/// Slice a secret custom value, producing a new secret of the same type.
+///
+/// `SecretString` is sliced by characters and `SecretBinary` by bytes. The end
+/// is exclusive, out-of-range bounds are clamped, and only the selected region
+/// is copied into the result. The result keeps the input's template.
+fn slice_secret(
+    val: &dyn nu_protocol::CustomValue,
+    start: i64,
+    end: i64,
+    span: Span,
+) -> Result<Value, LabeledError> {
+    if let Some(secret_string) = val.as_any().downcast_ref::<SecretString>() {
+        let text = secret_string.reveal();
+        let range = resolve_range(start, end, text.chars().count());
+        let sliced: String = text
+            .chars()
+            .skip(range.start)
+            .take(range.end - range.start)
+            .collect();
+        Ok(Value::custom(
+            Box::new(secret_string.with_value(sliced)),
+            span,
+        ))
+    } else if let Some(secret_binary) = val.as_any().downcast_ref::<SecretBinary>() {
+        let bytes = secret_binary.reveal();
+        let range = resolve_range(start, end, bytes.len());
+        Ok(Value::custom(
+            Box::new(secret_binary.with_value(bytes[range].to_vec())),
+            span,
+        ))
+    } else {
+        Err(LabeledError::new("Unsupported secret type")
+            .with_label("Only SecretString and SecretBinary support slicing", span))
+    }
+}
+
+impl PluginCommand for SecretSliceCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret slice"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![
+                (
+                    Type::Custom("secret_string".into()),
+                    Type::Custom("secret_string".into()),
+                ),
+                (
+                    Type::Custom("secret_binary".into()),
+                    Type::Custom("secret_binary".into()),
+                ),
+            ])
+            .required(
+                "start",
+                SyntaxShape::Int,
+                "Start index (inclusive); negative counts from the end",
+            )
+            .required(
+                "end",
+                SyntaxShape::Int,
+                "End index (exclusive); negative counts from the end",
+            )
+            .category(Category::Strings)
+    }
+
+    fn description(&self) -> &str {
+        "Extract a character range of a secret string or a byte range of a secret binary as a new secret"
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                example: r#""sk_live_abc123" | secret wrap | secret slice 0 8"#,
+                description: "Keep the key prefix as a secret string",
+                result: None,
+            },
+            Example {
+                example: r#""sk_live_abc123" | secret wrap | secret slice -6 100"#,
+                description: "Keep the last six characters, clamping the end",
+                result: None,
+            },
+            Example {
+                example: r#"0x[00 01 02 03] | secret wrap | secret slice 1 3"#,
+                description: "Extract two bytes of a secret binary",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let start: i64 = call.req(0)?;
+        let end: i64 = call.req(1)?;
+
+        match input {
+            PipelineData::Value(Value::Custom { val, .. }, metadata) => {
+                let result = slice_secret(val.as_ref(), start, end, call.head)?;
+                Ok(PipelineData::Value(result, metadata))
+            }
+            PipelineData::Value(_, _) => Err(LabeledError::new("Invalid input").with_label(
+                "Input must be a secret string or secret binary. Use 'secret wrap' to create a secret first",
+                call.head,
+            )),
+            _ => Err(LabeledError::new("Invalid input")
+                .with_label("Expected a single secret value", call.head)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SecretInt;
+
+    fn sliced_text(secret: &str, start: i64, end: i64) -> String {
+        let secret = SecretString::new(secret.to_string());
+        let result = slice_secret(&secret, start, end, Span::test_data()).unwrap();
+        result
+            .as_custom_value()
+            .unwrap()
+            .as_any()
+            .downcast_ref::<SecretString>()
+            .unwrap()
+            .reveal()
+            .to_string()
+    }
+
+    fn sliced_bytes(secret: &[u8], start: i64, end: i64) -> Vec<u8> {
+        let secret = SecretBinary::new(secret.to_vec());
+        let result = slice_secret(&secret, start, end, Span::test_data()).unwrap();
+        result
+            .as_custom_value()
+            .unwrap()
+            .as_any()
+            .downcast_ref::<SecretBinary>()
+            .unwrap()
+            .reveal()
+            .into_owned()
+    }
+
+    #[test]
+    fn test_command_name() {
+        let command = SecretSliceCommand;
+        assert_eq!(command.name(), "secret slice");
+    }
+
+    #[test]
+    fn test_signature() {
+        let command = SecretSliceCommand;
+        let signature = command.signature();
+        assert_eq!(signature.name, "secret slice");
+        assert_eq!(signature.required_positional.len(), 2);
+    }
+
+    #[test]
+    fn test_normal_ranges() {
+        assert_eq!(sliced_text("sk_live_abc123", 0, 8), "sk_live_");
+        assert_eq!(sliced_text("sk_live_abc123", 8, 14), "abc123");
+        assert_eq!(sliced_bytes(&[0, 1, 2, 3], 1, 3), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_negative_indices_and_clamping() {
+        assert_eq!(sliced_text("sk_live_abc123", -6, 100), "abc123");
+        assert_eq!(sliced_text("sk_live_abc123", 0, -6), "sk_live_");
+        assert_eq!(sliced_text("abc", -100, 2), "ab");
+        assert_eq!(sliced_bytes(&[0, 1, 2, 3], -2, 4), vec![2, 3]);
+    }
+
+    #[test]
+    fn test_empty_results() {
+        assert_eq!(sliced_text("abc", 2, 1), "");
+        assert_eq!(sliced_text("abc", 5, 10), "");
+        assert_eq!(sliced_text("", 0, 3), "");
+        assert!(sliced_bytes(&[0, 1], 1, 1).is_empty());
+    }
+
+    #[test]
+    fn test_unicode_char_boundaries() {
+        assert_eq!(sliced_text("café 世界🌍", 3, 7), "é 世界");
+        assert_eq!(sliced_text("café 世界🌍", -1, 8), "🌍");
+    }
+
+    #[test]
+    fn test_result_redacts_and_rejects_other_types() {
+        let span = Span::test_data();
+        let secret = SecretString::new("sk_live_abc123".to_string());
+        let result = slice_secret(&secret, 8, 14, span).unwrap();
+        assert!(!format!("{:?}", result).contains("abc123"));
+
+        assert!(slice_secret(&SecretInt::new(1), 0, 1, span).is_err());
+    }
+}
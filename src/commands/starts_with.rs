@@ -0,0 +1,143 @@
+//! Implements `secret starts-with` — checks a secret string's prefix without revealing it.
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, Signature, SyntaxShape, Type, Value,
+};
+
+use crate::SecretString;
+
+#[derive(Clone)]
+pub struct SecretStartsWithCommand;
+
+/// Check whether a secret custom value starts with `prefix`, returning a boolean result.
+///
+/// Returns a type mismatch error for secrets that are not a `SecretString`.
+fn secret_starts_with(
+    val: &dyn nu_protocol::CustomValue,
+    prefix: &str,
+    span: nu_protocol::Span,
+) -> Result<Value, LabeledError> {
+    if let Some(secret_string) = val.as_any().downcast_ref::<SecretString>() {
+        Ok(Value::bool(
+            secret_string.reveal().starts_with(prefix),
+            span,
+        ))
+    } else {
+        Err(LabeledError::new("Type mismatch").with_label(
+            format!("Expected secret_string, found {}", val.type_name()),
+            span,
+        ))
+    }
+}
+
+impl PluginCommand for SecretStartsWithCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret starts-with"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Custom("secret_string".into()), Type::Bool)])
+            .required("prefix", SyntaxShape::String, "Prefix to check for")
+            .category(Category::Strings)
+    }
+
+    fn description(&self) -> &str {
+        "Check if a secret string starts with the given prefix without revealing it"
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![Example {
+            example: r#""sk-live-abc123" | secret wrap | secret starts-with "sk-""#,
+            description: "Check whether a secret token has the expected prefix",
+            result: Some(Value::test_bool(true)),
+        }]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let prefix: String = call.req(0)?;
+
+        match input {
+            PipelineData::Value(value, metadata) => {
+                let result = match value {
+                    Value::Custom { val, .. } => {
+                        secret_starts_with(val.as_ref(), &prefix, call.head)?
+                    }
+                    _ => {
+                        return Err(LabeledError::new("Invalid input").with_label(
+                            "Input must be a secret string. Use 'secret wrap' to create a secret first",
+                            call.head,
+                        ));
+                    }
+                };
+
+                Ok(PipelineData::Value(result, metadata))
+            }
+            _ => Err(LabeledError::new("Invalid input")
+                .with_label("Expected a single secret value", call.head)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SecretInt;
+    use nu_protocol::Span;
+
+    fn check(secret: &str, prefix: &str) -> bool {
+        let secret = SecretString::new(secret.to_string());
+        secret_starts_with(&secret, prefix, Span::test_data())
+            .unwrap()
+            .as_bool()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_command_name() {
+        let command = SecretStartsWithCommand;
+        assert_eq!(command.name(), "secret starts-with");
+    }
+
+    #[test]
+    fn test_signature() {
+        let command = SecretStartsWithCommand;
+        let signature = command.signature();
+        assert_eq!(signature.name, "secret starts-with");
+        assert_eq!(signature.required_positional.len(), 1);
+    }
+
+    #[test]
+    fn test_starts_with() {
+        assert!(check("sk-live-abc123", "sk-"));
+        assert!(!check("sk-live-abc123", "pk-"));
+    }
+
+    #[test]
+    fn test_starts_with_unicode() {
+        assert!(check("🔑é-token", "🔑é"));
+        assert!(!check("🔑é-token", "🔒"));
+    }
+
+    #[test]
+    fn test_starts_with_empty_prefix() {
+        assert!(check("anything", ""));
+        assert!(check("", ""));
+        assert!(!check("", "x"));
+    }
+
+    #[test]
+    fn test_starts_with_rejects_non_string_secret() {
+        let err = secret_starts_with(&SecretInt::new(42), "4", Span::test_data()).unwrap_err();
+        assert_eq!(err.msg, "Type mismatch");
+    }
+}
@@ -0,0 +1,231 @@
+//! Implements `secret string capitalize` and `secret string title-case` —
+//! change the case of a secret string without revealing it.
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{Category, Example, LabeledError, PipelineData, Signature, Span, Type, Value};
+
+use crate::SecretString;
+
+#[derive(Clone)]
+pub struct SecretStringCapitalizeCommand;
+
+#[derive(Clone)]
+pub struct SecretStringTitleCaseCommand;
+
+/// Upper-case the first character of `content`, leaving the rest unchanged.
+fn capitalize(content: &str) -> String {
+    let mut chars = content.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Upper-case the first character of every whitespace-separated word and
+/// lower-case the remaining characters. Whitespace is preserved as-is.
+fn title_case(content: &str) -> String {
+    let mut result = String::with_capacity(content.len());
+    let mut at_word_start = true;
+
+    for c in content.chars() {
+        if c.is_whitespace() {
+            result.push(c);
+            at_word_start = true;
+        } else if at_word_start {
+            result.extend(c.to_uppercase());
+            at_word_start = false;
+        } else {
+            result.extend(c.to_lowercase());
+        }
+    }
+
+    result
+}
+
+/// Apply `transform` to a secret custom value, producing a new `SecretString`.
+fn transform_secret_string(
+    val: &dyn nu_protocol::CustomValue,
+    transform: fn(&str) -> String,
+    span: Span,
+) -> Result<Value, LabeledError> {
+    if let Some(secret_string) = val.as_any().downcast_ref::<SecretString>() {
+        let transformed = transform(secret_string.reveal());
+        Ok(Value::custom(
+            Box::new(secret_string.with_value(transformed)),
+            span,
+        ))
+    } else {
+        Err(LabeledError::new("Unsupported secret type")
+            .with_label("Only SecretString supports case conversion", span))
+    }
+}
+
+/// Shared signature for both case commands.
+fn case_signature(name: &str) -> Signature {
+    Signature::build(name)
+        .input_output_types(vec![(
+            Type::Custom("secret_string".into()),
+            Type::Custom("secret_string".into()),
+        )])
+        .category(Category::Strings)
+}
+
+/// Shared `run` implementation for both case commands.
+fn run_case(
+    call: &EvaluatedCall,
+    input: PipelineData,
+    transform: fn(&str) -> String,
+) -> Result<PipelineData, LabeledError> {
+    match input {
+        PipelineData::Value(value, metadata) => {
+            let result = match value {
+                Value::Custom { val, .. } => {
+                    transform_secret_string(val.as_ref(), transform, call.head)?
+                }
+                _ => {
+                    return Err(LabeledError::new("Invalid input").with_label(
+                        "Input must be a secret string. Use 'secret wrap' to create a secret first",
+                        call.head,
+                    ));
+                }
+            };
+
+            Ok(PipelineData::Value(result, metadata))
+        }
+        _ => Err(LabeledError::new("Invalid input")
+            .with_label("Expected a single secret value", call.head)),
+    }
+}
+
+impl PluginCommand for SecretStringCapitalizeCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret string capitalize"
+    }
+
+    fn signature(&self) -> Signature {
+        case_signature(self.name())
+    }
+
+    fn description(&self) -> &str {
+        "Capitalize the first character of a secret string without revealing it"
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![Example {
+            example: r#""service-account" | secret wrap | secret string capitalize"#,
+            description: "Capitalize a secret identifier",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        run_case(call, input, capitalize)
+    }
+}
+
+impl PluginCommand for SecretStringTitleCaseCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret string title-case"
+    }
+
+    fn signature(&self) -> Signature {
+        case_signature(self.name())
+    }
+
+    fn description(&self) -> &str {
+        "Convert a secret string to title case without revealing it"
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![Example {
+            example: r#""jane DOE" | secret wrap | secret string title-case"#,
+            description: "Normalize a secret account holder name to title case",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        run_case(call, input, title_case)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SecretInt;
+
+    #[test]
+    fn test_command_names() {
+        assert_eq!(
+            SecretStringCapitalizeCommand.name(),
+            "secret string capitalize"
+        );
+        assert_eq!(
+            SecretStringTitleCaseCommand.name(),
+            "secret string title-case"
+        );
+    }
+
+    #[test]
+    fn test_signature() {
+        let signature = SecretStringTitleCaseCommand.signature();
+        assert_eq!(signature.name, "secret string title-case");
+        assert_eq!(signature.required_positional.len(), 0);
+    }
+
+    #[test]
+    fn test_capitalize() {
+        assert_eq!(capitalize("hello world"), "Hello world");
+        assert_eq!(capitalize("hELLO"), "HELLO");
+        assert_eq!(capitalize(""), "");
+        assert_eq!(capitalize("élan"), "Élan");
+        assert_eq!(capitalize("ßtraße"), "SStraße");
+    }
+
+    #[test]
+    fn test_title_case() {
+        assert_eq!(title_case("jane DOE"), "Jane Doe");
+        assert_eq!(title_case("  two  spaces "), "  Two  Spaces ");
+        assert_eq!(title_case("émile ZOLA"), "Émile Zola");
+        assert_eq!(title_case(""), "");
+    }
+
+    #[test]
+    fn test_transform_secret_string() {
+        let span = Span::test_data();
+        let secret = SecretString::new("jane doe".to_string());
+        let result = transform_secret_string(&secret, title_case, span).unwrap();
+        assert_eq!(
+            result
+                .as_custom_value()
+                .unwrap()
+                .as_any()
+                .downcast_ref::<SecretString>()
+                .unwrap()
+                .reveal(),
+            "Jane Doe"
+        );
+    }
+
+    #[test]
+    fn test_transform_rejects_non_string_secret() {
+        let secret = SecretInt::new(7);
+        assert!(transform_secret_string(&secret, capitalize, Span::test_data()).is_err());
+    }
+}
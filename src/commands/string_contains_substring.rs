@@ -0,0 +1,170 @@
+//! Implements `secret string contains-substring` — checks whether a secret string
+//! contains a fragment without revealing it.
+//!
+//! Unlike `secret contains`, this is not a constant-time comparison, and a `true`
+//! result reveals partial structure of the secret. Avoid exposing it to untrusted
+//! callers who can probe with many fragments.
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, Signature, SyntaxShape, Type, Value,
+};
+
+use crate::SecretString;
+
+#[derive(Clone)]
+pub struct SecretStringContainsSubstringCommand;
+
+/// Check whether a secret custom value contains `fragment`, returning a boolean result.
+///
+/// With `ignore_case`, both sides are lowercased before comparing. Returns a
+/// type mismatch error for secrets that are not a `SecretString`.
+fn secret_contains_substring(
+    val: &dyn nu_protocol::CustomValue,
+    fragment: &str,
+    ignore_case: bool,
+    span: nu_protocol::Span,
+) -> Result<Value, LabeledError> {
+    if let Some(secret_string) = val.as_any().downcast_ref::<SecretString>() {
+        let found = if ignore_case {
+            secret_string
+                .reveal()
+                .to_lowercase()
+                .contains(&fragment.to_lowercase())
+        } else {
+            secret_string.reveal().contains(fragment)
+        };
+        Ok(Value::bool(found, span))
+    } else {
+        Err(LabeledError::new("Type mismatch").with_label(
+            format!("Expected secret_string, found {}", val.type_name()),
+            span,
+        ))
+    }
+}
+
+impl PluginCommand for SecretStringContainsSubstringCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret string contains-substring"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Custom("secret_string".into()), Type::Bool)])
+            .required("substring", SyntaxShape::String, "Fragment to look for")
+            .switch(
+                "ignore-case",
+                "Compare without regard to letter case",
+                Some('i'),
+            )
+            .category(Category::Strings)
+    }
+
+    fn description(&self) -> &str {
+        "Check if a secret string contains a fragment without revealing it (not constant-time)"
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                example: r#""sk-live-abc123" | secret wrap | secret string contains-substring "live""#,
+                description: "Check whether a secret token contains a fragment",
+                result: Some(Value::test_bool(true)),
+            },
+            Example {
+                example: r#""sk-live-abc123" | secret wrap | secret string contains-substring --ignore-case "LIVE""#,
+                description: "Check for a fragment regardless of case",
+                result: Some(Value::test_bool(true)),
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let fragment: String = call.req(0)?;
+        let ignore_case = call.has_flag("ignore-case")?;
+
+        match input {
+            PipelineData::Value(value, metadata) => {
+                let result = match value {
+                    Value::Custom { val, .. } => {
+                        secret_contains_substring(val.as_ref(), &fragment, ignore_case, call.head)?
+                    }
+                    _ => {
+                        return Err(LabeledError::new("Invalid input").with_label(
+                            "Input must be a secret string. Use 'secret wrap' to create a secret first",
+                            call.head,
+                        ));
+                    }
+                };
+
+                Ok(PipelineData::Value(result, metadata))
+            }
+            _ => Err(LabeledError::new("Invalid input")
+                .with_label("Expected a single secret value", call.head)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SecretInt;
+    use nu_protocol::Span;
+
+    fn check(secret: &str, fragment: &str, ignore_case: bool) -> bool {
+        let secret = SecretString::new(secret.to_string());
+        secret_contains_substring(&secret, fragment, ignore_case, Span::test_data())
+            .unwrap()
+            .as_bool()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_command_name() {
+        let command = SecretStringContainsSubstringCommand;
+        assert_eq!(command.name(), "secret string contains-substring");
+    }
+
+    #[test]
+    fn test_signature() {
+        let command = SecretStringContainsSubstringCommand;
+        let signature = command.signature();
+        assert_eq!(signature.name, "secret string contains-substring");
+        assert_eq!(signature.required_positional.len(), 1);
+        assert!(signature.get_long_flag("ignore-case").is_some());
+    }
+
+    #[test]
+    fn test_fragment_present() {
+        assert!(check("sk-live-abc123", "live", false));
+        assert!(check("sk-live-abc123", "", false));
+    }
+
+    #[test]
+    fn test_fragment_absent() {
+        assert!(!check("sk-live-abc123", "test", false));
+        assert!(!check("sk-live-abc123", "LIVE", false));
+    }
+
+    #[test]
+    fn test_ignore_case() {
+        assert!(check("sk-live-abc123", "LIVE", true));
+        assert!(check("Sk-Live-ABC123", "abc", true));
+        assert!(!check("sk-live-abc123", "TEST", true));
+    }
+
+    #[test]
+    fn test_rejects_non_string_secret() {
+        let err = secret_contains_substring(&SecretInt::new(42), "4", false, Span::test_data())
+            .unwrap_err();
+        assert_eq!(err.msg, "Type mismatch");
+    }
+}
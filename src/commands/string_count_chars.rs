@@ -0,0 +1,182 @@
+//! Implements `secret string count-chars` — counts the character classes of a
+//! secret string without revealing it.
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, Record, Signature, Span, Type, Value,
+};
+
+use crate::SecretString;
+
+#[derive(Clone)]
+pub struct SecretStringCountCharsCommand;
+
+/// Per-class character counts of a string.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct CharStats {
+    length: usize,
+    upper: usize,
+    lower: usize,
+    digit: usize,
+    symbol: usize,
+    whitespace: usize,
+}
+
+/// Classify every character of `text`.
+///
+/// Each character lands in exactly one class, so the classes always sum to
+/// `length`. Anything that is not an uppercase or lowercase letter, a digit or
+/// whitespace counts as a symbol.
+fn char_stats(text: &str) -> CharStats {
+    let mut stats = CharStats::default();
+
+    for c in text.chars() {
+        stats.length += 1;
+        if c.is_uppercase() {
+            stats.upper += 1;
+        } else if c.is_lowercase() {
+            stats.lower += 1;
+        } else if c.is_numeric() {
+            stats.digit += 1;
+        } else if c.is_whitespace() {
+            stats.whitespace += 1;
+        } else {
+            stats.symbol += 1;
+        }
+    }
+
+    stats
+}
+
+/// Build the `{length, upper, lower, digit, symbol, whitespace}` record.
+fn char_stats_record(stats: CharStats, span: Span) -> Value {
+    let mut record = Record::new();
+    record.push("length", Value::int(stats.length as i64, span));
+    record.push("upper", Value::int(stats.upper as i64, span));
+    record.push("lower", Value::int(stats.lower as i64, span));
+    record.push("digit", Value::int(stats.digit as i64, span));
+    record.push("symbol", Value::int(stats.symbol as i64, span));
+    record.push("whitespace", Value::int(stats.whitespace as i64, span));
+    Value::record(record, span)
+}
+
+impl PluginCommand for SecretStringCountCharsCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret string count-chars"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(
+                Type::Custom("secret_string".into()),
+                Type::Record(Box::new([])),
+            )])
+            .category(Category::Strings)
+    }
+
+    fn description(&self) -> &str {
+        "Count the uppercase, lowercase, digit, symbol and whitespace characters of a secret string"
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![Example {
+            example: r#""Hunter 2!" | secret wrap | secret string count-chars"#,
+            description: "Break a password down by character class without revealing it",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        match input {
+            PipelineData::Value(Value::Custom { val, .. }, metadata) => {
+                let secret_string =
+                    val.as_any().downcast_ref::<SecretString>().ok_or_else(|| {
+                        LabeledError::new("Unsupported secret type")
+                            .with_label("Only SecretString supports count-chars", call.head)
+                    })?;
+
+                let result = char_stats_record(char_stats(secret_string.reveal()), call.head);
+                Ok(PipelineData::Value(result, metadata))
+            }
+            PipelineData::Value(_, _) => Err(LabeledError::new("Invalid input").with_label(
+                "Input must be a secret string. Use 'secret wrap' to create a secret first",
+                call.head,
+            )),
+            _ => Err(LabeledError::new("Invalid input")
+                .with_label("Expected a single secret value", call.head)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_command_name() {
+        let command = SecretStringCountCharsCommand;
+        assert_eq!(command.name(), "secret string count-chars");
+    }
+
+    #[test]
+    fn test_signature() {
+        let command = SecretStringCountCharsCommand;
+        let signature = command.signature();
+        assert_eq!(signature.name, "secret string count-chars");
+        assert_eq!(signature.input_output_types.len(), 1);
+    }
+
+    #[test]
+    fn test_mixed_class_password() {
+        assert_eq!(
+            char_stats("Tr0ub4dor &3!"),
+            CharStats {
+                length: 13,
+                upper: 1,
+                lower: 6,
+                digit: 3,
+                symbol: 2,
+                whitespace: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_all_digit_password() {
+        let stats = char_stats("12345678");
+        assert_eq!(
+            stats,
+            CharStats {
+                length: 8,
+                digit: 8,
+                ..CharStats::default()
+            }
+        );
+
+        let record = char_stats_record(stats, Span::test_data());
+        let record = record.as_record().unwrap();
+        assert_eq!(
+            record.columns().collect::<Vec<_>>(),
+            vec!["length", "upper", "lower", "digit", "symbol", "whitespace"]
+        );
+        assert_eq!(record.get("digit").unwrap().as_int().unwrap(), 8);
+        assert_eq!(record.get("upper").unwrap().as_int().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_length_counts_characters_not_bytes() {
+        let stats = char_stats("Äb€");
+        assert_eq!(stats.length, 3);
+        assert_eq!(stats.upper, 1);
+        assert_eq!(stats.lower, 1);
+        assert_eq!(stats.symbol, 1);
+    }
+}
@@ -0,0 +1,140 @@
+//! Implements `secret string index-of` — finds a substring in a secret string.
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, Signature, SyntaxShape, Type, Value,
+};
+
+use crate::SecretString;
+
+#[derive(Clone)]
+pub struct SecretStringIndexOfCommand;
+
+/// Return the character index of the first occurrence of `needle`, or -1 if absent.
+fn char_index_of(haystack: &str, needle: &str) -> i64 {
+    match haystack.find(needle) {
+        Some(byte_index) => haystack[..byte_index].chars().count() as i64,
+        None => -1,
+    }
+}
+
+/// Find `needle` in a secret custom value, returning only the index.
+fn index_of_secret_string(
+    val: &dyn nu_protocol::CustomValue,
+    needle: &str,
+    span: nu_protocol::Span,
+) -> Result<Value, LabeledError> {
+    if let Some(secret_string) = val.as_any().downcast_ref::<SecretString>() {
+        Ok(Value::int(
+            char_index_of(secret_string.reveal(), needle),
+            span,
+        ))
+    } else {
+        Err(LabeledError::new("Unsupported secret type")
+            .with_label("Only SecretString supports index-of", span))
+    }
+}
+
+impl PluginCommand for SecretStringIndexOfCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret string index-of"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Custom("secret_string".into()), Type::Int)])
+            .required("substring", SyntaxShape::String, "Substring to search for")
+            .category(Category::Strings)
+    }
+
+    fn description(&self) -> &str {
+        "Get the character index of a substring in a secret string (-1 if absent)"
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![Example {
+            example: r#""user:password" | secret wrap | secret string index-of ":""#,
+            description: "Find the separator in a secret credential",
+            result: Some(Value::test_int(4)),
+        }]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let needle: String = call.req(0)?;
+
+        match input {
+            PipelineData::Value(value, metadata) => {
+                let result = match value {
+                    Value::Custom { val, .. } => {
+                        index_of_secret_string(val.as_ref(), &needle, call.head)?
+                    }
+                    _ => {
+                        return Err(LabeledError::new("Invalid input").with_label(
+                            "Input must be a secret string. Use 'secret wrap' to create a secret first",
+                            call.head,
+                        ));
+                    }
+                };
+
+                Ok(PipelineData::Value(result, metadata))
+            }
+            _ => Err(LabeledError::new("Invalid input")
+                .with_label("Expected a single secret value", call.head)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SecretInt;
+    use nu_protocol::Span;
+
+    #[test]
+    fn test_command_name() {
+        let command = SecretStringIndexOfCommand;
+        assert_eq!(command.name(), "secret string index-of");
+    }
+
+    #[test]
+    fn test_signature() {
+        let command = SecretStringIndexOfCommand;
+        let signature = command.signature();
+        assert_eq!(signature.name, "secret string index-of");
+        assert_eq!(signature.required_positional.len(), 1);
+    }
+
+    #[test]
+    fn test_char_index_present_and_absent() {
+        assert_eq!(char_index_of("user:password", ":"), 4);
+        assert_eq!(char_index_of("user:password", "pass"), 5);
+        assert_eq!(char_index_of("user:password", "token"), -1);
+        assert_eq!(char_index_of("abc", ""), 0);
+    }
+
+    #[test]
+    fn test_char_index_multi_byte() {
+        // "é" and "ü" are two bytes each in UTF-8
+        assert_eq!(char_index_of("héllü:key", ":"), 5);
+        assert_eq!(char_index_of("日本語", "語"), 2);
+    }
+
+    #[test]
+    fn test_index_of_secret_string() {
+        let span = Span::test_data();
+        let secret = SecretString::new("abc=def".to_string());
+        assert_eq!(
+            index_of_secret_string(&secret, "=", span).unwrap(),
+            Value::int(3, span)
+        );
+        assert!(index_of_secret_string(&SecretInt::new(1), "=", span).is_err());
+    }
+}
@@ -0,0 +1,151 @@
+//! Implements `secret string lines` — splits a secret string into secret lines.
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{Category, Example, LabeledError, PipelineData, Signature, Type, Value};
+
+use crate::{SecretList, SecretString};
+
+#[derive(Clone)]
+pub struct SecretStringLinesCommand;
+
+/// Split a secret custom value into a `SecretList` of `SecretString` lines.
+///
+/// Lines are split on `\n` and a trailing `\r` is stripped from each line, so
+/// `\r\n` input behaves like `\n`. A single trailing newline does not produce
+/// an empty final line, and an empty secret yields an empty list.
+fn split_secret_lines(
+    val: &dyn nu_protocol::CustomValue,
+    span: nu_protocol::Span,
+) -> Result<Value, LabeledError> {
+    if let Some(secret_string) = val.as_any().downcast_ref::<SecretString>() {
+        let lines = secret_string
+            .reveal()
+            .lines()
+            .map(|line| Value::custom(Box::new(secret_string.with_value(line.to_string())), span))
+            .collect();
+        Ok(Value::custom(Box::new(SecretList::new(lines)), span))
+    } else {
+        Err(LabeledError::new("Unsupported secret type")
+            .with_label("Only SecretString can be split into lines", span))
+    }
+}
+
+impl PluginCommand for SecretStringLinesCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret string lines"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(
+                Type::Custom("secret_string".into()),
+                Type::Custom("secret_list".into()),
+            )])
+            .category(Category::Strings)
+    }
+
+    fn description(&self) -> &str {
+        "Split a secret string on \\n or \\r\\n into a secret list of secret lines"
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![Example {
+            example: r#"open key.pem | secret wrap | secret string lines"#,
+            description: "Split a PEM key into secret lines",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        match input {
+            PipelineData::Value(value, metadata) => {
+                let result = match value {
+                    Value::Custom { val, .. } => split_secret_lines(val.as_ref(), call.head)?,
+                    _ => {
+                        return Err(LabeledError::new("Invalid input").with_label(
+                            "Input must be a secret string. Use 'secret wrap' to create a secret first",
+                            call.head,
+                        ));
+                    }
+                };
+
+                Ok(PipelineData::Value(result, metadata))
+            }
+            _ => Err(LabeledError::new("Invalid input")
+                .with_label("Expected a single secret value", call.head)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nu_protocol::Span;
+
+    fn revealed_lines(value: &Value) -> Vec<String> {
+        let custom = value.as_custom_value().unwrap();
+        let list = custom
+            .as_any()
+            .downcast_ref::<SecretList>()
+            .expect("Expected SecretList");
+        list.reveal()
+            .iter()
+            .map(|element| {
+                element
+                    .as_custom_value()
+                    .unwrap()
+                    .as_any()
+                    .downcast_ref::<SecretString>()
+                    .expect("Expected SecretString element")
+                    .reveal()
+                    .to_string()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_command_name() {
+        let command = SecretStringLinesCommand;
+        assert_eq!(command.name(), "secret string lines");
+    }
+
+    #[test]
+    fn test_signature() {
+        let command = SecretStringLinesCommand;
+        let signature = command.signature();
+        assert_eq!(signature.name, "secret string lines");
+        assert_eq!(signature.input_output_types.len(), 1);
+    }
+
+    #[test]
+    fn test_split_three_lines() {
+        let secret = SecretString::new("-----BEGIN-----\r\nabc\n-----END-----\n".to_string());
+        let result = split_secret_lines(&secret, Span::test_data()).unwrap();
+        assert_eq!(
+            revealed_lines(&result),
+            vec!["-----BEGIN-----", "abc", "-----END-----"]
+        );
+    }
+
+    #[test]
+    fn test_split_single_line() {
+        let secret = SecretString::new("token".to_string());
+        let result = split_secret_lines(&secret, Span::test_data()).unwrap();
+        assert_eq!(revealed_lines(&result), vec!["token"]);
+    }
+
+    #[test]
+    fn test_split_empty_string() {
+        let secret = SecretString::new(String::new());
+        let result = split_secret_lines(&secret, Span::test_data()).unwrap();
+        assert!(revealed_lines(&result).is_empty());
+    }
+}
@@ -0,0 +1,174 @@
+//! Implements `secret string matches` — tests a secret string against a regex
+//! without revealing it.
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, Signature, Span, SyntaxShape, Type, Value,
+};
+use regex::Regex;
+
+use crate::SecretString;
+
+#[derive(Clone)]
+pub struct SecretStringMatchesCommand;
+
+/// Compile `pattern`, anchoring it to the whole string when `full` is set.
+///
+/// The error only describes the pattern problem and never includes secret content.
+fn compile_pattern(pattern: &str, full: bool, span: Span) -> Result<Regex, LabeledError> {
+    let pattern = if full {
+        format!(r"\A(?:{})\z", pattern)
+    } else {
+        pattern.to_string()
+    };
+
+    Regex::new(&pattern)
+        .map_err(|e| LabeledError::new("Invalid regex pattern").with_label(e.to_string(), span))
+}
+
+/// Check whether a secret custom value matches `regex`, returning a boolean result.
+///
+/// Returns a type mismatch error for secrets that are not a `SecretString`.
+fn secret_matches(
+    val: &dyn nu_protocol::CustomValue,
+    regex: &Regex,
+    span: Span,
+) -> Result<Value, LabeledError> {
+    if let Some(secret_string) = val.as_any().downcast_ref::<SecretString>() {
+        Ok(Value::bool(regex.is_match(secret_string.reveal()), span))
+    } else {
+        Err(LabeledError::new("Type mismatch").with_label(
+            format!("Expected secret_string, found {}", val.type_name()),
+            span,
+        ))
+    }
+}
+
+impl PluginCommand for SecretStringMatchesCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret string matches"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Custom("secret_string".into()), Type::Bool)])
+            .required("pattern", SyntaxShape::String, "Regex pattern to match")
+            .switch(
+                "full",
+                "Require the pattern to match the entire string",
+                Some('f'),
+            )
+            .category(Category::Strings)
+    }
+
+    fn description(&self) -> &str {
+        "Check if a secret string matches a regex pattern without revealing it"
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                example: r#""sk-live-abc123" | secret wrap | secret string matches 'live'"#,
+                description: "Check whether a secret contains a match for the pattern",
+                result: Some(Value::test_bool(true)),
+            },
+            Example {
+                example: r#""sk-live-abc123" | secret wrap | secret string matches --full 'live'"#,
+                description: "Require the pattern to match the whole secret",
+                result: Some(Value::test_bool(false)),
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let pattern: String = call.req(0)?;
+        let full = call.has_flag("full")?;
+        let regex = compile_pattern(&pattern, full, call.head)?;
+
+        match input {
+            PipelineData::Value(value, metadata) => {
+                let result = match value {
+                    Value::Custom { val, .. } => secret_matches(val.as_ref(), &regex, call.head)?,
+                    _ => {
+                        return Err(LabeledError::new("Invalid input").with_label(
+                            "Input must be a secret string. Use 'secret wrap' to create a secret first",
+                            call.head,
+                        ));
+                    }
+                };
+
+                Ok(PipelineData::Value(result, metadata))
+            }
+            _ => Err(LabeledError::new("Invalid input")
+                .with_label("Expected a single secret value", call.head)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SecretInt;
+
+    fn check(secret: &str, pattern: &str, full: bool) -> bool {
+        let span = Span::test_data();
+        let secret = SecretString::new(secret.to_string());
+        let regex = compile_pattern(pattern, full, span).unwrap();
+        secret_matches(&secret, &regex, span)
+            .unwrap()
+            .as_bool()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_command_name() {
+        let command = SecretStringMatchesCommand;
+        assert_eq!(command.name(), "secret string matches");
+    }
+
+    #[test]
+    fn test_signature() {
+        let command = SecretStringMatchesCommand;
+        let signature = command.signature();
+        assert_eq!(signature.name, "secret string matches");
+        assert_eq!(signature.required_positional.len(), 1);
+        assert!(signature.get_long_flag("full").is_some());
+    }
+
+    #[test]
+    fn test_partial_match() {
+        assert!(check("sk-live-abc123", "live", false));
+        assert!(check("sk-live-abc123", r"\d+$", false));
+        assert!(!check("sk-live-abc123", "test", false));
+    }
+
+    #[test]
+    fn test_full_match() {
+        assert!(!check("sk-live-abc123", "live", true));
+        assert!(check("sk-live-abc123", r"sk-\w+-[a-z0-9]+", true));
+        // Alternation must not escape the anchors
+        assert!(!check("sk-live-abc123", "sk|abc123", true));
+    }
+
+    #[test]
+    fn test_invalid_pattern() {
+        let err = compile_pattern("(unclosed", false, Span::test_data()).unwrap_err();
+        assert_eq!(err.msg, "Invalid regex pattern");
+    }
+
+    #[test]
+    fn test_rejects_non_string_secret() {
+        let span = Span::test_data();
+        let regex = compile_pattern("4", false, span).unwrap();
+        let err = secret_matches(&SecretInt::new(42), &regex, span).unwrap_err();
+        assert_eq!(err.msg, "Type mismatch");
+    }
+}
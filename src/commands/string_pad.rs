@@ -0,0 +1,262 @@
+//! Implements `secret string pad-left` and `secret string pad-right` — pad a
+//! secret string to a fixed width without revealing it.
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, Signature, Span, SyntaxShape, Type, Value,
+};
+
+use crate::SecretString;
+
+#[derive(Clone)]
+pub struct SecretStringPadLeftCommand;
+
+#[derive(Clone)]
+pub struct SecretStringPadRightCommand;
+
+/// Which side of the content the fill characters are added to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum PadSide {
+    Left,
+    Right,
+}
+
+/// Pad `content` with `fill` up to `width` characters.
+///
+/// Content that is already `width` characters or longer is returned unchanged.
+fn pad(content: &str, width: usize, fill: char, side: PadSide) -> String {
+    let len = content.chars().count();
+    if len >= width {
+        return content.to_string();
+    }
+
+    let padding = fill.to_string().repeat(width - len);
+    match side {
+        PadSide::Left => format!("{}{}", padding, content),
+        PadSide::Right => format!("{}{}", content, padding),
+    }
+}
+
+/// Read the padding width and fill character from the call.
+fn parse_pad_args(call: &EvaluatedCall) -> Result<(usize, char), LabeledError> {
+    let width: i64 = call.req(0)?;
+    let width = usize::try_from(width).map_err(|_| {
+        LabeledError::new("Invalid width").with_label("Width must not be negative", call.head)
+    })?;
+
+    let fill = match call.get_flag::<String>("char")? {
+        Some(fill) => {
+            let mut chars = fill.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => c,
+                _ => {
+                    return Err(LabeledError::new("Invalid fill character")
+                        .with_label("--char must be exactly one character", call.head))
+                }
+            }
+        }
+        None => ' ',
+    };
+
+    Ok((width, fill))
+}
+
+/// Pad a secret custom value, producing a new `SecretString`.
+fn pad_secret_string(
+    val: &dyn nu_protocol::CustomValue,
+    width: usize,
+    fill: char,
+    side: PadSide,
+    span: Span,
+) -> Result<Value, LabeledError> {
+    if let Some(secret_string) = val.as_any().downcast_ref::<SecretString>() {
+        let padded = pad(secret_string.reveal(), width, fill, side);
+        Ok(Value::custom(
+            Box::new(secret_string.with_value(padded)),
+            span,
+        ))
+    } else {
+        Err(LabeledError::new("Unsupported secret type")
+            .with_label("Only SecretString supports padding", span))
+    }
+}
+
+/// Shared signature for both padding commands.
+fn pad_signature(name: &str) -> Signature {
+    Signature::build(name)
+        .input_output_types(vec![(
+            Type::Custom("secret_string".into()),
+            Type::Custom("secret_string".into()),
+        )])
+        .required(
+            "width",
+            SyntaxShape::Int,
+            "Minimum width of the resulting string in characters",
+        )
+        .named(
+            "char",
+            SyntaxShape::String,
+            "Character to pad with (default: space)",
+            Some('c'),
+        )
+        .category(Category::Strings)
+}
+
+/// Shared `run` implementation for both padding commands.
+fn run_pad(
+    call: &EvaluatedCall,
+    input: PipelineData,
+    side: PadSide,
+) -> Result<PipelineData, LabeledError> {
+    let (width, fill) = parse_pad_args(call)?;
+
+    match input {
+        PipelineData::Value(value, metadata) => {
+            let result = match value {
+                Value::Custom { val, .. } => {
+                    pad_secret_string(val.as_ref(), width, fill, side, call.head)?
+                }
+                _ => {
+                    return Err(LabeledError::new("Invalid input").with_label(
+                        "Input must be a secret string. Use 'secret wrap' to create a secret first",
+                        call.head,
+                    ));
+                }
+            };
+
+            Ok(PipelineData::Value(result, metadata))
+        }
+        _ => Err(LabeledError::new("Invalid input")
+            .with_label("Expected a single secret value", call.head)),
+    }
+}
+
+impl PluginCommand for SecretStringPadLeftCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret string pad-left"
+    }
+
+    fn signature(&self) -> Signature {
+        pad_signature(self.name())
+    }
+
+    fn description(&self) -> &str {
+        "Pad a secret string on the left to a fixed width without revealing it"
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![Example {
+            example: r#""4711" | secret wrap | secret string pad-left 8 --char '0'"#,
+            description: "Zero-pad a secret account number to eight digits",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        run_pad(call, input, PadSide::Left)
+    }
+}
+
+impl PluginCommand for SecretStringPadRightCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret string pad-right"
+    }
+
+    fn signature(&self) -> Signature {
+        pad_signature(self.name())
+    }
+
+    fn description(&self) -> &str {
+        "Pad a secret string on the right to a fixed width without revealing it"
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![Example {
+            example: r#""abc" | secret wrap | secret string pad-right 6 --char '*'"#,
+            description: "Pad a secret string on the right with asterisks",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        run_pad(call, input, PadSide::Right)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SecretInt;
+
+    #[test]
+    fn test_command_names() {
+        assert_eq!(SecretStringPadLeftCommand.name(), "secret string pad-left");
+        assert_eq!(
+            SecretStringPadRightCommand.name(),
+            "secret string pad-right"
+        );
+    }
+
+    #[test]
+    fn test_signature() {
+        let signature = SecretStringPadLeftCommand.signature();
+        assert_eq!(signature.name, "secret string pad-left");
+        assert_eq!(signature.required_positional.len(), 1);
+        assert!(signature.get_long_flag("char").is_some());
+    }
+
+    #[test]
+    fn test_pad_short_string() {
+        assert_eq!(pad("42", 5, '0', PadSide::Left), "00042");
+        assert_eq!(pad("42", 5, '*', PadSide::Right), "42***");
+    }
+
+    #[test]
+    fn test_pad_noop_when_long_enough() {
+        assert_eq!(pad("123456", 4, '0', PadSide::Left), "123456");
+        assert_eq!(pad("1234", 4, '0', PadSide::Right), "1234");
+    }
+
+    #[test]
+    fn test_pad_counts_characters_not_bytes() {
+        assert_eq!(pad("é", 3, '-', PadSide::Left), "--é");
+    }
+
+    #[test]
+    fn test_pad_secret_string() {
+        let span = Span::test_data();
+        let secret = SecretString::new("7".to_string());
+        let result = pad_secret_string(&secret, 3, '0', PadSide::Left, span).unwrap();
+        let padded = result.as_custom_value().unwrap();
+        assert_eq!(
+            padded
+                .as_any()
+                .downcast_ref::<SecretString>()
+                .unwrap()
+                .reveal(),
+            "007"
+        );
+    }
+
+    #[test]
+    fn test_pad_rejects_non_string_secret() {
+        let secret = SecretInt::new(7);
+        assert!(pad_secret_string(&secret, 3, '0', PadSide::Left, Span::test_data()).is_err());
+    }
+}
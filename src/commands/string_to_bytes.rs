@@ -0,0 +1,142 @@
+//! Implements `secret string to-bytes` — converts a secret string into a secret
+//! binary of its UTF-8 bytes.
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{Category, Example, LabeledError, PipelineData, Signature, Type, Value};
+
+use crate::{SecretBinary, SecretString};
+
+#[derive(Clone)]
+pub struct SecretStringToBytesCommand;
+
+/// Convert a secret custom value to a `SecretBinary` of its UTF-8 bytes.
+fn secret_string_to_bytes(
+    val: &dyn nu_protocol::CustomValue,
+    span: nu_protocol::Span,
+) -> Result<Value, LabeledError> {
+    if let Some(secret_string) = val.as_any().downcast_ref::<SecretString>() {
+        let bytes = secret_string.reveal().as_bytes().to_vec();
+        Ok(Value::custom(Box::new(SecretBinary::new(bytes)), span))
+    } else {
+        Err(LabeledError::new("Type mismatch").with_label(
+            format!("Expected secret_string, found {}", val.type_name()),
+            span,
+        ))
+    }
+}
+
+impl PluginCommand for SecretStringToBytesCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret string to-bytes"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(
+                Type::Custom("secret_string".into()),
+                Type::Custom("secret_binary".into()),
+            )])
+            .category(Category::Strings)
+    }
+
+    fn description(&self) -> &str {
+        "Convert a secret string to a secret binary of its UTF-8 bytes without revealing it"
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![Example {
+            example: r#""hunter2" | secret wrap | secret string to-bytes"#,
+            description: "Get the UTF-8 bytes of a secret string as a secret binary",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        match input {
+            PipelineData::Value(value, metadata) => {
+                let result = match value {
+                    Value::Custom { val, .. } => secret_string_to_bytes(val.as_ref(), call.head)?,
+                    _ => {
+                        return Err(LabeledError::new("Invalid input").with_label(
+                            "Input must be a secret string. Use 'secret wrap' to create a secret first",
+                            call.head,
+                        ));
+                    }
+                };
+
+                Ok(PipelineData::Value(result, metadata))
+            }
+            _ => Err(LabeledError::new("Invalid input")
+                .with_label("Expected a single secret value", call.head)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::binary_to_string::secret_binary_to_string;
+    use super::*;
+    use crate::SecretInt;
+    use nu_protocol::Span;
+
+    #[test]
+    fn test_command_name() {
+        let command = SecretStringToBytesCommand;
+        assert_eq!(command.name(), "secret string to-bytes");
+    }
+
+    #[test]
+    fn test_signature() {
+        let command = SecretStringToBytesCommand;
+        let signature = command.signature();
+        assert_eq!(signature.name, "secret string to-bytes");
+        assert_eq!(signature.input_output_types.len(), 1);
+    }
+
+    #[test]
+    fn test_to_bytes() {
+        let secret = SecretString::new("héllo".to_string());
+        let result = secret_string_to_bytes(&secret, Span::test_data()).unwrap();
+        let custom = result.as_custom_value().unwrap();
+        assert_eq!(custom.type_name(), "secret_binary");
+
+        let bytes = custom
+            .as_any()
+            .downcast_ref::<SecretBinary>()
+            .unwrap()
+            .reveal()
+            .into_owned();
+        assert_eq!(bytes, "héllo".as_bytes());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let span = Span::test_data();
+        for text in ["", "hunter2", "🔑 clé secrète"] {
+            let secret = SecretString::new(text.to_string());
+            let bytes = secret_string_to_bytes(&secret, span).unwrap();
+            let back = secret_binary_to_string(bytes.as_custom_value().unwrap(), span).unwrap();
+            let back = back
+                .as_custom_value()
+                .unwrap()
+                .as_any()
+                .downcast_ref::<SecretString>()
+                .unwrap();
+            assert_eq!(back.reveal(), text);
+        }
+    }
+
+    #[test]
+    fn test_rejects_non_string_secret() {
+        let err = secret_string_to_bytes(&SecretInt::new(1), Span::test_data()).unwrap_err();
+        assert_eq!(err.msg, "Type mismatch");
+    }
+}
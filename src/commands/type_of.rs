@@ -1,16 +1,72 @@
 //! Implements `secret type-of` — returns the underlying type of a secret value.
 
 use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
-use nu_protocol::{Category, Example, LabeledError, PipelineData, Signature, Type, Value};
+use nu_protocol::{
+    Category, CustomValue, Example, LabeledError, PipelineData, Signature, Span, Type, Value,
+};
 
 use crate::{
-    SecretBinary, SecretBool, SecretDate, SecretFloat, SecretInt, SecretList, SecretRecord,
-    SecretString,
+    SecretBinary, SecretBool, SecretCellPath, SecretDate, SecretDuration, SecretFilesize,
+    SecretFloat, SecretInt, SecretList, SecretRange, SecretRecord, SecretString,
 };
 
 #[derive(Clone)]
 pub struct SecretTypeOfCommand;
 
+/// Friendly short name of the type a secret wraps, e.g. `string` for `SecretString`.
+fn short_type_name(val: &dyn CustomValue) -> &'static str {
+    if val.as_any().downcast_ref::<SecretString>().is_some() {
+        "string"
+    } else if val.as_any().downcast_ref::<SecretInt>().is_some() {
+        "int"
+    } else if val.as_any().downcast_ref::<SecretBool>().is_some() {
+        "bool"
+    } else if val.as_any().downcast_ref::<SecretRecord>().is_some() {
+        "record"
+    } else if val.as_any().downcast_ref::<SecretList>().is_some() {
+        "list"
+    } else if val.as_any().downcast_ref::<SecretFloat>().is_some() {
+        "float"
+    } else if val.as_any().downcast_ref::<SecretBinary>().is_some() {
+        "binary"
+    } else if val.as_any().downcast_ref::<SecretDate>().is_some() {
+        "date"
+    } else if val.as_any().downcast_ref::<SecretDuration>().is_some() {
+        "duration"
+    } else if val.as_any().downcast_ref::<SecretFilesize>().is_some() {
+        "filesize"
+    } else if val.as_any().downcast_ref::<SecretRange>().is_some() {
+        "range"
+    } else if val.as_any().downcast_ref::<SecretCellPath>().is_some() {
+        "cellpath"
+    } else {
+        "unknown"
+    }
+}
+
+/// Describe the type of a secret value.
+///
+/// Returns the short name by default, or the registered `CustomValue::type_name()`
+/// (e.g. `secret_string`) when `long` is set. Non-secret values are an error.
+fn secret_type_of(value: &Value, long: bool, span: Span) -> Result<Value, LabeledError> {
+    match value {
+        Value::Custom { val, .. } => {
+            let type_name = if long {
+                val.type_name()
+            } else {
+                short_type_name(val.as_ref()).to_string()
+            };
+            Ok(Value::string(type_name, span))
+        }
+        _ => Err(LabeledError::new("Type Error")
+            .with_label(
+                format!("Expected secret type, got {}", value.get_type()),
+                span,
+            )
+            .with_help("Only secret types have underlying types")),
+    }
+}
+
 impl PluginCommand for SecretTypeOfCommand {
     type Plugin = crate::SecretPlugin;
 
@@ -29,7 +85,16 @@ impl PluginCommand for SecretTypeOfCommand {
                 (Type::Custom("secret_float".into()), Type::String),
                 (Type::Custom("secret_binary".into()), Type::String),
                 (Type::Custom("secret_date".into()), Type::String),
+                (Type::Custom("secret_duration".into()), Type::String),
+                (Type::Custom("secret_filesize".into()), Type::String),
+                (Type::Custom("secret_range".into()), Type::String),
+                (Type::Custom("secret_cellpath".into()), Type::String),
             ])
+            .switch(
+                "long",
+                "Return the registered custom type name (e.g. secret_string) instead of the short form",
+                Some('l'),
+            )
             .category(Category::Core)
     }
 
@@ -38,11 +103,18 @@ impl PluginCommand for SecretTypeOfCommand {
     }
 
     fn examples(&self) -> Vec<Example<'_>> {
-        vec![Example {
-            example: r#""my-secret" | secret wrap | secret type-of"#,
-            description: "Get the underlying type of a secret string",
-            result: Some(Value::test_string("string")),
-        }]
+        vec![
+            Example {
+                example: r#""my-secret" | secret wrap | secret type-of"#,
+                description: "Get the underlying type of a secret string",
+                result: Some(Value::test_string("string")),
+            },
+            Example {
+                example: r#""my-secret" | secret wrap | secret type-of --long"#,
+                description: "Get the registered custom type name of a secret string",
+                result: Some(Value::test_string("secret_string")),
+            },
+        ]
     }
 
     fn run(
@@ -52,39 +124,13 @@ impl PluginCommand for SecretTypeOfCommand {
         call: &EvaluatedCall,
         input: PipelineData,
     ) -> Result<PipelineData, LabeledError> {
+        let long = call.has_flag("long")?;
+
         match input {
-            PipelineData::Value(Value::Custom { val, .. }, metadata) => {
-                let underlying_type = if val.as_any().downcast_ref::<SecretString>().is_some() {
-                    "string"
-                } else if val.as_any().downcast_ref::<SecretInt>().is_some() {
-                    "int"
-                } else if val.as_any().downcast_ref::<SecretBool>().is_some() {
-                    "bool"
-                } else if val.as_any().downcast_ref::<SecretRecord>().is_some() {
-                    "record"
-                } else if val.as_any().downcast_ref::<SecretList>().is_some() {
-                    "list"
-                } else if val.as_any().downcast_ref::<SecretFloat>().is_some() {
-                    "float"
-                } else if val.as_any().downcast_ref::<SecretBinary>().is_some() {
-                    "binary"
-                } else if val.as_any().downcast_ref::<SecretDate>().is_some() {
-                    "date"
-                } else {
-                    "unknown"
-                };
-
-                Ok(PipelineData::Value(
-                    Value::string(underlying_type, call.head),
-                    metadata,
-                ))
-            }
-            PipelineData::Value(value, _) => Err(LabeledError::new("Type Error")
-                .with_label(
-                    format!("Expected secret type, got {}", value.get_type()),
-                    call.head,
-                )
-                .with_help("Only secret types have underlying types")),
+            PipelineData::Value(value, metadata) => Ok(PipelineData::Value(
+                secret_type_of(&value, long, call.head)?,
+                metadata,
+            )),
             PipelineData::Empty => Err(LabeledError::new("Empty Input")
                 .with_label("No input provided", call.head)
                 .with_help("Provide a secret value to get its type")),
@@ -110,7 +156,44 @@ mod tests {
         let command = SecretTypeOfCommand;
         let sig = command.signature();
         assert_eq!(sig.name, "secret type-of");
-        assert_eq!(sig.input_output_types.len(), 8);
+        assert_eq!(sig.input_output_types.len(), 12);
         assert_eq!(sig.input_output_types[0].1, Type::String);
+        assert!(sig.get_long_flag("long").is_some());
+    }
+
+    #[test]
+    fn test_short_and_long_type_names() {
+        let span = Span::test_data();
+        let string = Value::custom(Box::new(SecretString::new("hunter2".to_string())), span);
+        let record = Value::custom(
+            Box::new(SecretRecord::new(nu_protocol::Record::new())),
+            span,
+        );
+
+        assert_eq!(
+            secret_type_of(&string, false, span).unwrap(),
+            Value::string("string", span)
+        );
+        assert_eq!(
+            secret_type_of(&string, true, span).unwrap(),
+            Value::string("secret_string", span)
+        );
+        assert_eq!(
+            secret_type_of(&record, false, span).unwrap(),
+            Value::string("record", span)
+        );
+        assert_eq!(
+            secret_type_of(&record, true, span).unwrap(),
+            Value::string("secret_record", span)
+        );
+    }
+
+    #[test]
+    fn test_non_secret_input_is_rejected() {
+        let span = Span::test_data();
+        for long in [false, true] {
+            let err = secret_type_of(&Value::string("plain", span), long, span).unwrap_err();
+            assert_eq!(err.msg, "Type Error");
+        }
     }
 }
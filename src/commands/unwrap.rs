@@ -1,16 +1,168 @@
 //! Implements `secret unwrap` — extracts the underlying value from a secret.
 
 use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
-use nu_protocol::{Category, Example, LabeledError, PipelineData, Signature, Type, Value};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, Signature, Span, SyntaxShape, Type, Value,
+};
 
 use crate::{
-    SecretBinary, SecretBool, SecretDate, SecretFloat, SecretInt, SecretList, SecretRecord,
-    SecretString,
+    SecretBinary, SecretBool, SecretCellPath, SecretDate, SecretDuration, SecretFilesize,
+    SecretFloat, SecretInt, SecretList, SecretRange, SecretRecord, SecretString,
 };
 
 #[derive(Clone)]
 pub struct SecretUnwrapCommand;
 
+/// Reveal a secret custom value as the plain Nushell value it wraps.
+///
+/// Returns `None` if `val` is not one of the plugin's secret types.
+pub(crate) fn reveal_secret_value(
+    val: &dyn nu_protocol::CustomValue,
+    span: nu_protocol::Span,
+) -> Option<Value> {
+    if let Some(secret_string) = val.as_any().downcast_ref::<SecretString>() {
+        Some(Value::string(secret_string.reveal().to_string(), span))
+    } else if let Some(secret_int) = val.as_any().downcast_ref::<SecretInt>() {
+        Some(Value::int(secret_int.reveal(), span))
+    } else if let Some(secret_bool) = val.as_any().downcast_ref::<SecretBool>() {
+        Some(Value::bool(secret_bool.reveal(), span))
+    } else if let Some(secret_record) = val.as_any().downcast_ref::<SecretRecord>() {
+        Some(Value::record(secret_record.reveal().clone(), span))
+    } else if let Some(secret_list) = val.as_any().downcast_ref::<SecretList>() {
+        Some(Value::list(secret_list.reveal().clone(), span))
+    } else if let Some(secret_float) = val.as_any().downcast_ref::<SecretFloat>() {
+        Some(Value::float(secret_float.reveal(), span))
+    } else if let Some(secret_binary) = val.as_any().downcast_ref::<SecretBinary>() {
+        Some(Value::binary(secret_binary.reveal().clone(), span))
+    } else if let Some(secret_date) = val.as_any().downcast_ref::<SecretDate>() {
+        Some(Value::date(*secret_date.reveal(), span))
+    } else if let Some(secret_duration) = val.as_any().downcast_ref::<SecretDuration>() {
+        Some(Value::duration(secret_duration.reveal(), span))
+    } else if let Some(secret_range) = val.as_any().downcast_ref::<SecretRange>() {
+        Some(Value::range(*secret_range.reveal(), span))
+    } else if let Some(secret_cell_path) = val.as_any().downcast_ref::<SecretCellPath>() {
+        Some(Value::cell_path(secret_cell_path.reveal().clone(), span))
+    } else {
+        val.as_any()
+            .downcast_ref::<SecretFilesize>()
+            .map(|secret_filesize| {
+                Value::filesize(nu_protocol::Filesize::new(secret_filesize.reveal()), span)
+            })
+    }
+}
+
+/// The redacted display form of a secret custom value as a plain string.
+///
+/// Uses the secret's own redaction path (`to_base_value`), so it honours a
+/// per-secret template, but ignores `redaction.unredacted_types` so the wrapped
+/// value is never revealed.
+fn redacted_secret_value(
+    val: &dyn nu_protocol::CustomValue,
+    span: Span,
+) -> Result<Value, LabeledError> {
+    crate::redaction::with_unredacted_types(&[], || val.to_base_value(span))
+        .map_err(LabeledError::from)
+}
+
+/// Span of the value a secret custom value was originally wrapped from.
+///
+/// Returns `None` for non-secret values and secrets created without a known
+/// origin (e.g. by conversions rather than `secret wrap`).
+pub(crate) fn secret_origin_span(val: &dyn nu_protocol::CustomValue) -> Option<Span> {
+    let any = val.as_any();
+    any.downcast_ref::<SecretString>()
+        .map(SecretString::origin_span)
+        .or_else(|| any.downcast_ref::<SecretInt>().map(SecretInt::origin_span))
+        .or_else(|| {
+            any.downcast_ref::<SecretBool>()
+                .map(SecretBool::origin_span)
+        })
+        .or_else(|| {
+            any.downcast_ref::<SecretRecord>()
+                .map(SecretRecord::origin_span)
+        })
+        .or_else(|| {
+            any.downcast_ref::<SecretList>()
+                .map(SecretList::origin_span)
+        })
+        .or_else(|| {
+            any.downcast_ref::<SecretFloat>()
+                .map(SecretFloat::origin_span)
+        })
+        .or_else(|| {
+            any.downcast_ref::<SecretBinary>()
+                .map(SecretBinary::origin_span)
+        })
+        .or_else(|| {
+            any.downcast_ref::<SecretDate>()
+                .map(SecretDate::origin_span)
+        })
+        .or_else(|| {
+            any.downcast_ref::<SecretDuration>()
+                .map(SecretDuration::origin_span)
+        })
+        .or_else(|| {
+            any.downcast_ref::<SecretRange>()
+                .map(SecretRange::origin_span)
+        })
+        .or_else(|| {
+            any.downcast_ref::<SecretCellPath>()
+                .map(SecretCellPath::origin_span)
+        })
+        .or_else(|| {
+            any.downcast_ref::<SecretFilesize>()
+                .map(SecretFilesize::origin_span)
+        })
+        .flatten()
+}
+
+/// Add a label pointing at the place the secret was wrapped from, when known.
+pub(crate) fn with_origin_label(
+    error: LabeledError,
+    val: &dyn nu_protocol::CustomValue,
+) -> LabeledError {
+    match secret_origin_span(val) {
+        Some(origin) => error.with_label("secret was wrapped from this value", origin),
+        None => error,
+    }
+}
+
+/// Underlying type names accepted by `--type-check`.
+const CHECKABLE_TYPES: &[&str] = &[
+    "string", "int", "bool", "float", "date", "duration", "filesize", "binary", "list", "record",
+    "range", "cellpath",
+];
+
+/// Ensure `val` is the secret counterpart of `expected` (e.g. `string` -> `secret_string`).
+fn check_secret_type(
+    val: &dyn nu_protocol::CustomValue,
+    expected: &str,
+    span: nu_protocol::Span,
+) -> Result<(), LabeledError> {
+    if !CHECKABLE_TYPES.contains(&expected) {
+        return Err(LabeledError::new("Invalid type name").with_label(
+            format!(
+                "Unknown type '{}'. Valid options: {}",
+                expected,
+                CHECKABLE_TYPES.join(", ")
+            ),
+            span,
+        ));
+    }
+
+    let expected_type = format!("secret_{}", expected);
+    let actual_type = val.type_name();
+    if actual_type != expected_type {
+        let error = LabeledError::new("Type check failed").with_label(
+            format!("expected {}, found {}", expected_type, actual_type),
+            span,
+        );
+        return Err(with_origin_label(error, val));
+    }
+
+    Ok(())
+}
+
 impl PluginCommand for SecretUnwrapCommand {
     type Plugin = crate::SecretPlugin;
 
@@ -20,22 +172,53 @@ impl PluginCommand for SecretUnwrapCommand {
 
     fn signature(&self) -> Signature {
         Signature::build(self.name())
-            .input_output_types(vec![
-                (Type::Custom("secret_string".into()), Type::String),
-                (Type::Custom("secret_int".into()), Type::Int),
-                (Type::Custom("secret_bool".into()), Type::Bool),
-                (
-                    Type::Custom("secret_record".into()),
-                    Type::Record(Box::new([])),
-                ),
-                (
-                    Type::Custom("secret_list".into()),
-                    Type::List(Box::new(Type::Any)),
-                ),
-                (Type::Custom("secret_float".into()), Type::Float),
-                (Type::Custom("secret_binary".into()), Type::Binary),
-                (Type::Custom("secret_date".into()), Type::Date),
-            ])
+            .input_output_types(
+                vec![
+                    (Type::Custom("secret_string".into()), Type::String),
+                    (Type::Custom("secret_int".into()), Type::Int),
+                    (Type::Custom("secret_bool".into()), Type::Bool),
+                    (
+                        Type::Custom("secret_record".into()),
+                        Type::Record(Box::new([])),
+                    ),
+                    (
+                        Type::Custom("secret_list".into()),
+                        Type::List(Box::new(Type::Any)),
+                    ),
+                    (Type::Custom("secret_float".into()), Type::Float),
+                    (Type::Custom("secret_binary".into()), Type::Binary),
+                    (Type::Custom("secret_date".into()), Type::Date),
+                    (Type::Custom("secret_duration".into()), Type::Duration),
+                    (Type::Custom("secret_filesize".into()), Type::Filesize),
+                    (Type::Custom("secret_range".into()), Type::Range),
+                    (Type::Custom("secret_cellpath".into()), Type::CellPath),
+                ]
+                .into_iter()
+                // `--redacted` returns the display string for every secret type
+                .chain(
+                    CHECKABLE_TYPES
+                        .iter()
+                        .filter(|name| **name != "string")
+                        .map(|name| {
+                            (
+                                Type::Custom(format!("secret_{}", name).into()),
+                                Type::String,
+                            )
+                        }),
+                )
+                .collect(),
+            )
+            .named(
+                "type-check",
+                SyntaxShape::String,
+                "Fail unless the secret wraps this type (e.g. string, int, bool)",
+                Some('t'),
+            )
+            .switch(
+                "redacted",
+                "Return the redacted display string instead of revealing the secret",
+                Some('r'),
+            )
             .category(Category::Conversions)
     }
 
@@ -44,11 +227,24 @@ impl PluginCommand for SecretUnwrapCommand {
     }
 
     fn examples(&self) -> Vec<Example<'_>> {
-        vec![Example {
-            example: r#""my-secret" | secret wrap | secret unwrap"#,
-            description: "Unwrap a secret string to get the original value",
-            result: Some(Value::test_string("my-secret")),
-        }]
+        vec![
+            Example {
+                example: r#""my-secret" | secret wrap | secret unwrap"#,
+                description: "Unwrap a secret string to get the original value",
+                result: Some(Value::test_string("my-secret")),
+            },
+            Example {
+                example: r#""my-secret" | secret wrap | secret unwrap --type-check string"#,
+                description: "Unwrap only if the secret holds a string",
+                result: Some(Value::test_string("my-secret")),
+            },
+            Example {
+                example: r#""my-secret" | secret wrap | secret unwrap --redacted"#,
+                description:
+                    "Get the redacted label as a plain string without revealing the secret",
+                result: Some(Value::test_string("<redacted:string>")),
+            },
+        ]
     }
 
     fn run(
@@ -63,42 +259,20 @@ impl PluginCommand for SecretUnwrapCommand {
                 // Security warning would be logged here in a real implementation
                 // For now, we'll just proceed with the unwrap operation
 
-                if let Some(secret_string) = val.as_any().downcast_ref::<SecretString>() {
-                    let revealed = secret_string.reveal().to_string();
-                    let value = Value::string(revealed, call.head);
-                    Ok(PipelineData::Value(value, metadata))
-                } else if let Some(secret_int) = val.as_any().downcast_ref::<SecretInt>() {
-                    let revealed = secret_int.reveal();
-                    let value = Value::int(revealed, call.head);
-                    Ok(PipelineData::Value(value, metadata))
-                } else if let Some(secret_bool) = val.as_any().downcast_ref::<SecretBool>() {
-                    let revealed = secret_bool.reveal();
-                    let value = Value::bool(revealed, call.head);
-                    Ok(PipelineData::Value(value, metadata))
-                } else if let Some(secret_record) = val.as_any().downcast_ref::<SecretRecord>() {
-                    let revealed = secret_record.reveal().clone();
-                    let value = Value::record(revealed, call.head);
-                    Ok(PipelineData::Value(value, metadata))
-                } else if let Some(secret_list) = val.as_any().downcast_ref::<SecretList>() {
-                    let revealed = secret_list.reveal().clone();
-                    let value = Value::list(revealed, call.head);
-                    Ok(PipelineData::Value(value, metadata))
-                } else if let Some(secret_float) = val.as_any().downcast_ref::<SecretFloat>() {
-                    let revealed = secret_float.reveal();
-                    let value = Value::float(revealed, call.head);
-                    Ok(PipelineData::Value(value, metadata))
-                } else if let Some(secret_binary) = val.as_any().downcast_ref::<SecretBinary>() {
-                    let revealed = secret_binary.reveal().clone();
-                    let value = Value::binary(revealed, call.head);
-                    Ok(PipelineData::Value(value, metadata))
-                } else if let Some(secret_date) = val.as_any().downcast_ref::<SecretDate>() {
-                    let revealed = *secret_date.reveal();
-                    let value = Value::date(revealed, call.head);
-                    Ok(PipelineData::Value(value, metadata))
-                } else {
-                    Err(LabeledError::new("Type Error")
+                if let Some(expected) = call.get_flag::<String>("type-check")? {
+                    check_secret_type(val.as_ref(), &expected, call.head)?;
+                }
+
+                if call.has_flag("redacted")? {
+                    let redacted = redacted_secret_value(val.as_ref(), call.head)?;
+                    return Ok(PipelineData::Value(redacted, metadata));
+                }
+
+                match reveal_secret_value(val.as_ref(), call.head) {
+                    Some(value) => Ok(PipelineData::Value(value, metadata)),
+                    None => Err(LabeledError::new("Type Error")
                         .with_label("Expected a secret type", call.head)
-                        .with_help("Only secret types can be unwrapped"))
+                        .with_help("Only secret types can be unwrapped")),
                 }
             }
             PipelineData::Value(value, _) => Err(LabeledError::new("Type Error")
@@ -132,7 +306,196 @@ mod tests {
         let command = SecretUnwrapCommand;
         let sig = command.signature();
         assert_eq!(sig.name, "secret unwrap");
-        assert_eq!(sig.input_output_types.len(), 8);
+        assert_eq!(sig.input_output_types.len(), 23);
         assert_eq!(sig.input_output_types[0].1, Type::String);
+        // Every secret type also declares the string `--redacted` returns
+        for name in CHECKABLE_TYPES {
+            let input = Type::Custom(format!("secret_{}", name).into());
+            assert!(sig.input_output_types.contains(&(input, Type::String)));
+        }
+        assert!(sig
+            .input_output_types
+            .contains(&(Type::Custom("secret_int".into()), Type::Int)));
+        assert!(sig.get_long_flag("type-check").is_some());
+        assert!(sig.get_long_flag("redacted").is_some());
+    }
+
+    #[test]
+    fn test_redacted_returns_display_string() {
+        let span = Span::test_data();
+        let secrets: Vec<(Box<dyn nu_protocol::CustomValue>, &str, &str)> = vec![
+            (
+                Box::new(SecretString::new("hunter2".to_string())),
+                "<redacted:string>",
+                "hunter2",
+            ),
+            (Box::new(SecretInt::new(4242)), "<redacted:int>", "4242"),
+            (Box::new(SecretBool::new(true)), "<redacted:bool>", "true"),
+            (
+                Box::new(SecretBinary::new(vec![0xde, 0xad])),
+                "<redacted:binary>",
+                "dead",
+            ),
+            (
+                Box::new(SecretList::new(vec![Value::test_string("token-a")])),
+                "<redacted:list>",
+                "token-a",
+            ),
+        ];
+
+        for (secret, expected, plaintext) in secrets {
+            let redacted = redacted_secret_value(secret.as_ref(), span).unwrap();
+            let text = redacted.as_str().unwrap();
+            assert_eq!(text, expected);
+            assert!(!text.contains(plaintext));
+        }
+    }
+
+    #[test]
+    fn test_redacted_ignores_unredacted_types() {
+        let span = Span::test_data();
+        let secret = SecretInt::new(4242);
+
+        let (displayed, redacted) =
+            crate::redaction::with_unredacted_types(&["int".to_string()], || {
+                (
+                    secret.to_base_value(span).unwrap(),
+                    redacted_secret_value(&secret, span).unwrap(),
+                )
+            });
+
+        assert_eq!(displayed.as_str().unwrap(), "4242");
+        assert_eq!(redacted.as_str().unwrap(), "<redacted:int>");
+    }
+
+    #[test]
+    fn test_redacted_uses_secret_template() {
+        let span = Span::test_data();
+        let secret = SecretString::new_with_template(
+            "hunter2".to_string(),
+            "[hidden {{secret_type}}]".to_string(),
+        );
+        let redacted = redacted_secret_value(&secret, span).unwrap();
+        assert_eq!(redacted.as_str().unwrap(), "[hidden string]");
+    }
+
+    #[test]
+    fn test_type_check_matching_type() {
+        let secret = SecretString::new("value".to_string());
+        assert!(check_secret_type(&secret, "string", nu_protocol::Span::test_data()).is_ok());
+    }
+
+    #[test]
+    fn test_type_check_mismatched_type() {
+        let secret = SecretInt::new(42);
+        let err = check_secret_type(&secret, "string", nu_protocol::Span::test_data()).unwrap_err();
+        assert_eq!(err.msg, "Type check failed");
+        assert!(format!("{:?}", err).contains("expected secret_string, found secret_int"));
+    }
+
+    #[test]
+    fn test_type_check_failure_points_at_wrap_site() {
+        let origin = Span::new(3, 10);
+        let secret = SecretInt::new(42).with_origin_span(origin);
+        let err = check_secret_type(&secret, "string", Span::test_data()).unwrap_err();
+        assert_eq!(err.labels.len(), 2);
+        assert!(err.labels.iter().any(|label| label.span == origin));
+
+        let secret = SecretInt::new(42);
+        let err = check_secret_type(&secret, "string", Span::test_data()).unwrap_err();
+        assert_eq!(err.labels.len(), 1);
+    }
+
+    #[test]
+    fn test_type_check_invalid_type_name() {
+        let secret = SecretString::new("value".to_string());
+        let err = check_secret_type(&secret, "strng", nu_protocol::Span::test_data()).unwrap_err();
+        assert_eq!(err.msg, "Invalid type name");
+    }
+
+    #[test]
+    fn test_filesize_round_trip() {
+        let span = nu_protocol::Span::test_data();
+        let original = Value::filesize(nu_protocol::Filesize::new(10_737_418_240), span);
+
+        let wrapped = super::super::wrap::wrap_value(original.clone(), span).unwrap();
+        let custom = wrapped.as_custom_value().unwrap();
+        assert_eq!(custom.type_name(), "secret_filesize");
+
+        let unwrapped = reveal_secret_value(custom, span).unwrap();
+        assert_eq!(unwrapped, original);
+    }
+
+    #[test]
+    fn test_cell_path_round_trip() {
+        use nu_protocol::ast::{CellPath, PathMember};
+
+        let span = nu_protocol::Span::test_data();
+        let original = Value::cell_path(
+            CellPath {
+                members: vec![
+                    PathMember::String {
+                        val: "users".to_string(),
+                        span,
+                        optional: false,
+                        casing: Default::default(),
+                    },
+                    PathMember::Int {
+                        val: 0,
+                        span,
+                        optional: true,
+                    },
+                ],
+            },
+            span,
+        );
+
+        let wrapped = super::super::wrap::wrap_value(original.clone(), span).unwrap();
+        let custom = wrapped.as_custom_value().unwrap();
+        assert_eq!(custom.type_name(), "secret_cellpath");
+        assert!(!format!("{:?}", wrapped).contains("users"));
+
+        let unwrapped = reveal_secret_value(custom, span).unwrap();
+        assert_eq!(unwrapped, original);
+    }
+
+    fn assert_range_round_trip(original: Value) {
+        let span = nu_protocol::Span::test_data();
+
+        let wrapped = super::super::wrap::wrap_value(original.clone(), span).unwrap();
+        let custom = wrapped.as_custom_value().unwrap();
+        assert_eq!(custom.type_name(), "secret_range");
+
+        let unwrapped = reveal_secret_value(custom, span).unwrap();
+        assert_eq!(unwrapped, original);
+    }
+
+    #[test]
+    fn test_inclusive_int_range_round_trip() {
+        let span = nu_protocol::Span::test_data();
+        let range = nu_protocol::Range::new(
+            Value::int(1, span),
+            Value::int(3, span),
+            Value::int(10, span),
+            nu_protocol::ast::RangeInclusion::Inclusive,
+            span,
+        )
+        .unwrap();
+        assert_range_round_trip(Value::range(range, span));
+    }
+
+    #[test]
+    fn test_float_range_round_trip() {
+        let span = nu_protocol::Span::test_data();
+        let range = nu_protocol::Range::new(
+            Value::float(0.5, span),
+            Value::nothing(span),
+            Value::float(2.5, span),
+            nu_protocol::ast::RangeInclusion::RightExclusive,
+            span,
+        )
+        .unwrap();
+        assert!(matches!(range, nu_protocol::Range::FloatRange(_)));
+        assert_range_round_trip(Value::range(range, span));
     }
 }
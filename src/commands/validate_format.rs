@@ -9,11 +9,11 @@ use nu_protocol::{
 };
 use regex::Regex;
 
-use crate::SecretString;
+use crate::{SecretBinary, SecretString};
 
 /// Supported format names for display in error messages.
-const SUPPORTED_FORMATS: &str =
-    "email, uuid, hex, base64, jwt, ipv4, ipv6, ssn, credit-card, regex";
+pub(crate) const SUPPORTED_FORMATS: &str =
+    "email, uuid, hex, base64, base32, jwt, ipv4, ipv6, ssn, credit-card, luhn, regex";
 
 /// Returns a compiled regex, caching it in the provided `OnceLock`.
 fn cached_regex<'a>(lock: &'a OnceLock<Regex>, pattern: &str) -> &'a Regex {
@@ -47,6 +47,12 @@ fn base64_regex() -> &'static Regex {
     cached_regex(&RE, r"^[A-Za-z0-9+/]*={0,2}$")
 }
 
+/// Returns the compiled RFC 4648 base32 regex (uppercase alphabet only).
+fn base32_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    cached_regex(&RE, r"^[A-Z2-7]+=*$")
+}
+
 /// Returns the compiled JWT structure regex (three base64url-encoded segments).
 fn jwt_regex() -> &'static Regex {
     static RE: OnceLock<Regex> = OnceLock::new();
@@ -66,11 +72,13 @@ pub enum FormatValidator {
     Uuid,
     Hex,
     Base64,
+    Base32,
     Jwt,
     Ipv4,
     Ipv6,
     Ssn,
     CreditCard,
+    Luhn,
     Regex(String),
 }
 
@@ -81,17 +89,37 @@ impl std::fmt::Display for FormatValidator {
             FormatValidator::Uuid => write!(f, "uuid"),
             FormatValidator::Hex => write!(f, "hex"),
             FormatValidator::Base64 => write!(f, "base64"),
+            FormatValidator::Base32 => write!(f, "base32"),
             FormatValidator::Jwt => write!(f, "jwt"),
             FormatValidator::Ipv4 => write!(f, "ipv4"),
             FormatValidator::Ipv6 => write!(f, "ipv6"),
             FormatValidator::Ssn => write!(f, "ssn"),
             FormatValidator::CreditCard => write!(f, "credit-card"),
+            FormatValidator::Luhn => write!(f, "luhn"),
             FormatValidator::Regex(pat) => write!(f, "regex {}", pat),
         }
     }
 }
 
 impl FormatValidator {
+    /// Looks up a built-in (non-regex) format by name, case-insensitively.
+    pub fn from_name(name: &str) -> Option<FormatValidator> {
+        match name.to_lowercase().as_str() {
+            "email" => Some(FormatValidator::Email),
+            "uuid" => Some(FormatValidator::Uuid),
+            "hex" => Some(FormatValidator::Hex),
+            "base64" => Some(FormatValidator::Base64),
+            "base32" => Some(FormatValidator::Base32),
+            "jwt" => Some(FormatValidator::Jwt),
+            "ipv4" => Some(FormatValidator::Ipv4),
+            "ipv6" => Some(FormatValidator::Ipv6),
+            "ssn" => Some(FormatValidator::Ssn),
+            "credit-card" => Some(FormatValidator::CreditCard),
+            "luhn" => Some(FormatValidator::Luhn),
+            _ => None,
+        }
+    }
+
     /// Validates the input string against this format.
     pub fn validate(&self, input: &str) -> Result<bool, String> {
         match self {
@@ -99,11 +127,13 @@ impl FormatValidator {
             FormatValidator::Uuid => Ok(uuid_regex().is_match(input)),
             FormatValidator::Hex => Ok(!input.is_empty() && hex_regex().is_match(input)),
             FormatValidator::Base64 => Ok(!input.is_empty() && base64_regex().is_match(input)),
+            FormatValidator::Base32 => Ok(base32_regex().is_match(input)),
             FormatValidator::Jwt => Ok(jwt_regex().is_match(input)),
             FormatValidator::Ipv4 => Ok(input.parse::<Ipv4Addr>().is_ok()),
             FormatValidator::Ipv6 => Ok(input.parse::<Ipv6Addr>().is_ok()),
             FormatValidator::Ssn => Ok(validate_ssn(input)),
             FormatValidator::CreditCard => Ok(validate_credit_card(input)),
+            FormatValidator::Luhn => Ok(validate_luhn(input)),
             FormatValidator::Regex(pattern) => {
                 let re =
                     Regex::new(pattern).map_err(|e| format!("Invalid regex pattern: {}", e))?;
@@ -152,6 +182,27 @@ fn validate_credit_card(input: &str) -> bool {
     luhn_check(&digits)
 }
 
+/// Validates a Luhn checksum on any number of digits (at least 2).
+///
+/// Unlike `credit-card`, no length range is enforced. Dashes and spaces are
+/// allowed as separators.
+fn validate_luhn(input: &str) -> bool {
+    if input
+        .chars()
+        .any(|c| !c.is_ascii_digit() && c != '-' && c != ' ')
+    {
+        return false;
+    }
+
+    let digits: Vec<u8> = input
+        .chars()
+        .filter(|c| c.is_ascii_digit())
+        .map(|c| c as u8 - b'0')
+        .collect();
+
+    digits.len() >= 2 && luhn_check(&digits)
+}
+
 /// Performs the Luhn checksum validation on a slice of digits.
 fn luhn_check(digits: &[u8]) -> bool {
     let mut sum: u32 = 0;
@@ -183,11 +234,14 @@ impl PluginCommand for SecretValidateFormatCommand {
 
     fn signature(&self) -> Signature {
         Signature::build(self.name())
-            .input_output_types(vec![(Type::Custom("secret_string".into()), Type::Bool)])
+            .input_output_types(vec![
+                (Type::Custom("secret_string".into()), Type::Bool),
+                (Type::Custom("secret_binary".into()), Type::Bool),
+            ])
             .required(
                 "format",
                 SyntaxShape::String,
-                "Format to validate against (email, uuid, hex, base64, jwt, ipv4, ipv6, ssn, credit-card, regex)",
+                "Format to validate against (email, uuid, hex, base64, base32, jwt, ipv4, ipv6, ssn, credit-card, luhn, regex)",
             )
             .optional(
                 "pattern",
@@ -223,6 +277,11 @@ impl PluginCommand for SecretValidateFormatCommand {
                 description: "Validate base64 format",
                 result: Some(Value::bool(true, nu_protocol::Span::test_data())),
             },
+            Example {
+                example: r#""JBSWY3DPEHPK3PXP" | secret wrap | secret validate-format base32"#,
+                description: "Validate a base32-encoded TOTP seed",
+                result: Some(Value::bool(true, nu_protocol::Span::test_data())),
+            },
             Example {
                 example: r#""eyJhbGciOiJIUzI1NiJ9.eyJzdWIiOiIxMjM0NTY3ODkwIn0.abc123" | secret wrap | secret validate-format jwt"#,
                 description: "Validate JWT structure",
@@ -248,6 +307,11 @@ impl PluginCommand for SecretValidateFormatCommand {
                 description: "Validate credit card number (Luhn check)",
                 result: Some(Value::bool(true, nu_protocol::Span::test_data())),
             },
+            Example {
+                example: r#""79927398713" | secret wrap | secret validate-format luhn"#,
+                description: "Validate a Luhn checksum of any length",
+                result: Some(Value::bool(true, nu_protocol::Span::test_data())),
+            },
             Example {
                 example: r#""ABC-123" | secret wrap | secret validate-format regex "^[A-Z]{3}-\d{3}$""#,
                 description: "Validate against a custom regex pattern",
@@ -287,7 +351,7 @@ impl PluginCommand for SecretValidateFormatCommand {
                         validate_secret_format(val.as_ref(), &validator, call.head)?
                     }
                     _ => return Err(LabeledError::new("Invalid input").with_label(
-                        "Input must be a SecretString. Use 'secret wrap' to create a secret first",
+                        "Input must be a SecretString or SecretBinary. Use 'secret wrap' to create a secret first",
                         call.head,
                     )),
                 };
@@ -305,16 +369,11 @@ fn parse_format_validator(
     format_name: &str,
     call: &EvaluatedCall,
 ) -> Result<FormatValidator, LabeledError> {
+    if let Some(validator) = FormatValidator::from_name(format_name) {
+        return Ok(validator);
+    }
+
     match format_name.to_lowercase().as_str() {
-        "email" => Ok(FormatValidator::Email),
-        "uuid" => Ok(FormatValidator::Uuid),
-        "hex" => Ok(FormatValidator::Hex),
-        "base64" => Ok(FormatValidator::Base64),
-        "jwt" => Ok(FormatValidator::Jwt),
-        "ipv4" => Ok(FormatValidator::Ipv4),
-        "ipv6" => Ok(FormatValidator::Ipv6),
-        "ssn" => Ok(FormatValidator::Ssn),
-        "credit-card" => Ok(FormatValidator::CreditCard),
         "regex" => {
             let pattern = match call.positional.get(1) {
                 Some(Value::String { val, .. }) => val.clone(),
@@ -336,22 +395,42 @@ fn parse_format_validator(
     }
 }
 
+/// Validates raw bytes against the given format validator.
+///
+/// `hex` checks the lowercase hex encoding of the bytes. Every other format
+/// requires the bytes to be valid UTF-8 and returns `false` otherwise.
+fn validate_bytes(validator: &FormatValidator, bytes: &[u8]) -> Result<bool, String> {
+    match validator {
+        FormatValidator::Hex => validator.validate(&hex::encode(bytes)),
+        _ => match std::str::from_utf8(bytes) {
+            Ok(text) => validator.validate(text),
+            Err(_) => Ok(false),
+        },
+    }
+}
+
 /// Validates a secret custom value against the given format validator.
 fn validate_secret_format(
     val: &dyn nu_protocol::CustomValue,
     validator: &FormatValidator,
     span: nu_protocol::Span,
 ) -> Result<Value, LabeledError> {
-    if let Some(secret_string) = val.as_any().downcast_ref::<SecretString>() {
-        let is_valid = validator.validate(secret_string.reveal()).map_err(|e| {
-            LabeledError::new(format!("Validation error: {}", e))
-                .with_label("Invalid regex pattern", span)
-        })?;
-        Ok(Value::bool(is_valid, span))
+    let result = if let Some(secret_string) = val.as_any().downcast_ref::<SecretString>() {
+        validator.validate(secret_string.reveal())
+    } else if let Some(secret_binary) = val.as_any().downcast_ref::<SecretBinary>() {
+        validate_bytes(validator, &secret_binary.reveal())
     } else {
-        Err(LabeledError::new("Unsupported secret type")
-            .with_label("Only SecretString supports validate-format operation", span))
-    }
+        return Err(LabeledError::new("Unsupported secret type").with_label(
+            "Only SecretString and SecretBinary support validate-format operation",
+            span,
+        ));
+    };
+
+    let is_valid = result.map_err(|e| {
+        LabeledError::new(format!("Validation error: {}", e))
+            .with_label("Invalid regex pattern", span)
+    })?;
+    Ok(Value::bool(is_valid, span))
 }
 
 #[cfg(test)]
@@ -372,7 +451,7 @@ mod tests {
         assert_eq!(signature.name, "secret validate-format");
         assert_eq!(signature.required_positional.len(), 1);
         assert_eq!(signature.optional_positional.len(), 1);
-        assert_eq!(signature.input_output_types.len(), 1);
+        assert_eq!(signature.input_output_types.len(), 2);
     }
 
     #[test]
@@ -386,7 +465,7 @@ mod tests {
     fn test_examples_count() {
         let command = SecretValidateFormatCommand;
         let examples = command.examples();
-        assert_eq!(examples.len(), 11);
+        assert_eq!(examples.len(), 13);
     }
 
     #[test]
@@ -500,6 +579,50 @@ mod tests {
         assert!(!validator.validate("").unwrap()); // empty
     }
 
+    // Luhn validation tests
+    #[test]
+    fn test_luhn_valid_short_numbers() {
+        let validator = FormatValidator::Luhn;
+        assert!(validator.validate("18").unwrap());
+        assert!(validator.validate("79927398713").unwrap());
+        assert!(validator.validate("1234-5674").unwrap());
+        assert!(!FormatValidator::CreditCard.validate("79927398713").unwrap());
+    }
+
+    #[test]
+    fn test_luhn_invalid() {
+        let validator = FormatValidator::Luhn;
+        assert!(!validator.validate("79927398710").unwrap()); // bad checksum
+        assert!(!validator.validate("0").unwrap()); // too short
+        assert!(!validator.validate("").unwrap());
+        assert!(!validator.validate("- -").unwrap()); // no digits
+        assert!(!validator.validate("abc").unwrap());
+        assert!(!validator.validate("7992739871x3").unwrap());
+    }
+
+    // Base32 validation tests
+    #[test]
+    fn test_base32_valid() {
+        let validator = FormatValidator::Base32;
+        assert!(validator.validate("JBSWY3DPEHPK3PXP").unwrap());
+        assert!(validator.validate("MZXW6===").unwrap());
+        assert!(validator
+            .validate("ABCDEFGHIJKLMNOPQRSTUVWXYZ234567")
+            .unwrap());
+    }
+
+    #[test]
+    fn test_base32_invalid() {
+        let validator = FormatValidator::Base32;
+        for digit in ["0", "1", "8", "9"] {
+            assert!(!validator.validate(&format!("JBSW{}", digit)).unwrap());
+        }
+        assert!(!validator.validate("jbswy3dpehpk3pxp").unwrap()); // lowercase
+        assert!(!validator.validate("MZXW6===A").unwrap()); // data after padding
+        assert!(!validator.validate("====").unwrap());
+        assert!(!validator.validate("").unwrap());
+    }
+
     // JWT validation tests
     #[test]
     fn test_jwt_valid() {
@@ -632,17 +755,29 @@ mod tests {
         assert_eq!(FormatValidator::Uuid.to_string(), "uuid");
         assert_eq!(FormatValidator::Hex.to_string(), "hex");
         assert_eq!(FormatValidator::Base64.to_string(), "base64");
+        assert_eq!(FormatValidator::Base32.to_string(), "base32");
         assert_eq!(FormatValidator::Jwt.to_string(), "jwt");
         assert_eq!(FormatValidator::Ipv4.to_string(), "ipv4");
         assert_eq!(FormatValidator::Ipv6.to_string(), "ipv6");
         assert_eq!(FormatValidator::Ssn.to_string(), "ssn");
         assert_eq!(FormatValidator::CreditCard.to_string(), "credit-card");
+        assert_eq!(FormatValidator::Luhn.to_string(), "luhn");
         assert_eq!(
             FormatValidator::Regex("^test$".to_string()).to_string(),
             "regex ^test$"
         );
     }
 
+    #[test]
+    fn test_format_from_name() {
+        assert!(matches!(
+            FormatValidator::from_name("UUID"),
+            Some(FormatValidator::Uuid)
+        ));
+        assert!(FormatValidator::from_name("regex").is_none());
+        assert!(FormatValidator::from_name("unknown").is_none());
+    }
+
     // SecretString integration tests
     #[test]
     fn test_validate_with_secret_string() {
@@ -698,6 +833,35 @@ mod tests {
         assert_eq!(err.msg, "Unsupported secret type");
     }
 
+    #[test]
+    fn test_validate_secret_format_binary_utf8() {
+        let span = Span::test_data();
+        let secret = SecretBinary::new(b"user@example.com".to_vec());
+
+        let email = validate_secret_format(&secret, &FormatValidator::Email, span).unwrap();
+        assert!(email.as_bool().unwrap());
+
+        let uuid = validate_secret_format(&secret, &FormatValidator::Uuid, span).unwrap();
+        assert!(!uuid.as_bool().unwrap());
+    }
+
+    #[test]
+    fn test_validate_secret_format_binary_non_utf8() {
+        let span = Span::test_data();
+        let secret = SecretBinary::new(vec![0xff, 0xfe, 0x00, 0x9f]);
+
+        let base64 = validate_secret_format(&secret, &FormatValidator::Base64, span).unwrap();
+        assert!(!base64.as_bool().unwrap());
+
+        let hex = validate_secret_format(&secret, &FormatValidator::Hex, span).unwrap();
+        assert!(hex.as_bool().unwrap());
+    }
+
+    #[test]
+    fn test_validate_bytes_hex_empty() {
+        assert!(!validate_bytes(&FormatValidator::Hex, &[]).unwrap());
+    }
+
     // Tests exercising parse_format_validator — the extracted parser function
 
     fn make_call(positional: Vec<Value>) -> EvaluatedCall {
@@ -736,6 +900,13 @@ mod tests {
         assert!(matches!(validator, FormatValidator::Base64));
     }
 
+    #[test]
+    fn test_parse_format_validator_base32() {
+        let call = make_call(vec![Value::test_string("base32")]);
+        let validator = parse_format_validator("base32", &call).unwrap();
+        assert!(matches!(validator, FormatValidator::Base32));
+    }
+
     #[test]
     fn test_parse_format_validator_jwt() {
         let call = make_call(vec![Value::test_string("jwt")]);
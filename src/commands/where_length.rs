@@ -0,0 +1,314 @@
+//! Implements `secret where-length` — keeps list items or table rows whose
+//! secret length satisfies a comparison, without revealing the secrets.
+
+use std::str::FromStr;
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, Signature, Span, SyntaxShape, Type, Value,
+};
+
+use super::length::secret_len;
+
+#[derive(Clone)]
+pub struct SecretWhereLengthCommand;
+
+/// Comparison operator applied to a secret's length.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LengthComparison {
+    Greater,
+    GreaterOrEqual,
+    Less,
+    LessOrEqual,
+    Equal,
+}
+
+impl LengthComparison {
+    fn matches(self, length: usize, n: usize) -> bool {
+        match self {
+            LengthComparison::Greater => length > n,
+            LengthComparison::GreaterOrEqual => length >= n,
+            LengthComparison::Less => length < n,
+            LengthComparison::LessOrEqual => length <= n,
+            LengthComparison::Equal => length == n,
+        }
+    }
+}
+
+impl FromStr for LengthComparison {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            ">" => Ok(LengthComparison::Greater),
+            ">=" => Ok(LengthComparison::GreaterOrEqual),
+            "<" => Ok(LengthComparison::Less),
+            "<=" => Ok(LengthComparison::LessOrEqual),
+            "==" => Ok(LengthComparison::Equal),
+            _ => Err(format!(
+                "Unknown comparison '{}'. Valid options: >, >=, <, <=, ==",
+                s
+            )),
+        }
+    }
+}
+
+/// Length predicate evaluated against each item of the input.
+struct LengthFilter {
+    comparison: LengthComparison,
+    n: usize,
+    column: Option<String>,
+    chars: bool,
+}
+
+impl LengthFilter {
+    /// Decide whether `item` is kept.
+    ///
+    /// Without a column the item itself must be a length-bearing secret; with a
+    /// column the item must be a record whose field holds one. Only the length
+    /// is inspected, and the item is passed through untouched.
+    fn keep(&self, item: &Value, span: Span) -> Result<bool, LabeledError> {
+        let target = match &self.column {
+            Some(column) => {
+                let record = item.as_record().map_err(|_| {
+                    LabeledError::new("Invalid input").with_label(
+                        format!("Expected a record row, got {}", item.get_type()),
+                        span,
+                    )
+                })?;
+                record.get(column).ok_or_else(|| {
+                    LabeledError::new("Column not found")
+                        .with_label(format!("Row has no column '{}'", column), span)
+                })?
+            }
+            None => item,
+        };
+
+        let length = match target {
+            Value::Custom { val, .. } => secret_len(val.as_ref(), self.chars),
+            _ => None,
+        }
+        .ok_or_else(|| {
+            LabeledError::new("Unsupported secret type").with_label(
+                format!(
+                    "Expected a secret string, list, record, or binary, got {}",
+                    target.get_type()
+                ),
+                span,
+            )
+        })?;
+
+        Ok(self.comparison.matches(length, self.n))
+    }
+}
+
+impl PluginCommand for SecretWhereLengthCommand {
+    type Plugin = crate::SecretPlugin;
+
+    fn name(&self) -> &str {
+        "secret where-length"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(
+                Type::List(Box::new(Type::Any)),
+                Type::List(Box::new(Type::Any)),
+            )])
+            .required(
+                "comparison",
+                SyntaxShape::String,
+                "Comparison operator: >, >=, <, <=, or ==",
+            )
+            .required("n", SyntaxShape::Int, "Length to compare against")
+            .named(
+                "column",
+                SyntaxShape::String,
+                "Column of each row holding the secret to test",
+                Some('c'),
+            )
+            .switch(
+                "chars",
+                "Compare unicode character counts instead of bytes for secret strings",
+                None,
+            )
+            .category(Category::Filters)
+    }
+
+    fn description(&self) -> &str {
+        "Keep items or rows whose secret length satisfies a comparison, without exposing the content"
+    }
+
+    fn examples(&self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                example: r#"["short", "a-much-longer-token"] | each { secret wrap } | secret where-length > 16"#,
+                description: "Keep secret strings longer than 16 bytes",
+                result: None,
+            },
+            Example {
+                example: r#"$keys | update token { secret wrap } | secret where-length >= 32 --column token"#,
+                description: "Keep table rows whose secret token is at least 32 bytes",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let comparison: String = call.req(0)?;
+        let comparison = LengthComparison::from_str(&comparison).map_err(|e| {
+            LabeledError::new("Invalid comparison").with_label(e, call.positional[0].span())
+        })?;
+        let n: i64 = call.req(1)?;
+        let n = usize::try_from(n).map_err(|_| {
+            LabeledError::new("Invalid length")
+                .with_label("Length must be non-negative", call.positional[1].span())
+        })?;
+        let filter = LengthFilter {
+            comparison,
+            n,
+            column: call.get_flag("column")?,
+            chars: call.has_flag("chars")?,
+        };
+        let span = call.head;
+
+        match input {
+            PipelineData::Value(Value::List { vals, .. }, metadata) => {
+                let mut kept = Vec::new();
+                for item in vals {
+                    if filter.keep(&item, span)? {
+                        kept.push(item);
+                    }
+                }
+                Ok(PipelineData::Value(Value::list(kept, span), metadata))
+            }
+            PipelineData::ListStream(stream, metadata) => Ok(PipelineData::ListStream(
+                stream.modify(move |iter| {
+                    iter.filter_map(move |item| match filter.keep(&item, span) {
+                        Ok(true) => Some(item),
+                        Ok(false) => None,
+                        Err(e) => Some(Value::error(e.into(), span)),
+                    })
+                }),
+                metadata,
+            )),
+            _ => Err(LabeledError::new("Invalid input").with_label(
+                "Expected a list of secrets or a table with a secret column",
+                span,
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{SecretInt, SecretString};
+
+    fn secret(text: &str) -> Value {
+        Value::custom(
+            Box::new(SecretString::new(text.to_string())),
+            Span::test_data(),
+        )
+    }
+
+    fn filter(comparison: &str, n: usize) -> LengthFilter {
+        LengthFilter {
+            comparison: comparison.parse().unwrap(),
+            n,
+            column: None,
+            chars: false,
+        }
+    }
+
+    /// Filter the sample secrets and return the lengths of those kept.
+    fn kept_lengths(filter: &LengthFilter) -> Vec<usize> {
+        [
+            "",
+            "abc",
+            "hunter22",
+            "0123456789abcdef",
+            "0123456789abcdef-x",
+        ]
+        .iter()
+        .filter(|text| filter.keep(&secret(text), Span::test_data()).unwrap())
+        .map(|text| text.len())
+        .collect()
+    }
+
+    #[test]
+    fn test_command_name() {
+        let command = SecretWhereLengthCommand;
+        assert_eq!(command.name(), "secret where-length");
+    }
+
+    #[test]
+    fn test_signature() {
+        let command = SecretWhereLengthCommand;
+        let signature = command.signature();
+        assert_eq!(signature.required_positional.len(), 2);
+        assert!(signature.get_long_flag("column").is_some());
+        assert!(signature.get_long_flag("chars").is_some());
+    }
+
+    #[test]
+    fn test_each_comparison() {
+        assert_eq!(kept_lengths(&filter(">", 8)), vec![16, 18]);
+        assert_eq!(kept_lengths(&filter(">=", 8)), vec![8, 16, 18]);
+        assert_eq!(kept_lengths(&filter("<", 8)), vec![0, 3]);
+        assert_eq!(kept_lengths(&filter("<=", 8)), vec![0, 3, 8]);
+        assert_eq!(kept_lengths(&filter("==", 16)), vec![16]);
+    }
+
+    #[test]
+    fn test_unknown_comparison_is_rejected() {
+        let err = LengthComparison::from_str("!=").unwrap_err();
+        assert!(err.contains("Unknown comparison '!='"));
+    }
+
+    #[test]
+    fn test_chars_and_bytes() {
+        let mut filter = filter("==", 6);
+        assert!(!filter.keep(&secret("пароль"), Span::test_data()).unwrap());
+        filter.chars = true;
+        assert!(filter.keep(&secret("пароль"), Span::test_data()).unwrap());
+    }
+
+    #[test]
+    fn test_column_rows_pass_through_redacted() {
+        let span = Span::test_data();
+        let mut filter = filter(">", 4);
+        filter.column = Some("token".to_string());
+
+        let row = Value::record(
+            nu_protocol::record! { "name" => Value::test_string("ci"), "token" => secret("hunter22") },
+            span,
+        );
+        assert!(filter.keep(&row, span).unwrap());
+        assert!(!format!("{:?}", row).contains("hunter22"));
+
+        let missing = Value::record(
+            nu_protocol::record! { "name" => Value::test_string("ci") },
+            span,
+        );
+        assert_eq!(
+            filter.keep(&missing, span).unwrap_err().msg,
+            "Column not found"
+        );
+    }
+
+    #[test]
+    fn test_non_length_items_are_rejected() {
+        let span = Span::test_data();
+        let filter = filter(">", 0);
+        let secret_int = Value::custom(Box::new(SecretInt::new(1)), span);
+        assert!(filter.keep(&secret_int, span).is_err());
+        assert!(filter.keep(&Value::test_string("plain"), span).is_err());
+    }
+}
@@ -1,16 +1,545 @@
 //! Implements `secret wrap` — wraps a Nushell value into a secret type.
 
 use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
-use nu_protocol::{Category, Example, LabeledError, PipelineData, Signature, Type, Value};
+use nu_protocol::ast::{CellPath, PathMember};
+use nu_protocol::{
+    Category, Example, LabeledError, ListStream, PipelineData, Signature, Span, SyntaxShape, Type,
+    Value,
+};
+
+use std::path::Path;
 
+use super::redact_with::redact_secret_with;
+use super::validate_format::{FormatValidator, SUPPORTED_FORMATS};
+use crate::config::ConfigManager;
 use crate::{
-    SecretBinary, SecretBool, SecretDate, SecretFloat, SecretInt, SecretList, SecretRecord,
-    SecretString,
+    SecretBinary, SecretBool, SecretCellPath, SecretDate, SecretDuration, SecretFilesize,
+    SecretFloat, SecretInt, SecretList, SecretRange, SecretRecord, SecretString,
 };
 
 #[derive(Clone)]
 pub struct SecretWrapCommand;
 
+/// Error for wrapping `null`, which has no value worth keeping secret.
+///
+/// Usually a sign that an upstream command produced nothing (a missing
+/// environment variable or column, an empty `get`), so it gets its own message
+/// rather than the generic "Unsupported type" one.
+fn nothing_error(span: Span) -> LabeledError {
+    LabeledError::new("Cannot wrap null")
+        .with_label("Input is null (nothing), which cannot be wrapped as a secret", span)
+        .with_help(
+            "Check the pipeline feeding 'secret wrap': an earlier command may have returned nothing, e.g. a missing environment variable or column. Empty strings, lists and records can be wrapped",
+        )
+}
+
+/// Wrap a single Nushell value into its corresponding secret type.
+///
+/// The secret remembers the input value's span so later commands can point
+/// errors at the place the value came from. Returns a "Cannot wrap null" error
+/// for nothing and an "Unsupported type" error for other values that have no
+/// secret counterpart (e.g. closures).
+pub(crate) fn wrap_value(value: Value, span: Span) -> Result<Value, LabeledError> {
+    let origin = value.span();
+    let wrapped_value = match value {
+        Value::String { val, .. } => {
+            let secret = SecretString::new(val).with_origin_span(origin);
+            Value::custom(Box::new(secret), span)
+        }
+        Value::Int { val, .. } => {
+            let secret = SecretInt::new(val).with_origin_span(origin);
+            Value::custom(Box::new(secret), span)
+        }
+        Value::Bool { val, .. } => {
+            let secret = SecretBool::new(val).with_origin_span(origin);
+            Value::custom(Box::new(secret), span)
+        }
+        Value::Float { val, .. } => {
+            let secret = SecretFloat::new(val).with_origin_span(origin);
+            Value::custom(Box::new(secret), span)
+        }
+        Value::Date { val, .. } => {
+            let secret = SecretDate::new(val).with_origin_span(origin);
+            Value::custom(Box::new(secret), span)
+        }
+        Value::Binary { val, .. } => {
+            let secret = SecretBinary::new(val).with_origin_span(origin);
+            Value::custom(Box::new(secret), span)
+        }
+        Value::Duration { val, .. } => {
+            let secret = SecretDuration::new(val).with_origin_span(origin);
+            Value::custom(Box::new(secret), span)
+        }
+        Value::Filesize { val, .. } => {
+            let secret = SecretFilesize::new(val.get()).with_origin_span(origin);
+            Value::custom(Box::new(secret), span)
+        }
+        Value::List { vals, .. } => {
+            let secret = SecretList::new(vals).with_origin_span(origin);
+            Value::custom(Box::new(secret), span)
+        }
+        Value::Record { val, .. } => {
+            let secret = SecretRecord::new(val.into_owned()).with_origin_span(origin);
+            Value::custom(Box::new(secret), span)
+        }
+        Value::Range { val, .. } => {
+            let secret = SecretRange::new(*val).with_origin_span(origin);
+            Value::custom(Box::new(secret), span)
+        }
+        Value::CellPath { val, .. } => {
+            let secret = SecretCellPath::new(val).with_origin_span(origin);
+            Value::custom(Box::new(secret), span)
+        }
+        Value::Nothing { .. } => return Err(nothing_error(span)),
+        _ => {
+            return Err(LabeledError::new("Unsupported type").with_label(
+                format!(
+                    "Cannot wrap value of type '{}'. Supported types: string, int, bool, float, date, duration, filesize, binary, list, record, range, cell-path",
+                    value.get_type()
+                ),
+                span,
+            ));
+        }
+    };
+
+    Ok(wrapped_value)
+}
+
+/// Wrap a single Nushell value into its secret type with its own redaction template.
+///
+/// Like [`wrap_value`], but the secret is built with `new_with_template` so it
+/// displays using `template` instead of the global one. The template must
+/// already be validated.
+pub(crate) fn wrap_value_with_template(
+    value: Value,
+    template: String,
+    span: Span,
+) -> Result<Value, LabeledError> {
+    let origin = value.span();
+    let secret: Box<dyn nu_protocol::CustomValue> = match value {
+        Value::String { val, .. } => {
+            Box::new(SecretString::new_with_template(val, template).with_origin_span(origin))
+        }
+        Value::Int { val, .. } => {
+            Box::new(SecretInt::new_with_template(val, template).with_origin_span(origin))
+        }
+        Value::Bool { val, .. } => {
+            Box::new(SecretBool::new_with_template(val, template).with_origin_span(origin))
+        }
+        Value::Float { val, .. } => {
+            Box::new(SecretFloat::new_with_template(val, template).with_origin_span(origin))
+        }
+        Value::Date { val, .. } => {
+            Box::new(SecretDate::new_with_template(val, template).with_origin_span(origin))
+        }
+        Value::Binary { val, .. } => {
+            Box::new(SecretBinary::new_with_template(val, template).with_origin_span(origin))
+        }
+        Value::Duration { val, .. } => {
+            Box::new(SecretDuration::new_with_template(val, template).with_origin_span(origin))
+        }
+        Value::Filesize { val, .. } => Box::new(
+            SecretFilesize::new_with_template(val.get(), template).with_origin_span(origin),
+        ),
+        Value::List { vals, .. } => {
+            Box::new(SecretList::new_with_template(vals, template).with_origin_span(origin))
+        }
+        Value::Record { val, .. } => Box::new(
+            SecretRecord::new_with_template(val.into_owned(), template).with_origin_span(origin),
+        ),
+        Value::Range { val, .. } => {
+            Box::new(SecretRange::new_with_template(*val, template).with_origin_span(origin))
+        }
+        Value::CellPath { val, .. } => {
+            Box::new(SecretCellPath::new_with_template(val, template).with_origin_span(origin))
+        }
+        Value::Nothing { .. } => return Err(nothing_error(span)),
+        _ => {
+            return Err(LabeledError::new("Unsupported type").with_label(
+                format!(
+                    "Cannot wrap value of type '{}' with a redaction template",
+                    value.get_type()
+                ),
+                span,
+            ));
+        }
+    };
+
+    Ok(Value::custom(secret, span))
+}
+
+/// Per-value options of `secret wrap` applied to piped input or `--as-list` values
+#[derive(Clone, Default)]
+struct WrapOptions {
+    /// Format the input must match (`--expect-format`)
+    expect_format: Option<String>,
+    /// Type to coerce the input to (`--type`)
+    forced_type: Option<String>,
+    /// Redaction template for the secret (`--template`), already validated
+    template: Option<String>,
+}
+
+impl WrapOptions {
+    /// Check, coerce and wrap a single input value according to the options.
+    fn wrap(&self, value: Value, span: Span) -> Result<Value, LabeledError> {
+        if value.is_nothing() {
+            return Err(nothing_error(span));
+        }
+        if let Some(format) = &self.expect_format {
+            check_expected_format(&value, format, span)?;
+        }
+        let value = match &self.forced_type {
+            Some(target) => coerce_value(value, target, span)?,
+            None => value,
+        };
+        match &self.template {
+            Some(template) => wrap_value_with_template(value, template.clone(), span),
+            None => wrap_value(value, span),
+        }
+    }
+
+    /// Check, coerce and wrap each of `values`, collecting them into a `SecretList`.
+    ///
+    /// `--template` applies to the outer list, whose elements keep the global one.
+    fn wrap_as_list(&self, values: Vec<Value>, span: Span) -> Result<Value, LabeledError> {
+        let mut values = values;
+        if let Some(format) = &self.expect_format {
+            for value in &values {
+                check_expected_format(value, format, span)?;
+            }
+        }
+        if let Some(target) = &self.forced_type {
+            values = values
+                .into_iter()
+                .map(|value| coerce_value(value, target, span))
+                .collect::<Result<_, _>>()?;
+        }
+        let wrapped = wrap_values_as_list(values, span)?;
+        match &self.template {
+            Some(template) => {
+                let list = wrapped.as_custom_value().map_err(LabeledError::from)?;
+                redact_secret_with(list, template.clone(), span)
+            }
+            None => Ok(wrapped),
+        }
+    }
+}
+
+/// Wrap each element of a list stream lazily, keeping the output a stream.
+///
+/// Elements are pulled and wrapped one at a time as the output is consumed, so
+/// memory stays bounded. An element that cannot be wrapped becomes an error value.
+fn wrap_stream(stream: ListStream, options: WrapOptions, span: Span) -> ListStream {
+    stream.map(move |value| {
+        options
+            .wrap(value, span)
+            .unwrap_or_else(|e| Value::error(e.into(), span))
+    })
+}
+
+/// Wrap each value individually and collect the results into a `SecretList`.
+///
+/// Every element of the resulting list is itself a secret, so unwrapping the
+/// outer list still yields redacted elements.
+pub(crate) fn wrap_values_as_list(values: Vec<Value>, span: Span) -> Result<Value, LabeledError> {
+    let wrapped = values
+        .into_iter()
+        .map(|value| wrap_value(value, span))
+        .collect::<Result<Vec<Value>, LabeledError>>()?;
+
+    Ok(Value::custom(Box::new(SecretList::new(wrapped)), span))
+}
+
+/// Convert a parsed JSON value into the equivalent Nushell value.
+fn json_to_value(json: serde_json::Value, span: Span) -> Value {
+    match json {
+        serde_json::Value::Null => Value::nothing(span),
+        serde_json::Value::Bool(b) => Value::bool(b, span),
+        serde_json::Value::Number(n) => match n.as_i64() {
+            Some(i) => Value::int(i, span),
+            None => Value::float(n.as_f64().unwrap_or(f64::NAN), span),
+        },
+        serde_json::Value::String(s) => Value::string(s, span),
+        serde_json::Value::Array(items) => Value::list(
+            items
+                .into_iter()
+                .map(|item| json_to_value(item, span))
+                .collect(),
+            span,
+        ),
+        serde_json::Value::Object(map) => Value::record(
+            map.into_iter()
+                .map(|(key, item)| (key, json_to_value(item, span)))
+                .collect(),
+            span,
+        ),
+    }
+}
+
+/// Read a JSON credentials file and wrap its top-level object as a `SecretRecord`.
+///
+/// The file content never reaches the pipeline unwrapped. Errors report only the
+/// path and the error position, never the file content.
+pub(crate) fn wrap_json_file(path: &Path, span: Span) -> Result<Value, LabeledError> {
+    let content = std::fs::read_to_string(path).map_err(|e| {
+        LabeledError::new("Failed to read file").with_label(
+            format!("Cannot read '{}': {}", path.display(), e.kind()),
+            span,
+        )
+    })?;
+
+    let json: serde_json::Value = serde_json::from_str(&content).map_err(|e| {
+        LabeledError::new("Invalid JSON").with_label(
+            format!(
+                "'{}' is not valid JSON (line {}, column {})",
+                path.display(),
+                e.line(),
+                e.column()
+            ),
+            span,
+        )
+    })?;
+
+    if !json.is_object() {
+        return Err(LabeledError::new("Invalid JSON").with_label(
+            format!(
+                "'{}' must contain a JSON object at the top level",
+                path.display()
+            ),
+            span,
+        ));
+    }
+
+    wrap_value(json_to_value(json, span), span)
+}
+
+/// Reject values given as rest arguments without `--as-list`, which would be dropped.
+fn check_rest_requires_as_list(call: &EvaluatedCall) -> Result<(), LabeledError> {
+    let Some(first) = call.positional.first() else {
+        return Ok(());
+    };
+    if call.has_flag("as-list")? {
+        return Ok(());
+    }
+    Err(LabeledError::new("Unexpected arguments")
+        .with_label("Values to wrap are only accepted with --as-list", first.span())
+        .with_help(
+            "Pipe a single value into 'secret wrap', or pass --as-list to wrap these values into a secret list",
+        ))
+}
+
+/// Reject flags and piped input that `--from-json-file` would otherwise ignore.
+fn check_json_file_conflicts(
+    call: &EvaluatedCall,
+    input: &PipelineData,
+) -> Result<(), LabeledError> {
+    if !matches!(
+        input,
+        PipelineData::Empty | PipelineData::Value(Value::Nothing { .. }, _)
+    ) {
+        return Err(LabeledError::new("Conflicting input").with_label(
+            "--from-json-file cannot be combined with piped input",
+            call.head,
+        ));
+    }
+    if call.has_flag("as-list")? {
+        return Err(LabeledError::new("Conflicting flags").with_label(
+            "--from-json-file cannot be combined with --as-list",
+            call.head,
+        ));
+    }
+    for flag in ["type", "template", "expect-format"] {
+        if call.get_flag::<Value>(flag)?.is_some() {
+            return Err(LabeledError::new("Conflicting flags").with_label(
+                format!("--from-json-file cannot be combined with --{}", flag),
+                call.head,
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Check that `value` is a string matching `format` before it gets wrapped.
+///
+/// The error never includes the input itself, only the expected format name,
+/// so a rejected secret does not leak into the error output.
+pub(crate) fn check_expected_format(
+    value: &Value,
+    format: &str,
+    span: Span,
+) -> Result<(), LabeledError> {
+    let validator = FormatValidator::from_name(format).ok_or_else(|| {
+        LabeledError::new("Unsupported format").with_label(
+            format!(
+                "Unknown format '{}'. Supported formats: {}",
+                format,
+                SUPPORTED_FORMATS
+                    .split(", ")
+                    .filter(|name| *name != "regex")
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            span,
+        )
+    })?;
+
+    let Value::String { val, .. } = value else {
+        return Err(LabeledError::new("Invalid input").with_label(
+            format!(
+                "--expect-format requires string input, got '{}'",
+                value.get_type()
+            ),
+            span,
+        ));
+    };
+
+    match validator.validate(val) {
+        Ok(true) => Ok(()),
+        Ok(false) => Err(LabeledError::new("Format mismatch").with_label(
+            format!("Input does not match the expected format '{}'", validator),
+            span,
+        )),
+        Err(e) => Err(LabeledError::new("Format validation failed").with_label(e, span)),
+    }
+}
+
+/// Secret type names accepted by `--type`.
+const FORCEABLE_TYPES: &str =
+    "string, int, bool, float, date, binary, duration, filesize, list, record, range, cellpath";
+
+/// Build the error for an input that cannot be coerced to `target`.
+///
+/// Names only the input's type, never its content.
+fn coercion_error(value: &Value, target: &str, span: Span) -> LabeledError {
+    LabeledError::new("Incompatible type").with_label(
+        format!("Cannot coerce '{}' input to '{}'", value.get_type(), target),
+        span,
+    )
+}
+
+/// Parse a dotted cell path such as `users.0.ssn` or `config.token?`.
+///
+/// All-digit members become row indices and a trailing `?` marks a member
+/// optional. Returns `None` for an empty path or member.
+fn parse_cell_path(text: &str, span: Span) -> Option<CellPath> {
+    let members = text
+        .trim()
+        .split('.')
+        .map(|member| {
+            let (name, optional) = match member.strip_suffix('?') {
+                Some(name) => (name, true),
+                None => (member, false),
+            };
+            if name.is_empty() {
+                return None;
+            }
+            Some(match name.parse::<usize>() {
+                Ok(val) => PathMember::Int {
+                    val,
+                    span,
+                    optional,
+                },
+                Err(_) => PathMember::String {
+                    val: name.to_string(),
+                    span,
+                    optional,
+                    casing: Default::default(),
+                },
+            })
+        })
+        .collect::<Option<Vec<_>>>()?;
+    Some(CellPath { members })
+}
+
+/// Coerce `value` to the Nushell type named `target` so it wraps as that secret type.
+///
+/// A value that already has the target type is returned unchanged. Strings parse
+/// into ints, floats, bools (`true`/`false`) and RFC 3339 dates; ints widen to
+/// floats, integral floats narrow to ints, scalars format as strings, and
+/// strings and binaries convert via UTF-8, and dotted strings parse into cell
+/// paths. Every other combination is an error.
+/// The coerced value keeps the input's span.
+pub(crate) fn coerce_value(value: Value, target: &str, span: Span) -> Result<Value, LabeledError> {
+    let origin = value.span();
+    let coerced = match (target, &value) {
+        ("string", Value::String { .. })
+        | ("int", Value::Int { .. })
+        | ("bool", Value::Bool { .. })
+        | ("float", Value::Float { .. })
+        | ("date", Value::Date { .. })
+        | ("binary", Value::Binary { .. })
+        | ("duration", Value::Duration { .. })
+        | ("filesize", Value::Filesize { .. })
+        | ("list", Value::List { .. })
+        | ("record", Value::Record { .. })
+        | ("range", Value::Range { .. })
+        | ("cellpath", Value::CellPath { .. }) => return Ok(value),
+
+        ("string", Value::Int { val, .. }) => Value::string(val.to_string(), origin),
+        ("string", Value::Float { val, .. }) => Value::string(val.to_string(), origin),
+        ("string", Value::Bool { val, .. }) => Value::string(val.to_string(), origin),
+        ("string", Value::Date { val, .. }) => Value::string(val.to_rfc3339(), origin),
+        ("string", Value::Binary { val, .. }) => match String::from_utf8(val.clone()) {
+            Ok(text) => Value::string(text, origin),
+            Err(_) => return Err(coercion_error(&value, target, span)),
+        },
+
+        ("int", Value::String { val, .. }) => match val.trim().parse::<i64>() {
+            Ok(int) => Value::int(int, origin),
+            Err(_) => return Err(coercion_error(&value, target, span)),
+        },
+        ("int", Value::Float { val, .. })
+            if val.fract() == 0.0 && *val >= i64::MIN as f64 && *val < i64::MAX as f64 =>
+        {
+            Value::int(*val as i64, origin)
+        }
+
+        ("bool", Value::String { val, .. }) => match val.trim() {
+            "true" => Value::bool(true, origin),
+            "false" => Value::bool(false, origin),
+            _ => return Err(coercion_error(&value, target, span)),
+        },
+
+        ("float", Value::String { val, .. }) => match val.trim().parse::<f64>() {
+            Ok(float) => Value::float(float, origin),
+            Err(_) => return Err(coercion_error(&value, target, span)),
+        },
+        ("float", Value::Int { val, .. }) => Value::float(*val as f64, origin),
+
+        ("date", Value::String { val, .. }) => {
+            match chrono::DateTime::parse_from_rfc3339(val.trim()) {
+                Ok(date) => Value::date(date, origin),
+                Err(_) => return Err(coercion_error(&value, target, span)),
+            }
+        }
+
+        ("binary", Value::String { val, .. }) => Value::binary(val.as_bytes().to_vec(), origin),
+
+        ("cellpath", Value::String { val, .. }) => match parse_cell_path(val, origin) {
+            Some(path) => Value::cell_path(path, origin),
+            None => return Err(coercion_error(&value, target, span)),
+        },
+
+        (
+            "string" | "int" | "bool" | "float" | "date" | "binary" | "duration" | "filesize"
+            | "list" | "record" | "range" | "cellpath",
+            _,
+        ) => return Err(coercion_error(&value, target, span)),
+
+        _ => {
+            return Err(LabeledError::new("Invalid type").with_label(
+                format!(
+                    "Unknown secret type '{}'. Valid options: {}",
+                    target, FORCEABLE_TYPES
+                ),
+                span,
+            ));
+        }
+    };
+
+    Ok(coerced)
+}
+
 impl PluginCommand for SecretWrapCommand {
     type Plugin = crate::SecretPlugin;
 
@@ -27,6 +556,8 @@ impl PluginCommand for SecretWrapCommand {
                 (Type::Float, Type::Custom("secret_float".into())),
                 (Type::Date, Type::Custom("secret_date".into())),
                 (Type::Binary, Type::Custom("secret_binary".into())),
+                (Type::Duration, Type::Custom("secret_duration".into())),
+                (Type::Filesize, Type::Custom("secret_filesize".into())),
                 (
                     Type::List(Box::new(Type::Any)),
                     Type::Custom("secret_list".into()),
@@ -35,7 +566,45 @@ impl PluginCommand for SecretWrapCommand {
                     Type::Record(vec![].into()),
                     Type::Custom("secret_record".into()),
                 ),
+                (Type::Range, Type::Custom("secret_range".into())),
+                (Type::CellPath, Type::Custom("secret_cellpath".into())),
+                (Type::Nothing, Type::Custom("secret_list".into())),
+                (Type::Nothing, Type::Custom("secret_record".into())),
             ])
+            .switch(
+                "as-list",
+                "Wrap each rest argument and collect them into a secret list",
+                Some('l'),
+            )
+            .named(
+                "expect-format",
+                SyntaxShape::String,
+                "Refuse to wrap string input that does not match this format (e.g. uuid, email, jwt)",
+                Some('e'),
+            )
+            .named(
+                "type",
+                SyntaxShape::String,
+                "Force the secret type, coercing the input if needed (e.g. string, int, bool, float, binary, cellpath)",
+                Some('t'),
+            )
+            .named(
+                "template",
+                SyntaxShape::String,
+                "Redaction template for the wrapped secret (e.g. \"<hidden:{{secret_type}}>\")",
+                None,
+            )
+            .named(
+                "from-json-file",
+                SyntaxShape::Filepath,
+                "Read a JSON object from this file and wrap it as a secret record",
+                None,
+            )
+            .rest(
+                "values",
+                SyntaxShape::Any,
+                "Values to wrap when --as-list is given",
+            )
             .description(self.description())
             .category(Category::Conversions)
     }
@@ -71,6 +640,21 @@ impl PluginCommand for SecretWrapCommand {
                 description: "Convert a date to a secret date",
                 result: None,
             },
+            Example {
+                example: r#"1hr | secret wrap"#,
+                description: "Convert a duration to a secret duration",
+                result: None,
+            },
+            Example {
+                example: r#"10GB | secret wrap"#,
+                description: "Convert a filesize to a secret filesize",
+                result: None,
+            },
+            Example {
+                example: r#"1..100 | secret wrap"#,
+                description: "Convert a range to a secret range",
+                result: None,
+            },
             Example {
                 example: r#"[1, 2, 3] | secret wrap"#,
                 description: "Convert a list to a secret list",
@@ -86,6 +670,42 @@ impl PluginCommand for SecretWrapCommand {
                 description: "Convert any environment variable to its appropriate secret type",
                 result: None,
             },
+            Example {
+                example: r#"secret wrap --as-list "user" 42 true"#,
+                description: "Wrap several values and collect them into a secret list",
+                result: None,
+            },
+            Example {
+                example: r#"$env.SERVICE_ID | secret wrap --expect-format uuid"#,
+                description: "Wrap a string only if it is a valid UUID",
+                result: None,
+            },
+            Example {
+                example: r#"$env.PIN | secret wrap --type string"#,
+                description: "Keep a numeric-looking value as a secret string",
+                result: None,
+            },
+            Example {
+                example: r#""8080" | secret wrap --type int"#,
+                description: "Parse a string into a secret integer",
+                result: None,
+            },
+            Example {
+                example: r#""my-api-key" | secret wrap --template "<hidden:{{secret_type}}>""#,
+                description: "Wrap a string with its own redaction template",
+                result: None,
+            },
+            Example {
+                example: r#"open tokens.csv | get token | each { $in } | secret wrap"#,
+                description: "Wrap each element of a stream lazily as its own secret",
+                result: None,
+            },
+            Example {
+                example: r#"secret wrap --from-json-file credentials.json"#,
+                description:
+                    "Wrap a JSON credentials file as a secret record without loading it first",
+                result: None,
+            },
         ]
     }
 
@@ -96,54 +716,43 @@ impl PluginCommand for SecretWrapCommand {
         call: &EvaluatedCall,
         input: PipelineData,
     ) -> Result<PipelineData, LabeledError> {
+        check_rest_requires_as_list(call)?;
+
+        if let Some(path) = call.get_flag::<String>("from-json-file")? {
+            check_json_file_conflicts(call, &input)?;
+            let wrapped = wrap_json_file(Path::new(&path), call.head)?;
+            return Ok(PipelineData::Value(wrapped, None));
+        }
+
+        let forced_type: Option<String> = call.get_flag("type")?;
+        let template: Option<String> = call.get_flag("template")?;
+        if let Some(template) = &template {
+            ConfigManager::validate_redaction_template(template).map_err(|e| {
+                LabeledError::new("Invalid template").with_label(e.to_string(), call.head)
+            })?;
+        }
+
+        let options = WrapOptions {
+            expect_format: call.get_flag("expect-format")?,
+            forced_type,
+            template,
+        };
+
+        if call.has_flag("as-list")? {
+            let wrapped = options.wrap_as_list(call.rest(0)?, call.head)?;
+            return Ok(PipelineData::Value(wrapped, None));
+        }
+
         match input {
             PipelineData::Value(value, metadata) => {
-                let wrapped_value = match value {
-                    Value::String { val, .. } => {
-                        let secret = SecretString::new(val);
-                        Value::custom(Box::new(secret), call.head)
-                    }
-                    Value::Int { val, .. } => {
-                        let secret = SecretInt::new(val);
-                        Value::custom(Box::new(secret), call.head)
-                    }
-                    Value::Bool { val, .. } => {
-                        let secret = SecretBool::new(val);
-                        Value::custom(Box::new(secret), call.head)
-                    }
-                    Value::Float { val, .. } => {
-                        let secret = SecretFloat::new(val);
-                        Value::custom(Box::new(secret), call.head)
-                    }
-                    Value::Date { val, .. } => {
-                        let secret = SecretDate::new(val);
-                        Value::custom(Box::new(secret), call.head)
-                    }
-                    Value::Binary { val, .. } => {
-                        let secret = SecretBinary::new(val);
-                        Value::custom(Box::new(secret), call.head)
-                    }
-                    Value::List { vals, .. } => {
-                        let secret = SecretList::new(vals);
-                        Value::custom(Box::new(secret), call.head)
-                    }
-                    Value::Record { val, .. } => {
-                        let secret = SecretRecord::new(val.into_owned());
-                        Value::custom(Box::new(secret), call.head)
-                    }
-                    _ => {
-                        return Err(LabeledError::new("Unsupported type")
-                            .with_label(
-                                format!(
-                                    "Cannot wrap value of type '{}'. Supported types: string, int, bool, float, date, binary, list, record",
-                                    value.get_type()
-                                ),
-                                call.head,
-                            ));
-                    }
-                };
+                let wrapped_value = options.wrap(value, call.head)?;
                 Ok(PipelineData::Value(wrapped_value, metadata))
             }
+            PipelineData::ListStream(stream, metadata) => Ok(PipelineData::ListStream(
+                wrap_stream(stream, options, call.head),
+                metadata,
+            )),
+            PipelineData::Empty => Err(nothing_error(call.head)),
             _ => Err(LabeledError::new("Invalid input")
                 .with_label("Expected a single value to wrap as a secret", call.head)),
         }
@@ -165,6 +774,422 @@ mod tests {
         let command = SecretWrapCommand;
         let signature = command.signature();
         assert_eq!(signature.name, "secret wrap");
-        assert_eq!(signature.input_output_types.len(), 8);
+        assert_eq!(signature.input_output_types.len(), 14);
+        assert!(signature.get_long_flag("as-list").is_some());
+        assert!(signature.get_long_flag("expect-format").is_some());
+        assert!(signature.get_long_flag("type").is_some());
+        assert!(signature.get_long_flag("from-json-file").is_some());
+        assert!(signature.get_long_flag("template").is_some());
+        assert!(signature.rest_positional.is_some());
+    }
+
+    #[test]
+    fn test_wrap_value_duration() {
+        let span = Span::test_data();
+        let wrapped = wrap_value(Value::duration(3_600_000_000_000, span), span).unwrap();
+        let custom = wrapped.as_custom_value().unwrap();
+        assert_eq!(custom.type_name(), "secret_duration");
+        assert_eq!(
+            custom
+                .as_any()
+                .downcast_ref::<SecretDuration>()
+                .unwrap()
+                .reveal(),
+            3_600_000_000_000
+        );
+    }
+
+    #[test]
+    fn test_wrap_value_with_template_uses_template() {
+        let span = Span::test_data();
+        let template = "<hidden:{{secret_type}}>".to_string();
+
+        let string =
+            wrap_value_with_template(Value::string("hunter2", span), template.clone(), span)
+                .unwrap();
+        let custom = string.as_custom_value().unwrap();
+        let secret = custom.as_any().downcast_ref::<SecretString>().unwrap();
+        assert_eq!(format!("{}", secret), "<hidden:string>");
+        assert_eq!(secret.reveal(), "hunter2");
+
+        let record = wrap_value_with_template(
+            Value::test_record(nu_protocol::record! { "user" => Value::test_string("admin") }),
+            template,
+            span,
+        )
+        .unwrap();
+        let base = record
+            .as_custom_value()
+            .unwrap()
+            .to_base_value(span)
+            .unwrap();
+        assert_eq!(base.as_str().unwrap(), "<hidden:record>");
+    }
+
+    #[test]
+    fn test_wrap_value_with_template_unsupported_type() {
+        let span = Span::test_data();
+        let err =
+            wrap_value_with_template(Value::glob("*.key", false, span), "<x>".to_string(), span)
+                .unwrap_err();
+        assert_eq!(err.msg, "Unsupported type");
+    }
+
+    #[test]
+    fn test_wrap_value_unsupported_type() {
+        let result = wrap_value(Value::nothing(Span::test_data()), Span::test_data());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_wrap_nothing_has_targeted_error() {
+        let span = Span::test_data();
+        let err = wrap_value(Value::nothing(span), span).unwrap_err();
+        assert_eq!(err.msg, "Cannot wrap null");
+        assert!(err.help.unwrap().contains("Check the pipeline"));
+
+        let err =
+            wrap_value_with_template(Value::nothing(span), "<x>".to_string(), span).unwrap_err();
+        assert_eq!(err.msg, "Cannot wrap null");
+
+        // Checked before the format, so the error names the real problem
+        let options = WrapOptions {
+            expect_format: Some("uuid".to_string()),
+            ..Default::default()
+        };
+        let err = options.wrap(Value::nothing(span), span).unwrap_err();
+        assert_eq!(err.msg, "Cannot wrap null");
+    }
+
+    #[test]
+    fn test_wrap_empty_values_still_supported() {
+        let span = Span::test_data();
+        let empties = [
+            (Value::string("", span), "secret_string"),
+            (Value::list(vec![], span), "secret_list"),
+            (
+                Value::record(nu_protocol::Record::new(), span),
+                "secret_record",
+            ),
+        ];
+        for (value, type_name) in empties {
+            let wrapped = WrapOptions::default().wrap(value, span).unwrap();
+            assert_eq!(wrapped.as_custom_value().unwrap().type_name(), type_name);
+        }
+    }
+
+    #[test]
+    fn test_wrap_values_as_list_three_primitives() {
+        let span = Span::test_data();
+        let values = vec![
+            Value::string("user", span),
+            Value::int(42, span),
+            Value::bool(true, span),
+        ];
+
+        let wrapped = wrap_values_as_list(values, span).unwrap();
+        let Value::Custom { val, .. } = wrapped else {
+            panic!("Expected custom value");
+        };
+        let secret_list = val
+            .as_any()
+            .downcast_ref::<SecretList>()
+            .expect("Expected SecretList");
+
+        assert_eq!(secret_list.len(), 3);
+
+        let elements = secret_list.reveal();
+        let first = elements[0].as_custom_value().unwrap();
+        assert_eq!(
+            first
+                .as_any()
+                .downcast_ref::<SecretString>()
+                .unwrap()
+                .reveal(),
+            "user"
+        );
+        let second = elements[1].as_custom_value().unwrap();
+        assert_eq!(
+            second
+                .as_any()
+                .downcast_ref::<SecretInt>()
+                .unwrap()
+                .reveal(),
+            42
+        );
+        let third = elements[2].as_custom_value().unwrap();
+        assert!(third
+            .as_any()
+            .downcast_ref::<SecretBool>()
+            .unwrap()
+            .reveal());
+    }
+
+    #[test]
+    fn test_wrap_as_list_checks_expected_format() {
+        let span = Span::test_data();
+        let options = WrapOptions {
+            expect_format: Some("uuid".to_string()),
+            ..Default::default()
+        };
+        let valid = vec![Value::test_string("550e8400-e29b-41d4-a716-446655440000")];
+        assert!(options.wrap_as_list(valid, span).is_ok());
+
+        let values = vec![
+            Value::test_string("550e8400-e29b-41d4-a716-446655440000"),
+            Value::test_string("hunter2"),
+        ];
+        let err = options.wrap_as_list(values, span).unwrap_err();
+        assert_eq!(err.msg, "Format mismatch");
+        assert!(!format!("{:?}", err).contains("hunter2"));
+    }
+
+    #[test]
+    fn test_wrap_values_as_list_rejects_unsupported_element() {
+        let span = Span::test_data();
+        let values = vec![Value::string("ok", span), Value::nothing(span)];
+        assert!(wrap_values_as_list(values, span).is_err());
+    }
+
+    #[test]
+    fn test_check_expected_format_valid_uuid() {
+        let span = Span::test_data();
+        let value = Value::string("550e8400-e29b-41d4-a716-446655440000", span);
+        assert!(check_expected_format(&value, "uuid", span).is_ok());
+    }
+
+    #[test]
+    fn test_check_expected_format_invalid_uuid_does_not_leak() {
+        let span = Span::test_data();
+        let value = Value::string("super-secret-not-a-uuid", span);
+        let err = check_expected_format(&value, "uuid", span).unwrap_err();
+        assert_eq!(err.msg, "Format mismatch");
+        assert!(!format!("{:?}", err).contains("super-secret-not-a-uuid"));
+    }
+
+    #[test]
+    fn test_check_expected_format_rejects_unknown_and_non_string() {
+        let span = Span::test_data();
+        assert!(check_expected_format(&Value::string("x", span), "nope", span).is_err());
+        assert!(check_expected_format(&Value::int(1, span), "uuid", span).is_err());
+    }
+
+    #[test]
+    fn test_coerce_numeric_string_to_int() {
+        let span = Span::test_data();
+        let coerced = coerce_value(Value::string(" 8080 ", span), "int", span).unwrap();
+        let wrapped = wrap_value(coerced, span).unwrap();
+        let custom = wrapped.as_custom_value().unwrap();
+        assert_eq!(custom.type_name(), "secret_int");
+        assert_eq!(
+            custom
+                .as_any()
+                .downcast_ref::<SecretInt>()
+                .unwrap()
+                .reveal(),
+            8080
+        );
+
+        let coerced = coerce_value(Value::int(1234, span), "string", span).unwrap();
+        assert_eq!(coerced.as_str().unwrap(), "1234");
+    }
+
+    #[test]
+    fn test_coerce_string_to_cellpath() {
+        let span = Span::test_data();
+        let coerced = coerce_value(Value::test_string("users.0.ssn?"), "cellpath", span).unwrap();
+        let members = coerced.as_cell_path().unwrap().members.clone();
+        assert_eq!(members.len(), 3);
+        assert!(
+            matches!(&members[0], PathMember::String { val, optional: false, .. } if val == "users")
+        );
+        assert!(matches!(members[1], PathMember::Int { val: 0, .. }));
+        assert!(
+            matches!(&members[2], PathMember::String { val, optional: true, .. } if val == "ssn")
+        );
+
+        let wrapped = wrap_value(coerced, span).unwrap();
+        let custom = wrapped.as_custom_value().unwrap();
+        assert!(custom.as_any().downcast_ref::<SecretCellPath>().is_some());
+
+        for invalid in ["", "users..ssn", "users.?"] {
+            let err = coerce_value(Value::test_string(invalid), "cellpath", span).unwrap_err();
+            assert_eq!(err.msg, "Incompatible type");
+        }
+        let err = coerce_value(Value::test_int(1), "cellpath", span).unwrap_err();
+        assert_eq!(err.msg, "Incompatible type");
+    }
+
+    #[test]
+    fn test_coerce_matching_type_is_noop() {
+        let span = Span::test_data();
+        let value = Value::string("1234", span);
+        assert_eq!(coerce_value(value.clone(), "string", span).unwrap(), value);
+
+        let value = Value::float(2.5, span);
+        assert_eq!(coerce_value(value.clone(), "float", span).unwrap(), value);
+    }
+
+    #[test]
+    fn test_coerce_incompatible_is_rejected_without_leaking() {
+        let span = Span::test_data();
+
+        let err = coerce_value(Value::string("hunter2", span), "int", span).unwrap_err();
+        assert_eq!(err.msg, "Incompatible type");
+        assert!(!format!("{:?}", err).contains("hunter2"));
+
+        let err = coerce_value(Value::float(1.5, span), "int", span).unwrap_err();
+        assert_eq!(err.msg, "Incompatible type");
+
+        let err = coerce_value(Value::int(1, span), "record", span).unwrap_err();
+        assert_eq!(err.msg, "Incompatible type");
+
+        let err = coerce_value(Value::int(1, span), "secret", span).unwrap_err();
+        assert_eq!(err.msg, "Invalid type");
+    }
+
+    #[test]
+    fn test_wrap_json_file_into_secret_record() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("credentials.json");
+        std::fs::write(
+            &path,
+            r#"{"user": "admin", "password": "hunter2", "port": 5432, "tags": ["a"]}"#,
+        )
+        .unwrap();
+
+        let result = wrap_json_file(&path, Span::test_data()).unwrap();
+        let custom = result.as_custom_value().unwrap();
+        let secret = custom.as_any().downcast_ref::<SecretRecord>().unwrap();
+        let record = secret.reveal();
+
+        assert_eq!(record.get("user").unwrap().as_str().unwrap(), "admin");
+        assert_eq!(record.get("password").unwrap().as_str().unwrap(), "hunter2");
+        assert_eq!(record.get("port").unwrap().as_int().unwrap(), 5432);
+        assert_eq!(record.get("tags").unwrap().as_list().unwrap().len(), 1);
+        assert!(!format!("{:?}", result).contains("hunter2"));
+    }
+
+    #[test]
+    fn test_wrap_json_file_errors_do_not_leak_content() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let span = Span::test_data();
+
+        let invalid = temp_dir.path().join("invalid.json");
+        std::fs::write(&invalid, r#"{"password": "hunter2""#).unwrap();
+        let err = wrap_json_file(&invalid, span).unwrap_err();
+        assert_eq!(err.msg, "Invalid JSON");
+        assert!(!format!("{:?}", err).contains("hunter2"));
+
+        let array = temp_dir.path().join("array.json");
+        std::fs::write(&array, r#"["hunter2"]"#).unwrap();
+        let err = wrap_json_file(&array, span).unwrap_err();
+        assert!(!format!("{:?}", err).contains("hunter2"));
+
+        let missing = temp_dir.path().join("missing.json");
+        assert_eq!(
+            wrap_json_file(&missing, span).unwrap_err().msg,
+            "Failed to read file"
+        );
+    }
+
+    fn make_call(positional: Vec<Value>, named: &[(&str, Option<Value>)]) -> EvaluatedCall {
+        EvaluatedCall {
+            head: Span::test_data(),
+            positional,
+            named: named
+                .iter()
+                .map(|(name, value)| {
+                    (
+                        nu_protocol::Spanned {
+                            item: name.to_string(),
+                            span: Span::test_data(),
+                        },
+                        value.clone(),
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_rest_values_require_as_list() {
+        let values = vec![Value::test_string("a"), Value::test_string("b")];
+        assert!(check_rest_requires_as_list(&make_call(vec![], &[])).is_ok());
+        let call = make_call(values.clone(), &[("as-list", None)]);
+        assert!(check_rest_requires_as_list(&call).is_ok());
+
+        let err = check_rest_requires_as_list(&make_call(values, &[])).unwrap_err();
+        assert_eq!(err.msg, "Unexpected arguments");
+    }
+
+    #[test]
+    fn test_json_file_conflicts() {
+        let path = Some(Value::test_string("credentials.json"));
+        let call = make_call(vec![], &[("from-json-file", path.clone())]);
+        assert!(check_json_file_conflicts(&call, &PipelineData::Empty).is_ok());
+        let nothing = PipelineData::Value(Value::test_nothing(), None);
+        assert!(check_json_file_conflicts(&call, &nothing).is_ok());
+
+        let piped = PipelineData::Value(Value::test_string("hunter2"), None);
+        let err = check_json_file_conflicts(&call, &piped).unwrap_err();
+        assert_eq!(err.msg, "Conflicting input");
+
+        for (flag, value) in [
+            ("as-list", None),
+            ("type", Some(Value::test_string("string"))),
+            ("template", Some(Value::test_string("<hidden>"))),
+            ("expect-format", Some(Value::test_string("uuid"))),
+        ] {
+            let call = make_call(vec![], &[("from-json-file", path.clone()), (flag, value)]);
+            let err = check_json_file_conflicts(&call, &PipelineData::Empty).unwrap_err();
+            assert_eq!(err.msg, "Conflicting flags");
+            assert!(format!("{:?}", err).contains(&format!("--{}", flag)));
+        }
+    }
+
+    #[test]
+    fn test_wrap_stream_wraps_each_element_lazily() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        let span = Span::test_data();
+        let pulled = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&pulled);
+        let source = (0..1_000).map(move |i| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            Value::string(format!("token-{}", i), span)
+        });
+        let stream = ListStream::new(source, span, nu_protocol::Signals::empty());
+
+        let mut wrapped = wrap_stream(stream, WrapOptions::default(), span).into_iter();
+        assert_eq!(pulled.load(Ordering::SeqCst), 0);
+
+        for i in 0..3 {
+            let value = wrapped.next().unwrap();
+            let secret = value
+                .as_custom_value()
+                .unwrap()
+                .as_any()
+                .downcast_ref::<SecretString>()
+                .expect("each element should be a SecretString");
+            assert_eq!(secret.reveal(), format!("token-{}", i));
+        }
+        // Only the consumed elements were pulled from the source
+        assert_eq!(pulled.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn test_wrap_stream_reports_unwrappable_elements() {
+        let span = Span::test_data();
+        let source = vec![Value::string("ok", span), Value::nothing(span)].into_iter();
+        let stream = ListStream::new(source, span, nu_protocol::Signals::empty());
+
+        let values: Vec<Value> = wrap_stream(stream, WrapOptions::default(), span)
+            .into_iter()
+            .collect();
+        assert_eq!(values.len(), 2);
+        assert!(values[0].as_custom_value().is_ok());
+        assert!(matches!(values[1], Value::Error { .. }));
     }
 }
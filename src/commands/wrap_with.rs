@@ -2,9 +2,11 @@
 
 use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
 use nu_protocol::{
-    Category, Example, LabeledError, PipelineData, Signature, SyntaxShape, Type, Value,
+    Category, Example, LabeledError, PipelineData, Signature, Span, SyntaxShape, Type, Value,
 };
 
+use zeroize::Zeroize;
+
 use crate::{
     SecretBinary, SecretBool, SecretDate, SecretFloat, SecretInt, SecretList, SecretRecord,
     SecretString,
@@ -13,6 +15,142 @@ use crate::{
 #[derive(Clone)]
 pub struct SecretWrapWithCommand;
 
+/// Wrap the value of environment variable `name` as a `SecretString` with `template`.
+///
+/// `value` is the variable's value as read from the engine, or `None` if unset.
+fn wrap_env_var(
+    name: &str,
+    value: Option<Value>,
+    template: String,
+    span: Span,
+) -> Result<Value, LabeledError> {
+    let value = value.ok_or_else(|| {
+        LabeledError::new("Environment variable not set")
+            .with_label(format!("'{}' is not set", name), span)
+    })?;
+    let value = value.coerce_into_string().map_err(|_| {
+        LabeledError::new("Invalid environment variable")
+            .with_label(format!("'{}' must be a string", name), span)
+    })?;
+
+    Ok(Value::custom(
+        Box::new(SecretString::new_with_template(value, template)),
+        span,
+    ))
+}
+
+/// Largest length `--generate` accepts
+const MAX_GENERATE_LENGTH: usize = 65536;
+
+/// Characters drawn from by the `alnum` generator
+const ALNUM_CHARS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+
+/// Kind and length of a random secret requested with `--generate`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GenerateSpec {
+    /// `alnum:N` — N characters from `[A-Za-z0-9]`
+    Alnum(usize),
+    /// `hex:N` — N lowercase hex digits
+    Hex(usize),
+    /// `bytes:N` — N random bytes as a secret binary
+    Bytes(usize),
+}
+
+impl std::str::FromStr for GenerateSpec {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (kind, length) = s.split_once(':').ok_or_else(|| {
+            format!(
+                "Invalid generator '{}'. Expected KIND:LENGTH, e.g. alnum:32",
+                s
+            )
+        })?;
+        let length: usize = length
+            .parse()
+            .map_err(|_| format!("Invalid length '{}'. Expected a positive integer", length))?;
+        if length == 0 || length > MAX_GENERATE_LENGTH {
+            return Err(format!(
+                "Length must be between 1 and {}, got {}",
+                MAX_GENERATE_LENGTH, length
+            ));
+        }
+
+        match kind {
+            "alnum" => Ok(GenerateSpec::Alnum(length)),
+            "hex" => Ok(GenerateSpec::Hex(length)),
+            "bytes" => Ok(GenerateSpec::Bytes(length)),
+            _ => Err(format!(
+                "Unknown generator '{}'. Valid options: alnum:N, hex:N, bytes:N",
+                kind
+            )),
+        }
+    }
+}
+
+/// Fill `buf` from the operating system's CSPRNG.
+fn fill_random(buf: &mut [u8], span: Span) -> Result<(), LabeledError> {
+    getrandom::fill(buf).map_err(|e| {
+        LabeledError::new("Random generation failed").with_label(
+            format!("Cannot read from the system random source: {}", e),
+            span,
+        )
+    })
+}
+
+/// `length` characters drawn uniformly from `ALNUM_CHARS`.
+///
+/// Random bytes at or above the largest multiple of the alphabet size are
+/// rejected, so every character is equally likely.
+fn random_alnum(length: usize, span: Span) -> Result<String, LabeledError> {
+    let limit = (256 / ALNUM_CHARS.len() * ALNUM_CHARS.len()) as u8;
+    let mut text = String::with_capacity(length);
+    let mut buf = [0u8; 64];
+    while text.len() < length {
+        fill_random(&mut buf, span)?;
+        for byte in buf.iter().filter(|byte| **byte < limit) {
+            if text.len() == length {
+                break;
+            }
+            text.push(ALNUM_CHARS[*byte as usize % ALNUM_CHARS.len()] as char);
+        }
+    }
+    buf.zeroize();
+    Ok(text)
+}
+
+/// Generate a random secret for `spec` and wrap it with `template` straight away.
+///
+/// `alnum` and `hex` produce a `SecretString`, `bytes` a `SecretBinary`.
+/// Intermediate buffers are zeroized; the value is never returned unwrapped.
+fn generate_secret(
+    spec: GenerateSpec,
+    template: String,
+    span: Span,
+) -> Result<Value, LabeledError> {
+    let secret: Box<dyn nu_protocol::CustomValue> = match spec {
+        GenerateSpec::Alnum(length) => Box::new(SecretString::new_with_template(
+            random_alnum(length, span)?,
+            template,
+        )),
+        GenerateSpec::Hex(length) => {
+            let mut bytes = vec![0u8; length.div_ceil(2)];
+            fill_random(&mut bytes, span)?;
+            let mut text = hex::encode(&bytes);
+            bytes.zeroize();
+            text.truncate(length);
+            Box::new(SecretString::new_with_template(text, template))
+        }
+        GenerateSpec::Bytes(length) => {
+            let mut bytes = vec![0u8; length];
+            fill_random(&mut bytes, span)?;
+            Box::new(SecretBinary::new_with_template(bytes, template))
+        }
+    };
+
+    Ok(Value::custom(secret, span))
+}
+
 impl PluginCommand for SecretWrapWithCommand {
     type Plugin = crate::SecretPlugin;
 
@@ -27,7 +165,21 @@ impl PluginCommand for SecretWrapWithCommand {
                 SyntaxShape::String,
                 "The redaction template to use for this secret",
             )
+            .named(
+                "from-env",
+                SyntaxShape::String,
+                "Wrap the value of this environment variable instead of piped input",
+                None,
+            )
+            .named(
+                "generate",
+                SyntaxShape::String,
+                "Generate a random secret instead of wrapping input: alnum:N, hex:N or bytes:N",
+                Some('g'),
+            )
             .input_output_types(vec![
+                (Type::Nothing, Type::Custom("secret_string".into())),
+                (Type::Nothing, Type::Custom("secret_binary".into())),
                 (Type::String, Type::Custom("secret_string".into())),
                 (Type::Int, Type::Custom("secret_int".into())),
                 (Type::Bool, Type::Custom("secret_bool".into())),
@@ -73,18 +225,67 @@ impl PluginCommand for SecretWrapWithCommand {
                 description: "Convert any environment variable to its appropriate secret type with custom template",
                 result: None,
             },
+            Example {
+                example: r#"secret wrap-with --from-env API_KEY "{{secret_type}}_redacted""#,
+                description: "Wrap an environment variable without its value appearing on the command line",
+                result: None,
+            },
+            Example {
+                example: r#"secret wrap-with --generate alnum:32 "<generated:{{secret_type}}>""#,
+                description: "Generate a random 32-character alphanumeric secret string",
+                result: None,
+            },
+            Example {
+                example: r#"secret wrap-with --generate bytes:16 "<iv>""#,
+                description: "Generate 16 random bytes as a secret binary",
+                result: None,
+            },
         ]
     }
 
     fn run(
         &self,
         _plugin: &Self::Plugin,
-        _engine: &EngineInterface,
+        engine: &EngineInterface,
         call: &EvaluatedCall,
         input: PipelineData,
     ) -> Result<PipelineData, LabeledError> {
         let template: String = call.req(0)?;
 
+        if let Some(spec) = call.get_flag::<String>("generate")? {
+            let metadata = match input {
+                PipelineData::Empty => None,
+                PipelineData::Value(Value::Nothing { .. }, metadata) => metadata,
+                _ => {
+                    return Err(LabeledError::new("Conflicting input")
+                        .with_label("--generate cannot be combined with piped input", call.head))
+                }
+            };
+            if call.get_flag::<String>("from-env")?.is_some() {
+                return Err(LabeledError::new("Conflicting flags")
+                    .with_label("--generate cannot be combined with --from-env", call.head));
+            }
+            let spec: GenerateSpec = spec.parse().map_err(|e: String| {
+                LabeledError::new("Invalid generator").with_label(e, call.head)
+            })?;
+            let wrapped_value = generate_secret(spec, template, call.head)?;
+            return Ok(PipelineData::Value(wrapped_value, metadata));
+        }
+
+        if let Some(name) = call.get_flag::<String>("from-env")? {
+            let metadata = match input {
+                PipelineData::Empty => None,
+                PipelineData::Value(Value::Nothing { .. }, metadata) => metadata,
+                _ => {
+                    return Err(LabeledError::new("Conflicting input")
+                        .with_label("--from-env cannot be combined with piped input", call.head))
+                }
+            };
+            let value = engine.get_env_var(&name)?;
+            let wrapped_value = wrap_env_var(&name, value, template, call.head)?;
+            return Ok(PipelineData::Value(wrapped_value, metadata));
+        }
+
         match input {
             PipelineData::Value(value, metadata) => {
                 let wrapped_value = match value {
@@ -164,7 +365,9 @@ mod tests {
         let command = SecretWrapWithCommand;
         let signature = command.signature();
         assert_eq!(signature.name, "secret wrap-with");
-        assert_eq!(signature.input_output_types.len(), 8);
+        assert_eq!(signature.input_output_types.len(), 10);
+        assert!(signature.get_long_flag("from-env").is_some());
+        assert!(signature.get_long_flag("generate").is_some());
         assert_eq!(signature.required_positional.len(), 1);
         assert_eq!(signature.required_positional[0].name, "template");
 
@@ -376,6 +579,47 @@ mod tests {
         }
     }
 
+    #[test]
+    #[serial_test::serial]
+    fn test_wrap_env_var() {
+        let span = Span::test_data();
+        std::env::set_var("NU_PLUGIN_SECRET_TEST_WRAP_WITH", "env-secret-value");
+        let value = std::env::var("NU_PLUGIN_SECRET_TEST_WRAP_WITH")
+            .ok()
+            .map(|value| Value::string(value, span));
+        std::env::remove_var("NU_PLUGIN_SECRET_TEST_WRAP_WITH");
+
+        let wrapped = wrap_env_var(
+            "NU_PLUGIN_SECRET_TEST_WRAP_WITH",
+            value,
+            "[HIDDEN:{{secret_type}}]".to_string(),
+            span,
+        )
+        .unwrap();
+        let secret = wrapped
+            .as_custom_value()
+            .unwrap()
+            .as_any()
+            .downcast_ref::<SecretString>()
+            .unwrap();
+
+        assert_eq!(secret.reveal(), "env-secret-value");
+        assert_eq!(format!("{}", secret), "[HIDDEN:string]");
+        assert!(!format!("{:?}", secret).contains("env-secret-value"));
+    }
+
+    #[test]
+    fn test_wrap_env_var_unset() {
+        let err = wrap_env_var(
+            "NU_PLUGIN_SECRET_TEST_UNSET",
+            None,
+            "{{secret_type}}".to_string(),
+            Span::test_data(),
+        )
+        .unwrap_err();
+        assert_eq!(err.msg, "Environment variable not set");
+    }
+
     #[test]
     fn test_secret_string_template_variable() {
         // Test that the secret_string template variable works correctly
@@ -392,4 +636,78 @@ mod tests {
         let redacted = secret.redacted_display();
         assert_eq!(redacted, "moo:test_value");
     }
+
+    fn generated_string(spec: &str) -> String {
+        let value = generate_secret(
+            spec.parse().unwrap(),
+            "<gen>".to_string(),
+            Span::test_data(),
+        )
+        .unwrap();
+        value
+            .as_custom_value()
+            .unwrap()
+            .as_any()
+            .downcast_ref::<SecretString>()
+            .unwrap()
+            .reveal()
+            .to_string()
+    }
+
+    #[test]
+    fn test_generate_spec_parsing() {
+        assert_eq!("alnum:32".parse(), Ok(GenerateSpec::Alnum(32)));
+        assert_eq!("hex:7".parse(), Ok(GenerateSpec::Hex(7)));
+        assert_eq!("bytes:16".parse(), Ok(GenerateSpec::Bytes(16)));
+
+        for invalid in [
+            "alnum",
+            "alnum:",
+            "alnum:-1",
+            "alnum:0",
+            "base32:8",
+            "bytes:65537",
+        ] {
+            assert!(
+                invalid.parse::<GenerateSpec>().is_err(),
+                "{} should be rejected",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn test_generate_alnum_and_hex_charset() {
+        let alnum = generated_string("alnum:32");
+        assert_eq!(alnum.len(), 32);
+        assert!(alnum.chars().all(|c| c.is_ascii_alphanumeric()));
+
+        for length in [1, 7, 64] {
+            let hex = generated_string(&format!("hex:{}", length));
+            assert_eq!(hex.len(), length);
+            assert!(hex
+                .chars()
+                .all(|c| c.is_ascii_hexdigit() && !c.is_ascii_uppercase()));
+        }
+    }
+
+    #[test]
+    fn test_generate_bytes_is_redacted_binary() {
+        let value = generate_secret(
+            GenerateSpec::Bytes(16),
+            "<iv>".to_string(),
+            Span::test_data(),
+        )
+        .unwrap();
+        let custom = value.as_custom_value().unwrap();
+        let secret = custom.as_any().downcast_ref::<SecretBinary>().unwrap();
+        assert_eq!(secret.reveal().len(), 16);
+        assert_eq!(format!("{}", secret), "<iv>");
+    }
+
+    #[test]
+    fn test_generations_differ() {
+        assert_ne!(generated_string("alnum:32"), generated_string("alnum:32"));
+        assert_ne!(generated_string("hex:32"), generated_string("hex:32"));
+    }
 }
@@ -30,6 +30,57 @@ pub enum ConfigError {
 
     #[error("Environment variable error: {0}")]
     Environment(String),
+
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+/// File format for exporting and importing configuration
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ConfigFormat {
+    /// TOML, the format of the plugin's own config file
+    #[default]
+    Toml,
+    /// JSON, for external config management tooling
+    Json,
+}
+
+impl ConfigFormat {
+    /// Guess the format from a file extension, defaulting to TOML
+    pub fn from_path(path: &std::path::Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("json") => ConfigFormat::Json,
+            _ => ConfigFormat::Toml,
+        }
+    }
+
+    /// Parse configuration from `content` in this format
+    pub fn parse(self, content: &str) -> Result<PluginConfig, ConfigError> {
+        match self {
+            ConfigFormat::Toml => Ok(toml::from_str(content)?),
+            ConfigFormat::Json => Ok(serde_json::from_str(content)?),
+        }
+    }
+
+    /// Serialize `value` in this format
+    pub fn serialize<T: Serialize>(self, value: &T) -> Result<String, ConfigError> {
+        match self {
+            ConfigFormat::Toml => Ok(toml::to_string_pretty(value)?),
+            ConfigFormat::Json => Ok(serde_json::to_string_pretty(value)?),
+        }
+    }
+}
+
+impl std::str::FromStr for ConfigFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "toml" => Ok(ConfigFormat::Toml),
+            "json" => Ok(ConfigFormat::Json),
+            _ => Err(format!("Unknown format '{}'. Valid options: toml, json", s)),
+        }
+    }
 }
 
 /// Context where redaction is being applied
@@ -47,7 +98,9 @@ pub enum RedactionContext {
 }
 
 /// Security levels for configuration validation
-#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+///
+/// Levels are ordered from least to most strict.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
 #[serde(rename_all = "snake_case")]
 pub enum SecurityLevel {
     /// Minimal restrictions, more user flexibility
@@ -59,6 +112,33 @@ pub enum SecurityLevel {
     Paranoid,
 }
 
+impl std::str::FromStr for SecurityLevel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "minimal" => Ok(SecurityLevel::Minimal),
+            "standard" => Ok(SecurityLevel::Standard),
+            "paranoid" => Ok(SecurityLevel::Paranoid),
+            _ => Err(format!(
+                "Unknown level '{}'. Valid options: minimal, standard, paranoid",
+                s
+            )),
+        }
+    }
+}
+
+/// Format of audit log entries
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditFormat {
+    /// One human-readable line per configuration change
+    #[default]
+    Text,
+    /// One JSON object per changed field, one object per line
+    Jsonl,
+}
+
 /// Main redaction configuration
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct RedactionConfig {
@@ -73,6 +153,10 @@ pub struct RedactionConfig {
     /// Available variables: secret_type, secret_length
     #[serde(default)]
     pub redaction_template: Option<String>,
+    /// Secret types displayed unredacted even when `show_unredacted` is off
+    /// Example: ["int"] or ["secret_int"] reveals only secret integers
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub unredacted_types: Vec<String>,
 }
 
 impl RedactionConfig {
@@ -91,20 +175,40 @@ pub struct SecurityConfig {
     /// Security level for validation
     #[serde(default)]
     pub level: SecurityLevel,
+    /// Lowest security level that `level` may be set to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub minimum_level: Option<SecurityLevel>,
     /// Whether to audit configuration changes
     #[serde(default = "default_true")]
     pub audit_config_changes: bool,
+    /// Whether the audit log records custom redaction text as its length only
+    #[serde(default = "default_true")]
+    pub audit_mask_custom_text: bool,
     /// Maximum custom redaction text length
     #[serde(default = "default_max_custom_text_length")]
     pub max_custom_text_length: usize,
+    /// Custom audit log file (defaults to `audit.log` in the config directory)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub audit_log_path: Option<PathBuf>,
+    /// Format of audit log entries
+    #[serde(default)]
+    pub audit_format: AuditFormat,
+    /// Template functions (or `secret_string`) that redaction templates may not use
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub disabled_template_functions: Vec<String>,
 }
 
 impl Default for SecurityConfig {
     fn default() -> Self {
         Self {
             level: SecurityLevel::default(),
+            minimum_level: None,
             audit_config_changes: true,
+            audit_mask_custom_text: true,
             max_custom_text_length: 50,
+            audit_log_path: None,
+            audit_format: AuditFormat::default(),
+            disabled_template_functions: Vec::new(),
         }
     }
 }
@@ -127,6 +231,21 @@ fn default_config_version() -> String {
     "1.0".to_string()
 }
 
+/// Return a warning message when a config file's `version` does not match the
+/// version this plugin expects, or `None` when no warning is needed.
+pub fn config_version_warning(version: &str) -> Option<String> {
+    let expected = default_config_version();
+    if version == expected {
+        return None;
+    }
+
+    Some(format!(
+        "Warning: nu_plugin_secret config version '{}' differs from the expected version '{}'; \
+         some settings may be ignored. Run `secret config migrate` to update the file",
+        version, expected
+    ))
+}
+
 // Helper functions for default values
 fn default_true() -> bool {
     true
@@ -231,8 +350,36 @@ impl ConfigManager {
         &mut self.config
     }
 
+    /// Replace the active configuration and refresh the settings applied at render time
+    ///
+    /// Every command that swaps in a whole new configuration goes through here so
+    /// the redaction globals never lag behind the stored config.
+    pub fn replace_config(&mut self, config: PluginConfig) {
+        self.config = config;
+        self.apply_runtime_settings();
+    }
+
+    /// Push the settings read while rendering secrets into the redaction module
+    pub fn apply_runtime_settings(&self) {
+        crate::redaction::set_disabled_template_functions(
+            &self.config.security.disabled_template_functions,
+        );
+        crate::redaction::set_unredacted_types(&self.config.redaction.unredacted_types);
+        crate::redaction::set_redaction_template(
+            self.config.redaction.redaction_template.as_deref(),
+        );
+    }
+
     /// Load configuration from a specific path
     pub fn load_from_path(path: &std::path::Path) -> Result<Self, ConfigError> {
+        Self::load_from_path_as(path, ConfigFormat::Toml)
+    }
+
+    /// Load configuration in the given format from a specific path
+    pub fn load_from_path_as(
+        path: &std::path::Path,
+        format: ConfigFormat,
+    ) -> Result<Self, ConfigError> {
         if !path.exists() {
             return Err(ConfigError::Io(std::io::Error::new(
                 std::io::ErrorKind::NotFound,
@@ -241,7 +388,7 @@ impl ConfigManager {
         }
 
         let content = std::fs::read_to_string(path)?;
-        let config: PluginConfig = toml::from_str(&content)?;
+        let config = format.parse(&content)?;
 
         // Validate the loaded configuration
         Self::validate_config(&config)?;
@@ -254,20 +401,47 @@ impl ConfigManager {
 
     /// Save configuration to a specific path
     pub fn save_to_path(&self, path: &std::path::Path) -> Result<(), ConfigError> {
+        self.save_to_path_as(path, ConfigFormat::Toml)
+    }
+
+    /// Save configuration in the given format to a specific path
+    pub fn save_to_path_as(
+        &self,
+        path: &std::path::Path,
+        format: ConfigFormat,
+    ) -> Result<(), ConfigError> {
         // Create parent directories
         if let Some(parent) = path.parent() {
             std::fs::create_dir_all(parent)?;
         }
 
-        let content = toml::to_string_pretty(&self.config)?;
+        let content = format.serialize(&self.config)?;
         std::fs::write(path, content)?;
         Ok(())
     }
 
+    /// Environment variables read by `apply_env_overrides`
+    pub const ENV_OVERRIDE_VARS: &'static [&'static str] =
+        &["NU_PLUGIN_SECRET_SECURITY_LEVEL", "SHOW_UNREDACTED"];
+
     /// Apply environment variable overrides
     pub fn apply_env_overrides(config: &mut PluginConfig) -> Result<(), ConfigError> {
+        Self::apply_env_overrides_from(config, |name| std::env::var(name).ok())
+    }
+
+    /// Apply environment variable overrides, reading variables through `lookup`
+    ///
+    /// This lets callers resolve variables from somewhere other than the process
+    /// environment, such as the Nushell engine's `$env`.
+    pub fn apply_env_overrides_from<F>(
+        config: &mut PluginConfig,
+        lookup: F,
+    ) -> Result<(), ConfigError>
+    where
+        F: Fn(&str) -> Option<String>,
+    {
         // Security level override
-        if let Ok(security_level) = std::env::var("NU_PLUGIN_SECRET_SECURITY_LEVEL") {
+        if let Some(security_level) = lookup("NU_PLUGIN_SECRET_SECURITY_LEVEL") {
             config.security.level = match security_level.as_str() {
                 "minimal" => SecurityLevel::Minimal,
                 "standard" => SecurityLevel::Standard,
@@ -281,7 +455,7 @@ impl ConfigManager {
         }
 
         // Show unredacted override
-        if let Ok(show_unredacted) = std::env::var("SHOW_UNREDACTED") {
+        if let Some(show_unredacted) = lookup("SHOW_UNREDACTED") {
             config.redaction.show_unredacted = match show_unredacted.as_str() {
                 "1" | "true" | "True" | "TRUE" => true,
                 "0" | "false" | "False" | "FALSE" => false,
@@ -293,6 +467,14 @@ impl ConfigManager {
             };
         }
 
+        // Re-check the overridden settings so the environment cannot disable
+        // redaction under the paranoid level
+        if config.security.level == SecurityLevel::Paranoid && config.redaction.show_unredacted {
+            return Err(ConfigError::Security(
+                "SHOW_UNREDACTED cannot be enabled at the paranoid security level".to_string(),
+            ));
+        }
+
         Ok(())
     }
 
@@ -306,23 +488,89 @@ impl ConfigManager {
         // Enhanced security validation based on security level
         Self::validate_security_level_constraints(config)?;
 
+        // Validate custom audit log location if present
+        if let Some(ref path) = config.security.audit_log_path {
+            Self::validate_audit_log_path(path)?;
+        }
+
+        // Only known template functions can be disabled
+        for name in &config.security.disabled_template_functions {
+            if !crate::tera_functions::DISABLEABLE_TEMPLATE_FUNCTIONS.contains(&name.as_str()) {
+                return Err(ConfigError::Invalid(format!(
+                    "Unknown template function '{}' in disabled_template_functions. Valid options: {}",
+                    name,
+                    crate::tera_functions::DISABLEABLE_TEMPLATE_FUNCTIONS.join(", ")
+                )));
+            }
+        }
+
+        // Only secret types that can render their value can be unredacted
+        for name in &config.redaction.unredacted_types {
+            let short_name = name.strip_prefix("secret_").unwrap_or(name);
+            if !crate::redaction::UNREDACTABLE_TYPES.contains(&short_name) {
+                return Err(ConfigError::Invalid(format!(
+                    "Unknown or unsupported secret type '{}' in unredacted_types. Valid options: {}",
+                    name,
+                    crate::redaction::UNREDACTABLE_TYPES.join(", ")
+                )));
+            }
+        }
+
         Ok(())
     }
 
+    /// Validate that a custom audit log path points to a file in a writable directory
+    fn validate_audit_log_path(path: &std::path::Path) -> Result<(), ConfigError> {
+        if path.is_dir() {
+            return Err(ConfigError::Invalid(format!(
+                "Audit log path '{}' is a directory, expected a file",
+                path.display()
+            )));
+        }
+
+        let parent = match path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent,
+            _ => {
+                return Err(ConfigError::Invalid(format!(
+                    "Audit log path '{}' must include a parent directory",
+                    path.display()
+                )))
+            }
+        };
+
+        match std::fs::metadata(parent) {
+            Ok(metadata) if metadata.is_dir() && !metadata.permissions().readonly() => Ok(()),
+            Ok(_) => Err(ConfigError::Invalid(format!(
+                "Audit log directory '{}' is not a writable directory",
+                parent.display()
+            ))),
+            Err(e) => Err(ConfigError::Invalid(format!(
+                "Audit log directory '{}' is not accessible: {}",
+                parent.display(),
+                e
+            ))),
+        }
+    }
+
     /// Validate redaction template syntax and content
-    fn validate_redaction_template(template: &str) -> Result<(), ConfigError> {
+    pub(crate) fn validate_redaction_template(template: &str) -> Result<(), ConfigError> {
         // Validate Tera template syntax by attempting to compile it
         let mut tera = tera::Tera::default();
 
         // Register all standard template functions for validation
         crate::tera_functions::register_all_standard_functions(&mut tera);
+        crate::redaction::register_reveal_chars_function(&mut tera, Some("test_secret"));
 
         // Note: secret_string is available as a template variable during validation
 
         if let Err(e) = tera.add_raw_template("validation", template) {
+            // Tera reports the parse details in the error source
+            let detail = std::error::Error::source(&e)
+                .map(|source| source.to_string())
+                .unwrap_or_else(|| e.to_string());
             return Err(ConfigError::Invalid(format!(
                 "Invalid Tera template syntax: {}",
-                e
+                detail
             )));
         }
 
@@ -330,6 +578,7 @@ impl ConfigManager {
         let mut context = tera::Context::new();
         context.insert("secret_type", "string");
         context.insert("secret_length", &10usize);
+        context.insert("secret_char_length", &10usize);
         context.insert("secret_string", "test_secret");
         if let Err(e) = tera.render("validation", &context) {
             return Err(ConfigError::Invalid(format!(
@@ -343,34 +592,138 @@ impl ConfigManager {
 
     /// Validate security level constraints
     fn validate_security_level_constraints(config: &PluginConfig) -> Result<(), ConfigError> {
-        match config.security.level {
-            SecurityLevel::Minimal => {
-                // Minimal security allows most configurations, but still has basic limits
+        match security_level_violations(config).into_iter().next() {
+            Some(violation) => Err(ConfigError::Security(violation.reason)),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Reject a change that lowers or removes an existing `security.minimum_level`.
+///
+/// Commands that replace the whole configuration call this unless the user
+/// passes `--allow-lower-minimum`, so the floor cannot be bypassed by editing it.
+pub fn check_minimum_level_change(
+    old_config: &PluginConfig,
+    new_config: &PluginConfig,
+) -> Result<(), ConfigError> {
+    let Some(old_minimum) = &old_config.security.minimum_level else {
+        return Ok(());
+    };
+
+    let action = match &new_config.security.minimum_level {
+        Some(new_minimum) if new_minimum >= old_minimum => return Ok(()),
+        Some(_) => "lower",
+        None => "remove",
+    };
+
+    let minimum = format!("{:?}", old_minimum).to_lowercase();
+    Err(ConfigError::Security(format!(
+        "Refusing to {} the enforced minimum security level '{}'. Use --allow-lower-minimum to override",
+        action, minimum
+    )))
+}
+
+/// A setting that must change for a config to satisfy its security level
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LevelViolation {
+    /// Dotted path of the offending setting (e.g. `security.audit_config_changes`)
+    pub field: &'static str,
+    /// Current value of the setting
+    pub current: String,
+    /// Value the security level requires
+    pub required: String,
+    /// Human-readable explanation
+    pub reason: String,
+}
+
+/// Collect every setting that conflicts with the config's security level.
+///
+/// Unlike `validate_config`, which stops at the first problem, this reports all
+/// of them so callers can preview the full impact of a level change.
+pub fn security_level_violations(config: &PluginConfig) -> Vec<LevelViolation> {
+    let mut violations = Vec::new();
+
+    if let Some(minimum) = &config.security.minimum_level {
+        if config.security.level < *minimum {
+            let current = format!("{:?}", config.security.level).to_lowercase();
+            let required = format!("{:?}", minimum).to_lowercase();
+            violations.push(LevelViolation {
+                field: "security.level",
+                reason: format!(
+                    "Security level '{}' is below the enforced minimum '{}'",
+                    current, required
+                ),
+                current,
+                required,
+            });
+        }
+    }
+
+    match config.security.level {
+        SecurityLevel::Minimal => {
+            // Minimal security allows most configurations, but still has basic limits
+        }
+        SecurityLevel::Standard => {
+            // Standard security requires audit logging by default
+            if !config.security.audit_config_changes {
+                violations.push(LevelViolation {
+                    field: "security.audit_config_changes",
+                    current: "false".to_string(),
+                    required: "true".to_string(),
+                    reason: "Standard security level requires audit logging to be enabled"
+                        .to_string(),
+                });
             }
-            SecurityLevel::Standard => {
-                // Standard security requires audit logging by default
-                if !config.security.audit_config_changes {
-                    return Err(ConfigError::Security(
-                        "Standard security level requires audit logging to be enabled".to_string(),
-                    ));
-                }
+        }
+        SecurityLevel::Paranoid => {
+            // Paranoid security has strict requirements
+            if !config.security.audit_config_changes {
+                violations.push(LevelViolation {
+                    field: "security.audit_config_changes",
+                    current: "false".to_string(),
+                    required: "true".to_string(),
+                    reason: "Paranoid security level requires audit logging to be enabled"
+                        .to_string(),
+                });
             }
-            SecurityLevel::Paranoid => {
-                // Paranoid security has strict requirements
-                if !config.security.audit_config_changes {
-                    return Err(ConfigError::Security(
-                        "Paranoid security level requires audit logging to be enabled".to_string(),
-                    ));
-                }
+            if config.redaction.show_unredacted {
+                violations.push(LevelViolation {
+                    field: "redaction.show_unredacted",
+                    current: "true".to_string(),
+                    required: "false".to_string(),
+                    reason: "Paranoid security level forbids displaying secrets unredacted"
+                        .to_string(),
+                });
+            }
+            if !config.redaction.unredacted_types.is_empty() {
+                violations.push(LevelViolation {
+                    field: "redaction.unredacted_types",
+                    current: format!("{:?}", config.redaction.unredacted_types),
+                    required: "[]".to_string(),
+                    reason: "Paranoid security level forbids displaying any secret type unredacted"
+                        .to_string(),
+                });
             }
         }
-
-        Ok(())
     }
+
+    violations
 }
 
+/// Environment variable that points the plugin at a specific configuration file
+pub const CONFIG_PATH_ENV_VAR: &str = "NU_PLUGIN_SECRET_CONFIG_PATH";
+
 /// Get the configuration file path
+///
+/// `NU_PLUGIN_SECRET_CONFIG_PATH` is used verbatim when set and non-empty;
+/// otherwise the file lives under the user's config directory. Backups and the
+/// default audit log are placed next to whichever file is used.
 pub fn get_config_file_path() -> Option<PathBuf> {
+    if let Some(path) = std::env::var_os(CONFIG_PATH_ENV_VAR).filter(|p| !p.is_empty()) {
+        return Some(PathBuf::from(path));
+    }
+
     dirs::config_dir().map(|config| {
         config
             .join("nushell")
@@ -380,58 +733,236 @@ pub fn get_config_file_path() -> Option<PathBuf> {
     })
 }
 
-/// Collect the list of human-readable change descriptions between two configs.
-fn collect_config_changes(old_config: &PluginConfig, new_config: &PluginConfig) -> Vec<String> {
+/// Get the directory holding named configuration backups
+pub fn get_backup_dir() -> Option<PathBuf> {
+    get_config_file_path().and_then(|p| p.parent().map(|p| p.join("backups")))
+}
+
+/// Get the audit log file for `config`.
+///
+/// Uses `security.audit_log_path` when set, otherwise `audit.log` next to the
+/// configuration file.
+pub fn get_audit_log_path(config: &PluginConfig) -> Option<PathBuf> {
+    if let Some(path) = &config.security.audit_log_path {
+        return Some(path.clone());
+    }
+    get_config_file_path().and_then(|p| p.parent().map(|p| p.join("audit.log")))
+}
+
+/// Resolve the file path of a named backup inside `backup_dir`.
+///
+/// Names are restricted to ASCII letters, digits, `-` and `_` so a backup can
+/// never escape the backup directory.
+pub fn named_backup_path(backup_dir: &std::path::Path, name: &str) -> Result<PathBuf, ConfigError> {
+    if name.is_empty()
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(ConfigError::Invalid(format!(
+            "Invalid backup name '{}'. Use only letters, digits, '-' and '_'",
+            name
+        )));
+    }
+
+    Ok(backup_dir.join(format!("{}.toml", name)))
+}
+
+/// A single setting that differs between two configs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ConfigChange {
+    /// Dotted path of the setting (e.g. `security.level`)
+    pub field: &'static str,
+    /// Previous value, formatted for the audit log
+    pub old: String,
+    /// New value, formatted for the audit log
+    pub new: String,
+}
+
+impl std::fmt::Display for ConfigChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {} -> {}", self.field, self.old, self.new)
+    }
+}
+
+/// Collect the settings that differ between two configs.
+pub(crate) fn config_field_changes(
+    old_config: &PluginConfig,
+    new_config: &PluginConfig,
+) -> Vec<ConfigChange> {
     let mut changes = Vec::new();
+    let mut push = |field: &'static str, old: String, new: String| {
+        changes.push(ConfigChange { field, old, new });
+    };
+
+    // Mask custom text if either side asks for it, so switching masking off
+    // does not leak the text in the same change
+    let mask_custom_text =
+        old_config.security.audit_mask_custom_text || new_config.security.audit_mask_custom_text;
+
+    // Track settings that control whether secret content is displayed
+    if old_config.redaction.show_unredacted != new_config.redaction.show_unredacted {
+        push(
+            "redaction.show_unredacted",
+            old_config.redaction.show_unredacted.to_string(),
+            new_config.redaction.show_unredacted.to_string(),
+        );
+    }
+    if old_config.redaction.unredacted_types != new_config.redaction.unredacted_types {
+        push(
+            "redaction.unredacted_types",
+            format!("{:?}", old_config.redaction.unredacted_types),
+            format!("{:?}", new_config.redaction.unredacted_types),
+        );
+    }
+    if old_config.redaction.mask_secret != new_config.redaction.mask_secret {
+        push(
+            "redaction.mask_secret",
+            old_config.redaction.mask_secret.to_string(),
+            new_config.redaction.mask_secret.to_string(),
+        );
+    }
 
     // Track redaction template changes
     if old_config.redaction.redaction_template != new_config.redaction.redaction_template {
-        changes.push(format!(
-            "redaction.redaction_template: {:?} -> {:?}",
-            old_config.redaction.redaction_template, new_config.redaction.redaction_template
-        ));
+        push(
+            "redaction.redaction_template",
+            audit_custom_text(&old_config.redaction.redaction_template, mask_custom_text),
+            audit_custom_text(&new_config.redaction.redaction_template, mask_custom_text),
+        );
     }
 
     // Track security level changes
     if old_config.security.level != new_config.security.level {
-        changes.push(format!(
-            "security.level: {:?} -> {:?}",
-            old_config.security.level, new_config.security.level
-        ));
+        push(
+            "security.level",
+            format!("{:?}", old_config.security.level),
+            format!("{:?}", new_config.security.level),
+        );
+    }
+
+    if old_config.security.minimum_level != new_config.security.minimum_level {
+        push(
+            "security.minimum_level",
+            format!("{:?}", old_config.security.minimum_level),
+            format!("{:?}", new_config.security.minimum_level),
+        );
     }
 
     // Track audit setting changes (important for security)
     if old_config.security.audit_config_changes != new_config.security.audit_config_changes {
-        changes.push(format!(
-            "security.audit_config_changes: {} -> {}",
-            old_config.security.audit_config_changes, new_config.security.audit_config_changes
-        ));
+        push(
+            "security.audit_config_changes",
+            old_config.security.audit_config_changes.to_string(),
+            new_config.security.audit_config_changes.to_string(),
+        );
+    }
+    if old_config.security.audit_mask_custom_text != new_config.security.audit_mask_custom_text {
+        push(
+            "security.audit_mask_custom_text",
+            old_config.security.audit_mask_custom_text.to_string(),
+            new_config.security.audit_mask_custom_text.to_string(),
+        );
+    }
+    if old_config.security.disabled_template_functions
+        != new_config.security.disabled_template_functions
+    {
+        push(
+            "security.disabled_template_functions",
+            format!("{:?}", old_config.security.disabled_template_functions),
+            format!("{:?}", new_config.security.disabled_template_functions),
+        );
+    }
+    if old_config.security.audit_log_path != new_config.security.audit_log_path {
+        push(
+            "security.audit_log_path",
+            format!("{:?}", old_config.security.audit_log_path),
+            format!("{:?}", new_config.security.audit_log_path),
+        );
+    }
+    if old_config.security.audit_format != new_config.security.audit_format {
+        push(
+            "security.audit_format",
+            format!("{:?}", old_config.security.audit_format),
+            format!("{:?}", new_config.security.audit_format),
+        );
     }
 
     changes
 }
 
-/// Write a timestamped audit log entry for the given changes.
+/// Collect the list of human-readable change descriptions between two configs.
+pub(crate) fn collect_config_changes(
+    old_config: &PluginConfig,
+    new_config: &PluginConfig,
+) -> Vec<String> {
+    config_field_changes(old_config, new_config)
+        .iter()
+        .map(ToString::to_string)
+        .collect()
+}
+
+/// Format an audit log entry for `changes` made at `timestamp`.
+///
+/// `Text` produces a single line listing every change; `Jsonl` produces one
+/// `{timestamp, field, old, new}` object per change, each on its own line.
+fn format_audit_entry(
+    format: AuditFormat,
+    timestamp: chrono::DateTime<chrono::Utc>,
+    changes: &[ConfigChange],
+) -> String {
+    match format {
+        AuditFormat::Text => format!(
+            "[{}] Configuration changed: {}\n",
+            timestamp.format("%Y-%m-%d %H:%M:%S UTC"),
+            changes
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        AuditFormat::Jsonl => {
+            let timestamp = timestamp.to_rfc3339();
+            changes
+                .iter()
+                .map(|change| {
+                    let entry = serde_json::json!({
+                        "timestamp": timestamp,
+                        "field": change.field,
+                        "old": change.old,
+                        "new": change.new,
+                    });
+                    format!("{}\n", entry)
+                })
+                .collect()
+        }
+    }
+}
+
+/// Format custom redaction text for the audit log.
+///
+/// When `mask` is set, the text is replaced by its length, e.g. `Custom(12 chars)`.
+fn audit_custom_text(text: &Option<String>, mask: bool) -> String {
+    match text {
+        Some(text) if mask => format!("Custom({} chars)", text.chars().count()),
+        _ => format!("{:?}", text),
+    }
+}
+
+/// Append a formatted audit log entry to `audit_file`.
 ///
 /// Silently prints warnings to stderr and returns `Ok(())` if the audit log
 /// file cannot be written, to avoid blocking the caller.
-fn write_audit_log_entry(changes: &[String]) -> Result<(), ConfigError> {
+fn write_audit_log_entry(audit_file: Option<PathBuf>, log_entry: &str) -> Result<(), ConfigError> {
     use std::io::Write;
 
-    let timestamp = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC");
-
-    if let Some(config_dir) =
-        get_config_file_path().and_then(|p| p.parent().map(|p| p.to_path_buf()))
-    {
-        let audit_file = config_dir.join("audit.log");
-
+    if let Some(audit_file) = audit_file {
         // Create directory if it doesn't exist
-        if let Err(e) = std::fs::create_dir_all(&config_dir) {
-            eprintln!(
-                "Warning: Failed to create config directory for audit log: {}",
-                e
-            );
-            return Ok(());
+        if let Some(audit_dir) = audit_file.parent() {
+            if let Err(e) = std::fs::create_dir_all(audit_dir) {
+                eprintln!("Warning: Failed to create directory for audit log: {}", e);
+                return Ok(());
+            }
         }
 
         // Append to audit log
@@ -447,11 +978,6 @@ fn write_audit_log_entry(changes: &[String]) -> Result<(), ConfigError> {
             }
         };
 
-        let log_entry = format!(
-            "[{}] Configuration changed: {}\n",
-            timestamp,
-            changes.join(", ")
-        );
         if let Err(e) = file.write_all(log_entry.as_bytes()) {
             eprintln!("Warning: Failed to write to audit log: {}", e);
         }
@@ -461,6 +987,10 @@ fn write_audit_log_entry(changes: &[String]) -> Result<(), ConfigError> {
 }
 
 /// Log configuration changes for audit purposes
+///
+/// Entries are written to the audit log of `old_config` in its `audit_format`,
+/// so redirecting the audit log (or switching its format) is itself recorded in
+/// the previous location and format.
 pub fn audit_config_change(
     old_config: &PluginConfig,
     new_config: &PluginConfig,
@@ -470,10 +1000,15 @@ pub fn audit_config_change(
         return Ok(());
     }
 
-    let changes = collect_config_changes(old_config, new_config);
+    let changes = config_field_changes(old_config, new_config);
 
     if !changes.is_empty() {
-        write_audit_log_entry(&changes)?;
+        let log_entry = format_audit_entry(
+            old_config.security.audit_format,
+            chrono::Utc::now(),
+            &changes,
+        );
+        write_audit_log_entry(get_audit_log_path(old_config), &log_entry)?;
     }
 
     Ok(())
@@ -569,6 +1104,174 @@ mod tests {
         env::remove_var("SHOW_UNREDACTED");
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    #[cfg_attr(not(miri), serial(env))]
+    fn test_config_path_env_override() {
+        use std::env;
+
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("profile").join("custom.toml");
+        env::set_var(CONFIG_PATH_ENV_VAR, &config_path);
+
+        assert_eq!(get_config_file_path(), Some(config_path.clone()));
+        assert_eq!(
+            get_audit_log_path(&PluginConfig::default()),
+            Some(temp_dir.path().join("profile").join("audit.log"))
+        );
+
+        // Saving writes to the override, and loading reads it back
+        let mut manager = ConfigManager::load().unwrap();
+        manager.config_mut().redaction.mask_secret = true;
+        manager.save().unwrap();
+        assert!(config_path.exists());
+
+        let reloaded = ConfigManager::load().unwrap();
+        assert!(reloaded.config().redaction.mask_secret);
+
+        env::remove_var(CONFIG_PATH_ENV_VAR);
+        assert_ne!(get_config_file_path(), Some(config_path));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    #[cfg_attr(not(miri), serial(env))]
+    fn test_config_path_env_override_empty_is_ignored() {
+        use std::env;
+
+        env::set_var(CONFIG_PATH_ENV_VAR, "");
+        let path = get_config_file_path();
+        env::remove_var(CONFIG_PATH_ENV_VAR);
+
+        if let Some(path) = path {
+            assert!(path.ends_with("nushell/plugins/secret/config.toml"));
+        }
+    }
+
+    #[test]
+    fn test_paranoid_rejects_show_unredacted_in_file_config() {
+        let config: PluginConfig = toml::from_str(
+            r#"
+[redaction]
+show_unredacted = true
+
+[security]
+level = "paranoid"
+"#,
+        )
+        .unwrap();
+
+        let err = ConfigManager::validate_config(&config).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Paranoid security level forbids displaying secrets unredacted"));
+
+        let violations = security_level_violations(&config);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].field, "redaction.show_unredacted");
+    }
+
+    #[test]
+    fn test_paranoid_rejects_unredacted_types() {
+        let config: PluginConfig = toml::from_str(
+            r#"
+[redaction]
+unredacted_types = ["int", "string"]
+
+[security]
+level = "paranoid"
+"#,
+        )
+        .unwrap();
+
+        let err = ConfigManager::validate_config(&config).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Paranoid security level forbids displaying any secret type unredacted"));
+
+        let violations = security_level_violations(&config);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].field, "redaction.unredacted_types");
+
+        // The allowlist stays available below paranoid
+        let mut standard = config.clone();
+        standard.security.level = SecurityLevel::Standard;
+        assert!(security_level_violations(&standard).is_empty());
+    }
+
+    #[test]
+    fn test_unredacted_types_names_are_validated() {
+        let mut config = PluginConfig::default();
+        config.redaction.unredacted_types = vec!["int".to_string(), "secret_string".to_string()];
+        assert!(ConfigManager::validate_config(&config).is_ok());
+
+        for name in ["itn", "record", "secret_list", "binary", "cellpath"] {
+            config.redaction.unredacted_types = vec![name.to_string()];
+            let err = ConfigManager::validate_config(&config).unwrap_err();
+            assert!(matches!(err, ConfigError::Invalid(_)));
+            assert!(err.to_string().contains(&format!("'{}'", name)));
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_paranoid_file_with_unredacted_types_fails_to_load() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("config.toml");
+        fs::write(
+            &path,
+            r#"
+[redaction]
+unredacted_types = ["int"]
+
+[security]
+level = "paranoid"
+"#,
+        )
+        .unwrap();
+
+        let err = ConfigManager::load_from_path(&path).unwrap_err();
+        assert!(matches!(err, ConfigError::Security(_)));
+    }
+
+    #[test]
+    fn test_paranoid_rejects_show_unredacted_env_override() {
+        fn lookup<'a>(vars: &'a [(&str, &str)]) -> impl Fn(&str) -> Option<String> + 'a {
+            move |name| {
+                vars.iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| value.to_string())
+            }
+        }
+
+        // Env turning on SHOW_UNREDACTED under a paranoid file config
+        let mut config = PluginConfig::default();
+        config.security.level = SecurityLevel::Paranoid;
+        let result = ConfigManager::apply_env_overrides_from(
+            &mut config,
+            lookup(&[("SHOW_UNREDACTED", "1")]),
+        );
+        assert!(matches!(result, Err(ConfigError::Security(_))));
+
+        // Env raising the level while the file config shows secrets unredacted
+        let mut config = PluginConfig::default();
+        config.redaction.show_unredacted = true;
+        let result = ConfigManager::apply_env_overrides_from(
+            &mut config,
+            lookup(&[("NU_PLUGIN_SECRET_SECURITY_LEVEL", "paranoid")]),
+        );
+        assert!(matches!(result, Err(ConfigError::Security(_))));
+
+        // Other levels still accept the override
+        let mut config = PluginConfig::default();
+        let result = ConfigManager::apply_env_overrides_from(
+            &mut config,
+            lookup(&[("SHOW_UNREDACTED", "1")]),
+        );
+        assert!(result.is_ok());
+        assert!(config.redaction.show_unredacted);
+    }
+
     #[test]
     fn test_show_unredacted_default_value() {
         let config = PluginConfig::default();
@@ -788,6 +1491,11 @@ level = "minimal"
         let custom_template = "[HIDDEN:{{secret_type}}]";
         let result = ConfigManager::validate_redaction_template(custom_template);
         assert!(result.is_ok());
+
+        // Test template using the value-bound reveal_chars function
+        let chars_template = "<{{secret_type}}: {{reveal_chars()}} chars>";
+        let result = ConfigManager::validate_redaction_template(chars_template);
+        assert!(result.is_ok());
     }
 
     #[test]
@@ -865,6 +1573,292 @@ max_custom_text_length = 50
         assert_eq!(config.security.level, SecurityLevel::Standard);
     }
 
+    #[test]
+    fn test_security_level_from_str() {
+        assert_eq!(
+            "paranoid".parse::<SecurityLevel>(),
+            Ok(SecurityLevel::Paranoid)
+        );
+        assert!("extreme".parse::<SecurityLevel>().is_err());
+    }
+
+    #[test]
+    fn test_security_level_violations_collects_all() {
+        let mut config = PluginConfig::default();
+        assert!(security_level_violations(&config).is_empty());
+
+        config.security.audit_config_changes = false;
+        let violations = security_level_violations(&config);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].field, "security.audit_config_changes");
+        assert_eq!(violations[0].required, "true");
+
+        config.security.level = SecurityLevel::Minimal;
+        assert!(security_level_violations(&config).is_empty());
+    }
+
+    #[test]
+    fn test_minimum_security_level() {
+        let mut config = PluginConfig::default();
+        config.security.minimum_level = Some(SecurityLevel::Standard);
+
+        config.security.level = SecurityLevel::Minimal;
+        let err = ConfigManager::validate_config(&config).unwrap_err();
+        assert!(err.to_string().contains("below the enforced minimum"));
+
+        config.security.level = SecurityLevel::Paranoid;
+        assert!(ConfigManager::validate_config(&config).is_ok());
+
+        config.security.level = SecurityLevel::Standard;
+        assert!(ConfigManager::validate_config(&config).is_ok());
+    }
+
+    #[test]
+    fn test_minimum_level_cannot_be_lowered_or_removed() {
+        let mut old_config = PluginConfig::default();
+        old_config.security.minimum_level = Some(SecurityLevel::Standard);
+
+        let mut new_config = old_config.clone();
+        assert!(check_minimum_level_change(&old_config, &new_config).is_ok());
+
+        new_config.security.minimum_level = Some(SecurityLevel::Paranoid);
+        assert!(check_minimum_level_change(&old_config, &new_config).is_ok());
+
+        new_config.security.minimum_level = Some(SecurityLevel::Minimal);
+        let err = check_minimum_level_change(&old_config, &new_config).unwrap_err();
+        assert!(matches!(err, ConfigError::Security(_)));
+        assert!(err.to_string().contains("lower"));
+
+        new_config.security.minimum_level = None;
+        let err = check_minimum_level_change(&old_config, &new_config).unwrap_err();
+        assert!(err.to_string().contains("remove"));
+
+        // Nothing to protect when no minimum was set
+        assert!(check_minimum_level_change(&PluginConfig::default(), &new_config).is_ok());
+    }
+
+    #[test]
+    fn test_named_backup_path() {
+        let dir = std::path::Path::new("/tmp/backups");
+        assert_eq!(
+            named_backup_path(dir, "before-upgrade_1").unwrap(),
+            dir.join("before-upgrade_1.toml")
+        );
+
+        for name in ["", "../escape", "a/b", "with space", "dot.name"] {
+            assert!(
+                named_backup_path(dir, name).is_err(),
+                "{} should fail",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn test_config_version_warning() {
+        assert!(config_version_warning(&default_config_version()).is_none());
+
+        let config: PluginConfig = toml::from_str(
+            r#"
+version = "0.9"
+"#,
+        )
+        .expect("Should parse config with old version");
+        let warning = config_version_warning(&config.version).expect("Should warn");
+        assert!(warning.contains("'0.9'"));
+        assert!(warning.contains("secret config migrate"));
+        assert!(config_version_warning("2.0").is_some());
+    }
+
+    #[test]
+    fn test_custom_audit_log_path_receives_entries() {
+        let temp_dir = TempDir::new().unwrap();
+        let audit_file = temp_dir.path().join("central").join("secret-audit.log");
+        std::fs::create_dir_all(audit_file.parent().unwrap()).unwrap();
+
+        let mut old_config = PluginConfig::default();
+        old_config.security.audit_log_path = Some(audit_file.clone());
+        ConfigManager::validate_config(&old_config).expect("Custom audit path should validate");
+
+        let mut new_config = old_config.clone();
+        new_config.security.level = SecurityLevel::Paranoid;
+
+        audit_config_change(&old_config, &new_config).unwrap();
+
+        let content = std::fs::read_to_string(&audit_file).expect("Audit log should exist");
+        assert!(content.contains("security.level: Standard -> Paranoid"));
+    }
+
+    #[test]
+    fn test_audit_log_masks_custom_text() {
+        let temp_dir = TempDir::new().unwrap();
+        let audit_file = temp_dir.path().join("audit.log");
+
+        let mut old_config = PluginConfig::default();
+        old_config.security.audit_log_path = Some(audit_file.clone());
+        assert!(old_config.security.audit_mask_custom_text);
+
+        let mut new_config = old_config.clone();
+        new_config.redaction.redaction_template = Some("secret-ish".to_string());
+
+        audit_config_change(&old_config, &new_config).unwrap();
+
+        let content = std::fs::read_to_string(&audit_file).expect("Audit log should exist");
+        assert!(content.contains("redaction.redaction_template: None -> Custom(10 chars)"));
+        assert!(!content.contains("secret-ish"));
+    }
+
+    #[test]
+    fn test_audit_custom_text_unmasked_only_when_both_disable() {
+        let mut old_config = PluginConfig::default();
+        old_config.redaction.redaction_template = Some("old-text".to_string());
+        let mut new_config = old_config.clone();
+        new_config.redaction.redaction_template = Some("new-text".to_string());
+        new_config.security.audit_mask_custom_text = false;
+
+        let changes = collect_config_changes(&old_config, &new_config).join("\n");
+        assert!(!changes.contains("old-text"));
+        assert!(!changes.contains("new-text"));
+
+        old_config.security.audit_mask_custom_text = false;
+        let changes = collect_config_changes(&old_config, &new_config).join("\n");
+        assert!(changes.contains("Some(\"old-text\") -> Some(\"new-text\")"));
+    }
+
+    #[test]
+    fn test_audit_log_records_show_unredacted() {
+        let temp_dir = TempDir::new().unwrap();
+        let audit_file = temp_dir.path().join("audit.log");
+
+        let mut old_config = PluginConfig::default();
+        old_config.security.audit_log_path = Some(audit_file.clone());
+
+        let mut new_config = old_config.clone();
+        new_config.redaction.show_unredacted = true;
+
+        audit_config_change(&old_config, &new_config).unwrap();
+
+        let content = std::fs::read_to_string(&audit_file).expect("Audit log should exist");
+        assert!(content.contains("redaction.show_unredacted: false -> true"));
+    }
+
+    #[test]
+    fn test_audit_changes_include_redaction_display_settings() {
+        let old_config = PluginConfig::default();
+        let mut new_config = old_config.clone();
+        new_config.redaction.mask_secret = true;
+        new_config.redaction.unredacted_types = vec!["int".to_string()];
+
+        let changes = collect_config_changes(&old_config, &new_config);
+        assert_eq!(
+            changes,
+            vec![
+                "redaction.unredacted_types: [] -> [\"int\"]".to_string(),
+                "redaction.mask_secret: false -> true".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_audit_log_jsonl_records_field_transitions() {
+        let temp_dir = TempDir::new().unwrap();
+        let audit_file = temp_dir.path().join("audit.jsonl");
+
+        let mut old_config = PluginConfig::default();
+        old_config.security.audit_log_path = Some(audit_file.clone());
+        old_config.security.audit_format = AuditFormat::Jsonl;
+
+        let mut new_config = old_config.clone();
+        new_config.security.level = SecurityLevel::Paranoid;
+        new_config.redaction.redaction_template = Some("secret-ish".to_string());
+
+        audit_config_change(&old_config, &new_config).unwrap();
+
+        let content = std::fs::read_to_string(&audit_file).expect("Audit log should exist");
+        assert!(!content.contains("secret-ish"));
+
+        let entries: Vec<serde_json::Value> = content
+            .lines()
+            .map(|line| serde_json::from_str(line).expect("Each line should be JSON"))
+            .collect();
+        assert_eq!(entries.len(), 2);
+
+        let transitions: Vec<(&str, &str, &str)> = entries
+            .iter()
+            .map(|entry| {
+                assert!(
+                    chrono::DateTime::parse_from_rfc3339(entry["timestamp"].as_str().unwrap())
+                        .is_ok()
+                );
+                (
+                    entry["field"].as_str().unwrap(),
+                    entry["old"].as_str().unwrap(),
+                    entry["new"].as_str().unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            transitions,
+            vec![
+                ("redaction.redaction_template", "None", "Custom(10 chars)"),
+                ("security.level", "Standard", "Paranoid"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_audit_format_defaults_to_text() {
+        let config: PluginConfig = toml::from_str("[security]\nlevel = \"standard\"\n").unwrap();
+        assert_eq!(config.security.audit_format, AuditFormat::Text);
+
+        let config: PluginConfig =
+            toml::from_str("[security]\naudit_format = \"jsonl\"\n").unwrap();
+        assert_eq!(config.security.audit_format, AuditFormat::Jsonl);
+
+        let changes = [ConfigChange {
+            field: "security.level",
+            old: "Standard".to_string(),
+            new: "Paranoid".to_string(),
+        }];
+        let entry = format_audit_entry(AuditFormat::Text, chrono::Utc::now(), &changes);
+        assert!(entry.contains("Configuration changed: security.level: Standard -> Paranoid"));
+        assert_eq!(entry.lines().count(), 1);
+    }
+
+    #[test]
+    fn test_audit_log_path_requires_existing_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = PluginConfig::default();
+
+        config.security.audit_log_path = Some(temp_dir.path().join("missing").join("audit.log"));
+        assert!(ConfigManager::validate_config(&config).is_err());
+
+        config.security.audit_log_path = Some(temp_dir.path().to_path_buf());
+        assert!(ConfigManager::validate_config(&config).is_err());
+    }
+
+    #[test]
+    fn test_disabled_template_functions_config() {
+        let config: PluginConfig = toml::from_str(
+            r#"
+version = "1.0"
+
+[security]
+disabled_template_functions = ["secret_string", "take"]
+"#,
+        )
+        .expect("Should parse disabled template functions");
+        assert_eq!(
+            config.security.disabled_template_functions,
+            vec!["secret_string".to_string(), "take".to_string()]
+        );
+        ConfigManager::validate_config(&config).expect("Known functions should validate");
+
+        let mut invalid = config.clone();
+        invalid.security.disabled_template_functions = vec!["eval".to_string()];
+        assert!(ConfigManager::validate_config(&invalid).is_err());
+    }
+
     #[test]
     fn test_empty_config_sections() {
         // Test that empty sections get proper defaults
@@ -16,8 +16,8 @@ pub mod tera_functions;
 use commands::*;
 pub use config::ConfigManager;
 pub use secret_types::{
-    SecretBinary, SecretBool, SecretDate, SecretFloat, SecretInt, SecretList, SecretRecord,
-    SecretString,
+    SecretBinary, SecretBool, SecretCellPath, SecretDate, SecretDuration, SecretFilesize,
+    SecretFloat, SecretInt, SecretList, SecretRange, SecretRecord, SecretString,
 };
 
 /// Secret Plugin with dependency-injected configuration
@@ -54,6 +54,11 @@ impl Default for SecretPlugin {
         #[cfg(miri)]
         let config_manager = ConfigManager::new(config::PluginConfig::default());
 
+        // Warn about a stale config file once, when the plugin's own config loads
+        if let Some(warning) = config::config_version_warning(&config_manager.config().version) {
+            eprintln!("{}", warning);
+        }
+
         Self {
             config_manager: Arc::new(RwLock::new(config_manager)),
         }
@@ -71,11 +76,15 @@ impl Plugin for SecretPlugin {
 
         // Initialize Tera-based redaction templating system
         let _ = redaction::init_redaction_templating();
+        if let Ok(config_manager) = self.config_manager.read() {
+            config_manager.apply_runtime_settings();
+        }
 
         vec![
             // Unified wrap command
             Box::new(SecretWrapCommand),
             Box::new(SecretWrapWithCommand),
+            Box::new(SecretMapCommand),
             // Utility commands
             Box::new(SecretUnwrapCommand),
             Box::new(SecretContainsCommand),
@@ -86,6 +95,44 @@ impl Plugin for SecretPlugin {
             Box::new(SecretValidateCommand),
             Box::new(SecretValidateFormatCommand),
             Box::new(SecretTypeOfCommand),
+            Box::new(SecretDateToTimezoneCommand),
+            Box::new(SecretListSumByCommand),
+            Box::new(SecretStringPadLeftCommand),
+            Box::new(SecretStringPadRightCommand),
+            Box::new(SecretBinaryReverseCommand),
+            Box::new(SecretStringLinesCommand),
+            Box::new(SecretFloatToIntCommand),
+            Box::new(SecretIntToFloatCommand),
+            Box::new(SecretStringIndexOfCommand),
+            Box::new(SecretStartsWithCommand),
+            Box::new(SecretEndsWithCommand),
+            Box::new(SecretStringCapitalizeCommand),
+            Box::new(SecretStringTitleCaseCommand),
+            Box::new(SecretListWindowCommand),
+            Box::new(SecretBinaryConcatCommand),
+            Box::new(SecretStringMatchesCommand),
+            Box::new(SecretListJoinRecordsCommand),
+            Box::new(SecretStringToBytesCommand),
+            Box::new(SecretBinaryToStringCommand),
+            Box::new(SecretRedactWithCommand),
+            Box::new(SecretDateIsExpiredCommand),
+            Box::new(SecretListUniqueCountCommand),
+            Box::new(SecretStringContainsSubstringCommand),
+            Box::new(SecretToBase64Command),
+            Box::new(SecretFromBase64Command),
+            Box::new(SecretGetCommand),
+            Box::new(SecretSelectCommand),
+            Box::new(SecretListChunkByCommand),
+            Box::new(SecretStringCountCharsCommand),
+            Box::new(SecretBinaryToIntCommand),
+            Box::new(SecretIntToBinaryCommand),
+            Box::new(SecretRevealIntoCommand),
+            Box::new(SecretEqualsCommand),
+            Box::new(SecretConcatCommand),
+            Box::new(SecretSliceCommand),
+            Box::new(SecretWhereLengthCommand),
+            Box::new(SecretBinaryToHexCommand),
+            Box::new(SecretBinaryFromHexCommand),
             // Configuration commands
             Box::new(SecretConfigureCommand),
             Box::new(SecretConfigShowCommand),
@@ -93,6 +140,19 @@ impl Plugin for SecretPlugin {
             Box::new(SecretConfigValidateCommand),
             Box::new(SecretConfigExportCommand),
             Box::new(SecretConfigImportCommand),
+            Box::new(SecretConfigPreviewLevelCommand),
+            Box::new(SecretConfigBackupCommand),
+            Box::new(SecretConfigRestoreCommand),
+            Box::new(SecretConfigLintTemplatesCommand),
+            Box::new(SecretConfigDiffFilesCommand),
+            Box::new(SecretConfigInitCommand),
+            Box::new(SecretConfigGetCommand),
+            Box::new(SecretConfigTestEnvCommand),
+            Box::new(SecretConfigEnforceMinimumCommand),
+            Box::new(SecretConfigCheckTemplateVarsCommand),
+            Box::new(SecretConfigPresetCommand),
+            Box::new(SecretConfigCheckConflictsCommand),
+            Box::new(SecretConfigReportCommand),
         ]
     }
 }
@@ -111,13 +171,14 @@ mod tests {
     fn test_plugin_commands() {
         let plugin = SecretPlugin::default();
         let commands = plugin.commands();
-        assert_eq!(commands.len(), 17);
+        assert_eq!(commands.len(), 69);
 
         // Test all commands to ensure they're registered correctly
         let command_names: Vec<&str> = commands.iter().map(|cmd| cmd.name()).collect();
         // Unified wrap command
         assert!(command_names.contains(&"secret wrap"));
         assert!(command_names.contains(&"secret wrap-with"));
+        assert!(command_names.contains(&"secret map"));
         // Utility commands
         assert!(command_names.contains(&"secret unwrap"));
         assert!(command_names.contains(&"secret contains"));
@@ -128,6 +189,44 @@ mod tests {
         assert!(command_names.contains(&"secret validate"));
         assert!(command_names.contains(&"secret validate-format"));
         assert!(command_names.contains(&"secret type-of"));
+        assert!(command_names.contains(&"secret date to-timezone"));
+        assert!(command_names.contains(&"secret list sum-by"));
+        assert!(command_names.contains(&"secret string pad-left"));
+        assert!(command_names.contains(&"secret string pad-right"));
+        assert!(command_names.contains(&"secret binary reverse"));
+        assert!(command_names.contains(&"secret string lines"));
+        assert!(command_names.contains(&"secret float to-int"));
+        assert!(command_names.contains(&"secret int to-float"));
+        assert!(command_names.contains(&"secret string index-of"));
+        assert!(command_names.contains(&"secret starts-with"));
+        assert!(command_names.contains(&"secret ends-with"));
+        assert!(command_names.contains(&"secret string capitalize"));
+        assert!(command_names.contains(&"secret string title-case"));
+        assert!(command_names.contains(&"secret list window"));
+        assert!(command_names.contains(&"secret binary concat"));
+        assert!(command_names.contains(&"secret string matches"));
+        assert!(command_names.contains(&"secret list join-records"));
+        assert!(command_names.contains(&"secret string to-bytes"));
+        assert!(command_names.contains(&"secret binary to-string"));
+        assert!(command_names.contains(&"secret redact-with"));
+        assert!(command_names.contains(&"secret date is-expired"));
+        assert!(command_names.contains(&"secret list unique-count"));
+        assert!(command_names.contains(&"secret string contains-substring"));
+        assert!(command_names.contains(&"secret to-base64"));
+        assert!(command_names.contains(&"secret from-base64"));
+        assert!(command_names.contains(&"secret get"));
+        assert!(command_names.contains(&"secret select"));
+        assert!(command_names.contains(&"secret list chunk-by"));
+        assert!(command_names.contains(&"secret string count-chars"));
+        assert!(command_names.contains(&"secret binary to-int"));
+        assert!(command_names.contains(&"secret int to-binary"));
+        assert!(command_names.contains(&"secret reveal-into"));
+        assert!(command_names.contains(&"secret equals"));
+        assert!(command_names.contains(&"secret concat"));
+        assert!(command_names.contains(&"secret slice"));
+        assert!(command_names.contains(&"secret where-length"));
+        assert!(command_names.contains(&"secret binary to-hex"));
+        assert!(command_names.contains(&"secret binary from-hex"));
         // Configuration commands
         assert!(command_names.contains(&"secret configure"));
         assert!(command_names.contains(&"secret config show"));
@@ -135,5 +234,18 @@ mod tests {
         assert!(command_names.contains(&"secret config validate"));
         assert!(command_names.contains(&"secret config export"));
         assert!(command_names.contains(&"secret config import"));
+        assert!(command_names.contains(&"secret config preview-level"));
+        assert!(command_names.contains(&"secret config backup"));
+        assert!(command_names.contains(&"secret config restore"));
+        assert!(command_names.contains(&"secret config lint-templates"));
+        assert!(command_names.contains(&"secret config diff-files"));
+        assert!(command_names.contains(&"secret config init"));
+        assert!(command_names.contains(&"secret config get"));
+        assert!(command_names.contains(&"secret config test-env"));
+        assert!(command_names.contains(&"secret config enforce-minimum"));
+        assert!(command_names.contains(&"secret config check-template-vars"));
+        assert!(command_names.contains(&"secret config preset"));
+        assert!(command_names.contains(&"secret config check-conflicts"));
+        assert!(command_names.contains(&"secret config report"));
     }
 }
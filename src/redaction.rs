@@ -7,7 +7,11 @@
 //!
 //! Available template variables:
 //! - `secret_type`: The type of the secret (e.g., "string", "int", "float")
-//! - `secret_length`: The length of the secret value (only available when length is provided)
+//! - `secret_length`: The length of the secret value in UTF-8 bytes (only available when length
+//!   is provided). For multi-byte text this is larger than the number of characters.
+//! - `secret_char_length`: The number of unicode characters in the secret value (only available
+//!   when both the length and the value are provided), e.g. 6 for `"пароль"`, whose
+//!   `secret_length` is 12
 //! - `secret_string`: The actual secret value as a string (WARNING: exposes sensitive data!)
 //!
 //! Available template functions:
@@ -17,8 +21,28 @@
 //! - `reverse("text")` or `reverse(s="text")`: Returns the input string reversed
 //! - `take(5, "text")` or `take(n=5, s="text")`: Returns the first n characters of the input string
 //! - `strlen("text")` or `strlen(s="text")`: Returns the length of the input string as a number
+//! - `mask_middle(s=secret_string, prefix=2, suffix=2)`: Shows the first and last characters and
+//!   masks the middle (optional `character="*"`). Values of at most `prefix + suffix`
+//!   characters are fully masked.
+//! - `hash(s=secret_string, algorithm="sha256", length=6)`: Returns a hex fingerprint of the
+//!   value for correlating occurrences, e.g. `<{{secret_type}}:sha256:{{hash(s=secret_string, length=6)}}>`.
+//!   Returns an empty string when no value is available.
+//! - `reveal_chars()`: Returns the number of characters in the secret value as a string, e.g.
+//!   `<{{secret_type}}: {{reveal_chars()}} chars>`. Unlike `secret_length`, which counts UTF-8
+//!   bytes, this counts unicode characters, so `"пароль"` has 6 chars but 12 bytes.
+//!   Returns an empty string when no value is available.
+//!
+//! Secret types listed in `redaction.unredacted_types` (e.g. `int` or `secret_int`) are
+//! displayed as their actual value instead of being redacted, with or without a custom
+//! template. Only the scalar types in `UNREDACTABLE_TYPES` can be listed.
+//!
+//! Functions listed in `security.disabled_template_functions` are not registered (and a
+//! disabled `secret_string` is not provided), so templates using them fall back to
+//! `<redacted:{{secret_type}}>`.
 
-use std::sync::OnceLock;
+use std::cell::RefCell;
+use std::sync::{OnceLock, RwLock};
+use std::thread::LocalKey;
 
 use tera::{Context, Tera};
 
@@ -26,11 +50,144 @@ use tera::{Context, Tera};
 static REDACTION_TERA: OnceLock<Tera> = OnceLock::new();
 
 /// Template string for redaction
-const REDACTION_TEMPLATE: &str = "<redacted:{{secret_type}}>";
+pub(crate) const REDACTION_TEMPLATE: &str = "<redacted:{{secret_type}}>";
 
 /// Template name used internally
 const TEMPLATE_NAME: &str = "redaction";
 
+/// Template functions disabled by the active configuration
+static DISABLED_TEMPLATE_FUNCTIONS: RwLock<Vec<String>> = RwLock::new(Vec::new());
+
+/// Set the template functions that must not be available when rendering.
+///
+/// Called whenever the active configuration is loaded or replaced.
+pub fn set_disabled_template_functions(functions: &[String]) {
+    if let Ok(mut disabled) = DISABLED_TEMPLATE_FUNCTIONS.write() {
+        *disabled = functions.to_vec();
+    }
+}
+
+/// Get the currently disabled template functions
+fn disabled_template_functions() -> Vec<String> {
+    DISABLED_TEMPLATE_FUNCTIONS
+        .read()
+        .map(|disabled| disabled.clone())
+        .unwrap_or_default()
+}
+
+/// Secret types that `redaction.unredacted_types` may list.
+///
+/// Only scalar types render from their value; lists, records, binaries, ranges
+/// and cell paths have no display value to reveal and stay redacted.
+pub const UNREDACTABLE_TYPES: &[&str] = &[
+    "string", "int", "float", "bool", "date", "duration", "filesize",
+];
+
+/// Secret types the active configuration displays unredacted
+static UNREDACTED_TYPES: RwLock<Vec<String>> = RwLock::new(Vec::new());
+
+/// Set the secret types that are displayed unredacted.
+///
+/// Called whenever the active configuration is loaded or replaced.
+pub fn set_unredacted_types(types: &[String]) {
+    if let Ok(mut unredacted) = UNREDACTED_TYPES.write() {
+        *unredacted = types.to_vec();
+    }
+}
+
+/// Redaction template of the active configuration, if it sets one
+static CONFIGURED_TEMPLATE: RwLock<Option<String>> = RwLock::new(None);
+
+/// Set the template used for secrets without their own redaction template.
+///
+/// Called whenever the active configuration is loaded or replaced; `None`
+/// restores [`REDACTION_TEMPLATE`].
+pub fn set_redaction_template(template: Option<&str>) {
+    if let Ok(mut configured) = CONFIGURED_TEMPLATE.write() {
+        *configured = template.map(str::to_string);
+    }
+}
+
+thread_local! {
+    /// Per-thread replacement for the configured `unredacted_types`, if any
+    static UNREDACTED_TYPES_OVERRIDE: RefCell<Option<Vec<String>>> = const { RefCell::new(None) };
+    /// Per-thread replacement for the configured redaction template, if any
+    static TEMPLATE_OVERRIDE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Run `f` with `value` replacing the setting held in `key` on this thread,
+/// restoring the previous replacement afterwards
+fn with_thread_override<T: 'static, R>(
+    key: &'static LocalKey<RefCell<Option<T>>>,
+    value: T,
+    f: impl FnOnce() -> R,
+) -> R {
+    struct Restore<T: 'static> {
+        key: &'static LocalKey<RefCell<Option<T>>>,
+        previous: Option<T>,
+    }
+    impl<T: 'static> Drop for Restore<T> {
+        fn drop(&mut self) {
+            let previous = self.previous.take();
+            self.key.with(|current| *current.borrow_mut() = previous);
+        }
+    }
+
+    let _restore = Restore {
+        key,
+        previous: key.with(|current| current.borrow_mut().replace(value)),
+    };
+    f()
+}
+
+/// Run `f` with `types` in place of `redaction.unredacted_types` on this thread.
+///
+/// Passing an empty slice forces every type to render redacted, which is how
+/// explicit requests for the redacted form (e.g. `secret unwrap --redacted`)
+/// ignore the allowlist. The previous setting is restored afterwards.
+pub(crate) fn with_unredacted_types<R>(types: &[String], f: impl FnOnce() -> R) -> R {
+    with_thread_override(&UNREDACTED_TYPES_OVERRIDE, types.to_vec(), f)
+}
+
+/// Run `f` with `template` in place of the configured redaction template on this thread
+pub(crate) fn with_redaction_template<R>(template: &str, f: impl FnOnce() -> R) -> R {
+    with_thread_override(&TEMPLATE_OVERRIDE, template.to_string(), f)
+}
+
+/// Get the template for secrets without their own redaction template
+fn redaction_template() -> String {
+    if let Some(template) = TEMPLATE_OVERRIDE.with(|template| template.borrow().clone()) {
+        return template;
+    }
+    CONFIGURED_TEMPLATE
+        .read()
+        .ok()
+        .and_then(|configured| configured.clone())
+        .unwrap_or_else(|| REDACTION_TEMPLATE.to_string())
+}
+
+/// Get the secret types currently displayed unredacted
+fn unredacted_types() -> Vec<String> {
+    if let Some(types) = UNREDACTED_TYPES_OVERRIDE.with(|types| types.borrow().clone()) {
+        return types;
+    }
+    UNREDACTED_TYPES
+        .read()
+        .map(|unredacted| unredacted.clone())
+        .unwrap_or_default()
+}
+
+/// Whether `secret_type` (e.g. `int`) is in `unredacted`, which may list
+/// either the short name or the full custom type name (`secret_int`)
+///
+/// Types outside [`UNREDACTABLE_TYPES`] are never revealed.
+fn is_unredacted_type(secret_type: &str, unredacted: &[String]) -> bool {
+    UNREDACTABLE_TYPES.contains(&secret_type)
+        && unredacted
+            .iter()
+            .any(|t| t.strip_prefix("secret_").unwrap_or(t) == secret_type)
+}
+
 /// Initialize the Tera template engine for redaction
 pub fn init_redaction_templating() -> Result<(), tera::Error> {
     let _tera = REDACTION_TERA.get_or_init(|| {
@@ -48,30 +205,72 @@ pub fn init_redaction_templating() -> Result<(), tera::Error> {
 /// Generate redacted string using Tera template
 /// This is the core function that uses Tera templating
 fn generate_redacted_string(secret_string: Option<&str>, secret_type: &str) -> String {
-    generate_redacted_string_with_length(secret_string, secret_type, None)
+    generate_redacted_string_with_length(&redaction_template(), secret_string, secret_type, None)
 }
 
 /// Generate redacted string using Tera template with optional length
-/// This is the core function that uses Tera templating
+/// This is the core function that uses Tera templating; the default template
+/// skips Tera entirely
 fn generate_redacted_string_with_length(
+    template: &str,
+    secret_string: Option<&str>,
+    secret_type: &str,
+    secret_length: Option<usize>,
+) -> String {
+    if let Some(redacted) = default_template_fast_path(template, secret_type) {
+        return redacted;
+    }
+
+    render_redaction_template(template, secret_string, secret_type, secret_length)
+}
+
+/// Render the default template without Tera.
+///
+/// The default template only uses `secret_type`, so its output can be built
+/// directly. Returns `None` for any other template.
+fn default_template_fast_path(template: &str, secret_type: &str) -> Option<String> {
+    (template == REDACTION_TEMPLATE).then(|| format!("<redacted:{}>", secret_type))
+}
+
+/// Register `reveal_chars()`, which returns the character count of `secret_string`.
+///
+/// Only the count is captured, never the value itself. The count is of unicode
+/// characters rather than bytes, so it differs from `secret_length` for
+/// multi-byte text. Without a value the function returns an empty string.
+pub(crate) fn register_reveal_chars_function(tera: &mut Tera, secret_string: Option<&str>) {
+    let char_count = secret_string.map(|s| s.chars().count());
+    tera.register_function(
+        "reveal_chars",
+        move |_: &std::collections::HashMap<String, tera::Value>| {
+            Ok(tera::Value::String(
+                char_count.map(|n| n.to_string()).unwrap_or_default(),
+            ))
+        },
+    );
+}
+
+/// Render `template` with Tera, falling back to the default format on failure
+fn render_redaction_template(
+    template: &str,
     secret_string: Option<&str>,
     secret_type: &str,
     secret_length: Option<usize>,
 ) -> String {
-    // Use default template
-    // TODO: Add support for passing ConfigManager to enable custom templates
-    let template = REDACTION_TEMPLATE.to_string();
+    let disabled = disabled_template_functions();
 
     // Always create a fresh Tera instance to pick up template changes
     // This is slightly less efficient but allows for dynamic template updates
     let mut tera = Tera::default();
 
-    // Register all standard template functions
-    crate::tera_functions::register_all_standard_functions(&mut tera);
+    // Register all standard template functions that are not disabled
+    crate::tera_functions::register_standard_functions_except(&mut tera, &disabled);
+    if !disabled.iter().any(|d| d == "reveal_chars") {
+        register_reveal_chars_function(&mut tera, secret_string);
+    }
 
     // Note: secret_string is available as a template variable, not a function
 
-    if tera.add_raw_template(TEMPLATE_NAME, &template).is_err() {
+    if tera.add_raw_template(TEMPLATE_NAME, template).is_err() {
         // If template adding fails, fall back to simple format
         return format!("<redacted:{}>", secret_type);
     }
@@ -79,10 +278,15 @@ fn generate_redacted_string_with_length(
     let mut context = Context::new();
     context.insert("secret_type", secret_type);
     if let Some(secret_str) = secret_string {
-        context.insert("secret_string", secret_str);
+        if !disabled.iter().any(|d| d == "secret_string") {
+            context.insert("secret_string", secret_str);
+        }
     }
     if let Some(length) = secret_length {
         context.insert("secret_length", &length);
+        if let Some(secret_str) = secret_string {
+            context.insert("secret_char_length", &secret_str.chars().count());
+        }
     }
 
     // Use Tera to render the template, fallback to format if it fails
@@ -107,7 +311,12 @@ pub fn get_cached_redacted_string_with_length(
     secret_length: Option<usize>,
 ) -> String {
     // Always generate fresh to pick up template changes
-    generate_redacted_string_with_length(secret_string, secret_type, secret_length)
+    generate_redacted_string_with_length(
+        &redaction_template(),
+        secret_string,
+        secret_type,
+        secret_length,
+    )
 }
 
 /// Get configurable redacted string with optional unredacted mode support
@@ -118,6 +327,21 @@ pub fn get_redacted_string_with_value<T: std::fmt::Display + ?Sized>(
     _context: crate::config::RedactionContext,
     actual_value: Option<&T>,
 ) -> String {
+    redacted_string_with_value(secret_type, actual_value, &unredacted_types())
+}
+
+/// Redact `actual_value`, revealing it when its type is listed in `unredacted`
+fn redacted_string_with_value<T: std::fmt::Display + ?Sized>(
+    secret_type: &str,
+    actual_value: Option<&T>,
+    unredacted: &[String],
+) -> String {
+    if let Some(value) = actual_value {
+        if is_unredacted_type(secret_type, unredacted) {
+            return value.to_string();
+        }
+    }
+
     // Calculate length if we have a value
     let secret_length = actual_value.map(|v| v.to_string().len());
 
@@ -158,6 +382,23 @@ pub fn generate_redacted_string_with_custom_template_and_value(
     secret_type: &str,
     secret_length: Option<usize>,
     secret_value: Option<String>,
+) -> String {
+    render_custom_template(
+        custom_template,
+        secret_type,
+        secret_length,
+        secret_value,
+        &disabled_template_functions(),
+    )
+}
+
+/// Render a custom template without the template functions named in `disabled`
+fn render_custom_template(
+    custom_template: &str,
+    secret_type: &str,
+    secret_length: Option<usize>,
+    secret_value: Option<String>,
+    disabled: &[String],
 ) -> String {
     // Note: show_unredacted support requires ConfigManager access
     // TODO: Add variant that accepts ConfigManager parameter
@@ -165,8 +406,11 @@ pub fn generate_redacted_string_with_custom_template_and_value(
     // Create a fresh Tera instance with the custom template
     let mut tera = tera::Tera::default();
 
-    // Register all standard template functions
-    crate::tera_functions::register_all_standard_functions(&mut tera);
+    // Register all standard template functions that are not disabled
+    crate::tera_functions::register_standard_functions_except(&mut tera, disabled);
+    if !disabled.iter().any(|d| d == "reveal_chars") {
+        register_reveal_chars_function(&mut tera, secret_value.as_deref());
+    }
 
     // Use the secret value as-is for template rendering
     // Note: mask_secret feature disabled in this context without ConfigManager
@@ -187,9 +431,14 @@ pub fn generate_redacted_string_with_custom_template_and_value(
     context.insert("secret_type", secret_type);
     if let Some(length) = secret_length {
         context.insert("secret_length", &length);
+        if let Some(value) = &effective_secret_value {
+            context.insert("secret_char_length", &value.chars().count());
+        }
     }
     if let Some(value) = &effective_secret_value {
-        context.insert("secret_string", value);
+        if !disabled.iter().any(|d| d == "secret_string") {
+            context.insert("secret_string", value);
+        }
     }
 
     // Use Tera to render the template, fallback to format if it fails
@@ -206,6 +455,12 @@ pub fn get_redacted_string_with_custom_template_and_value<T: std::fmt::Display +
     _context: crate::config::RedactionContext,
     actual_value: Option<&T>,
 ) -> String {
+    if let Some(value) = actual_value {
+        if is_unredacted_type(secret_type, &unredacted_types()) {
+            return value.to_string();
+        }
+    }
+
     // Calculate length and string value if we have a value
     let (secret_length, secret_string_value) = if let Some(value) = actual_value {
         let string_value = value.to_string();
@@ -227,6 +482,48 @@ pub fn get_redacted_string_with_custom_template_and_value<T: std::fmt::Display +
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_default_template_fast_path_matches_tera() {
+        for secret_type in [
+            "string", "int", "float", "bool", "date", "binary", "duration", "filesize", "list",
+            "record", "range", "cellpath",
+        ] {
+            let fast = default_template_fast_path(REDACTION_TEMPLATE, secret_type).unwrap();
+
+            // Render with a bare Tera so a render error cannot hide behind
+            // render_redaction_template's fallback, which matches the fast path
+            let mut context = Context::new();
+            context.insert("secret_type", secret_type);
+            let tera = Tera::one_off(REDACTION_TEMPLATE, &context, false).unwrap();
+            assert_eq!(fast, tera);
+
+            let rendered =
+                render_redaction_template(REDACTION_TEMPLATE, Some("value"), secret_type, Some(5));
+            assert_eq!(fast, rendered);
+        }
+    }
+
+    #[test]
+    fn test_default_template_fast_path_selection() {
+        assert!(default_template_fast_path(REDACTION_TEMPLATE, "string").is_some());
+        for template in [
+            "[HIDDEN:{{secret_type}}]",
+            "<redacted>",
+            "<redacted:{{ secret_type }}>",
+        ] {
+            assert!(default_template_fast_path(template, "string").is_none());
+        }
+
+        // Any other template is rendered by Tera
+        let rendered = generate_redacted_string_with_length(
+            "[HIDDEN:{{secret_type}}:{{secret_length}}]",
+            Some("hunter22"),
+            "string",
+            Some(8),
+        );
+        assert_eq!(rendered, "[HIDDEN:string:8]");
+    }
+
     #[test]
     fn test_redaction_template_initialization() {
         let result = init_redaction_templating();
@@ -399,6 +696,100 @@ mod tests {
         assert_eq!(result, "*****");
     }
 
+    #[test]
+    fn test_mask_middle_function() {
+        let mut tera = tera::Tera::default();
+        crate::tera_functions::register_all_standard_functions(&mut tera);
+
+        tera.add_raw_template(
+            "mask_middle_test",
+            "{{mask_middle(s=secret_string, prefix=2, suffix=2)}}",
+        )
+        .unwrap();
+
+        let mut context = tera::Context::new();
+        context.insert("secret_string", "sk-live-abcdyz");
+        let result = tera.render("mask_middle_test", &context).unwrap();
+        assert_eq!(result, "sk**********yz");
+    }
+
+    #[test]
+    fn test_mask_middle_function_exact_boundary() {
+        let mut tera = tera::Tera::default();
+        crate::tera_functions::register_all_standard_functions(&mut tera);
+
+        tera.add_raw_template(
+            "mask_middle_boundary",
+            "{{mask_middle(s=secret_string, prefix=2, suffix=2, character='#')}}",
+        )
+        .unwrap();
+
+        let mut context = tera::Context::new();
+        context.insert("secret_string", "abcde");
+        assert_eq!(
+            tera.render("mask_middle_boundary", &context).unwrap(),
+            "ab#de"
+        );
+
+        // prefix + suffix == length would reveal everything, so it is fully masked
+        context.insert("secret_string", "abcd");
+        assert_eq!(
+            tera.render("mask_middle_boundary", &context).unwrap(),
+            "####"
+        );
+    }
+
+    #[test]
+    fn test_mask_middle_function_too_short() {
+        let mut tera = tera::Tera::default();
+        crate::tera_functions::register_all_standard_functions(&mut tera);
+
+        tera.add_raw_template(
+            "mask_middle_short",
+            "{{mask_middle(s=secret_string, prefix=3, suffix=3)}}",
+        )
+        .unwrap();
+
+        let mut context = tera::Context::new();
+        context.insert("secret_string", "abc");
+        assert_eq!(tera.render("mask_middle_short", &context).unwrap(), "***");
+
+        context.insert("secret_string", "");
+        assert_eq!(tera.render("mask_middle_short", &context).unwrap(), "");
+    }
+
+    #[test]
+    fn test_hash_function_in_custom_template() {
+        let template = "<{{secret_type}}:sha256:{{hash(s=secret_string, length=6)}}>";
+
+        let first = generate_redacted_string_with_custom_template_and_value(
+            template,
+            "string",
+            None,
+            Some("hello world".to_string()),
+        );
+        let second = generate_redacted_string_with_custom_template_and_value(
+            template,
+            "string",
+            None,
+            Some("hello world".to_string()),
+        );
+
+        assert_eq!(first, "<string:sha256:b94d27>");
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_hash_function_in_global_template() {
+        let result = render_redaction_template(
+            "{{hash(s=secret_string, algorithm='blake3', length=8)}}",
+            Some("hello world"),
+            "string",
+            None,
+        );
+        assert_eq!(result, "d74981ef");
+    }
+
     #[test]
     fn test_replicate_function_different_characters() {
         let mut tera = tera::Tera::default();
@@ -792,7 +1183,7 @@ mod tests {
     #[test]
     fn test_secret_string_integration_with_none() {
         // Test that secret_string variable is not available when no secret is provided
-        let result = generate_redacted_string_with_length(None, "test", None);
+        let result = generate_redacted_string_with_length(REDACTION_TEMPLATE, None, "test", None);
         assert_eq!(result, "<redacted:test>");
 
         // Test behavior when secret_string is None but we try to use it in custom templates
@@ -812,7 +1203,12 @@ mod tests {
     #[test]
     fn test_secret_string_integration_with_some() {
         // Test that secret_string variable works when secret is provided
-        let result = generate_redacted_string_with_length(Some("mysecret"), "test", None);
+        let result = generate_redacted_string_with_length(
+            REDACTION_TEMPLATE,
+            Some("mysecret"),
+            "test",
+            None,
+        );
         assert_eq!(result, "<redacted:test>");
 
         // Test with custom template that uses secret_string variable when Some is provided
@@ -1099,4 +1495,183 @@ mod tests {
         let result2 = tera2.render("test2", &context2);
         assert!(result2.is_err()); // Should fail since function is not registered
     }
+
+    #[test]
+    fn test_disabled_secret_string_falls_back() {
+        let disabled = vec!["secret_string".to_string()];
+
+        for template in ["{{secret_string()}}", "{{secret_string}}"] {
+            let result = render_custom_template(
+                template,
+                "string",
+                Some(6),
+                Some("hunter".to_string()),
+                &disabled,
+            );
+            assert_eq!(result, "<redacted:string>");
+        }
+
+        let enabled = render_custom_template(
+            "{{secret_string}}",
+            "string",
+            Some(6),
+            Some("hunter".to_string()),
+            &[],
+        );
+        assert_eq!(enabled, "hunter");
+    }
+
+    #[test]
+    fn test_disabled_take_falls_back() {
+        let result = render_custom_template(
+            "{{take(n=2, s=secret_string)}}***",
+            "string",
+            Some(6),
+            Some("hunter".to_string()),
+            &["take".to_string()],
+        );
+        assert_eq!(result, "<redacted:string>");
+    }
+
+    #[test]
+    fn test_reveal_chars_counts_characters() {
+        let template = "<{{secret_type}}: {{reveal_chars()}} chars>";
+
+        let ascii = render_custom_template(
+            template,
+            "string",
+            Some(7),
+            Some("hunter2".to_string()),
+            &[],
+        );
+        assert_eq!(ascii, "<string: 7 chars>");
+
+        // 6 characters but 12 UTF-8 bytes
+        let multi_byte = render_custom_template(
+            "{{reveal_chars()}}/{{secret_length}}",
+            "string",
+            Some("пароль".len()),
+            Some("пароль".to_string()),
+            &[],
+        );
+        assert_eq!(multi_byte, "6/12");
+
+        let emoji = render_redaction_template(template, Some("🔑🔑"), "string", Some(8));
+        assert_eq!(emoji, "<string: 2 chars>");
+    }
+
+    #[test]
+    fn test_reveal_chars_without_value_or_when_disabled() {
+        let template = "<{{secret_type}}: {{reveal_chars()}} chars>";
+
+        let missing = render_redaction_template(template, None, "string", None);
+        assert_eq!(missing, "<string:  chars>");
+
+        let disabled = render_custom_template(
+            template,
+            "string",
+            Some(7),
+            Some("hunter2".to_string()),
+            &["reveal_chars".to_string()],
+        );
+        assert_eq!(disabled, "<redacted:string>");
+    }
+
+    #[test]
+    fn test_secret_char_length_differs_from_byte_length() {
+        let template = "{{secret_char_length}} chars, {{secret_length}} bytes";
+
+        let custom = get_redacted_string_with_custom_template_and_value(
+            template,
+            "string",
+            crate::config::RedactionContext::Display,
+            Some("пароль"),
+        );
+        assert_eq!(custom, "6 chars, 12 bytes");
+
+        let global = render_redaction_template(template, Some("café"), "string", Some(5));
+        assert_eq!(global, "4 chars, 5 bytes");
+
+        // Without a value only the byte length is known
+        let no_value = render_custom_template(template, "string", Some(12), None, &[]);
+        assert_eq!(no_value, "<redacted:string>");
+    }
+
+    #[test]
+    fn test_unredacted_types_reveal_only_listed_types() {
+        let unredacted = vec!["int".to_string()];
+
+        let int = redacted_string_with_value("int", Some(&42), &unredacted);
+        assert_eq!(int, "42");
+
+        let string = redacted_string_with_value("string", Some("hunter2"), &unredacted);
+        assert_eq!(string, "<redacted:string>");
+
+        // Without a value there is nothing to reveal
+        let missing = redacted_string_with_value::<i64>("int", None, &unredacted);
+        assert_eq!(missing, "<redacted:int>");
+    }
+
+    #[test]
+    fn test_unredacted_types_accept_full_type_names() {
+        let unredacted = vec!["secret_int".to_string()];
+        assert!(is_unredacted_type("int", &unredacted));
+        assert!(!is_unredacted_type("string", &unredacted));
+        assert!(!is_unredacted_type("int", &[]));
+    }
+
+    #[test]
+    fn test_unredacted_types_never_reveal_unsupported_types() {
+        let unredacted = vec!["record".to_string(), "secret_list".to_string()];
+        assert!(!is_unredacted_type("record", &unredacted));
+        assert!(!is_unredacted_type("list", &unredacted));
+    }
+
+    #[test]
+    fn test_unredacted_types_apply_to_custom_templates() {
+        use crate::config::RedactionContext;
+
+        let unredacted = vec!["int".to_string()];
+        let (int, string) = with_unredacted_types(&unredacted, || {
+            (
+                get_redacted_string_with_custom_template_and_value(
+                    "[X]",
+                    "int",
+                    RedactionContext::Display,
+                    Some(&42),
+                ),
+                get_redacted_string_with_custom_template_and_value(
+                    "[X]",
+                    "string",
+                    RedactionContext::Display,
+                    Some("hunter2"),
+                ),
+            )
+        });
+        assert_eq!(int, "42");
+        assert_eq!(string, "[X]");
+    }
+
+    #[test]
+    fn test_unredacted_types_config_is_backward_compatible() {
+        use crate::config::PluginConfig;
+
+        let config: PluginConfig = toml::from_str(
+            r#"
+[redaction]
+show_unredacted = false
+"#,
+        )
+        .unwrap();
+        assert!(config.redaction.unredacted_types.is_empty());
+
+        let config: PluginConfig = toml::from_str(
+            r#"
+[redaction]
+unredacted_types = ["int"]
+"#,
+        )
+        .unwrap();
+        assert_eq!(config.redaction.unredacted_types, vec!["int".to_string()]);
+    }
 }
@@ -0,0 +1,53 @@
+//! Constant-time byte comparison shared by the secret types' `PartialEq` impls.
+
+/// Compare two byte slices without branching on their contents.
+///
+/// Runs `max(a.len(), b.len())` rounds and accumulates every difference, with
+/// bytes past the end of the shorter slice counting as a mismatch, so the time
+/// taken does not depend on where (or whether) the inputs differ. It does still
+/// scale with the longer input: comparing against a candidate reveals the larger
+/// of the two lengths, though not whether the lengths match. Callers comparing
+/// fixed-width encodings (ints, floats, dates) leak nothing.
+pub(crate) fn ct_eq(a: &[u8], b: &[u8]) -> bool {
+    let rounds = a.len().max(b.len());
+    let mut diff = (a.len() ^ b.len()) as u64;
+
+    for i in 0..rounds {
+        let x = a.get(i).copied().unwrap_or(0);
+        let y = b.get(i).copied().unwrap_or(0);
+        diff |= u64::from(x ^ y);
+    }
+
+    std::hint::black_box(diff) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_equal_inputs() {
+        assert!(ct_eq(b"", b""));
+        assert!(ct_eq(b"a", b"a"));
+        assert!(ct_eq(b"correct_password", b"correct_password"));
+        assert!(ct_eq(&[0u8; 64], &[0u8; 64]));
+    }
+
+    #[test]
+    fn test_unequal_inputs() {
+        assert!(!ct_eq(b"a", b"b"));
+        assert!(!ct_eq(b"correct_password", b"correct_passwore"));
+        assert!(!ct_eq(b"correct_password", b"Correct_password"));
+        assert!(!ct_eq(&[0x00], &[0x80]));
+    }
+
+    #[test]
+    fn test_different_length_inputs() {
+        assert!(!ct_eq(b"", b"a"));
+        assert!(!ct_eq(b"abc", b"abcd"));
+        assert!(!ct_eq(b"abcd", b"abc"));
+        // Trailing zero bytes must not be mistaken for padding
+        assert!(!ct_eq(b"abc", b"abc\0"));
+        assert!(!ct_eq(&[0u8; 3], &[0u8; 4]));
+    }
+}
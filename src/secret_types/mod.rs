@@ -1,23 +1,33 @@
 //! Secure custom value types that redact content in display while preserving data in pipelines.
 
+mod constant_time;
 mod operations;
 mod secret_binary;
 mod secret_bool;
+mod secret_cellpath;
 mod secret_date;
+mod secret_duration;
+mod secret_filesize;
 mod secret_float;
 mod secret_int;
 mod secret_list;
+mod secret_range;
 mod secret_record;
 mod secret_string;
 
+pub(crate) use constant_time::ct_eq;
 pub(crate) use operations::secret_comparison_operation;
 pub(crate) use operations::secret_ordering_operation;
 
 pub use secret_binary::SecretBinary;
 pub use secret_bool::SecretBool;
+pub use secret_cellpath::SecretCellPath;
 pub use secret_date::SecretDate;
+pub use secret_duration::SecretDuration;
+pub use secret_filesize::SecretFilesize;
 pub use secret_float::SecretFloat;
 pub use secret_int::SecretInt;
 pub use secret_list::SecretList;
+pub use secret_range::SecretRange;
 pub use secret_record::SecretRecord;
 pub use secret_string::SecretString;
@@ -6,7 +6,7 @@ use nu_protocol::ast::Operator;
 use nu_protocol::CustomValue;
 use nu_protocol::{ShellError, Span, Value};
 
-use super::secret_comparison_operation;
+use super::{ct_eq, secret_comparison_operation};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
@@ -19,6 +19,7 @@ use crate::memory_optimizations::binary_optimization::OptimizedBinary;
 pub struct SecretBinary {
     inner: OptimizedBinary,
     redaction_template: Option<String>,
+    origin_span: Option<Span>,
 }
 
 // Functional serialization - serialize actual content for pipeline operations
@@ -28,10 +29,11 @@ impl Serialize for SecretBinary {
         S: Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut state = serializer.serialize_struct("SecretBinary", 2)?;
+        let mut state = serializer.serialize_struct("SecretBinary", 3)?;
         let bytes = self.inner.as_bytes();
         state.serialize_field("inner", bytes.as_ref())?;
         state.serialize_field("redaction_template", &self.redaction_template)?;
+        state.serialize_field("origin_span", &self.origin_span)?;
         state.end()
     }
 }
@@ -46,12 +48,14 @@ impl<'de> Deserialize<'de> for SecretBinary {
         struct SecretBinaryData {
             inner: Vec<u8>,
             redaction_template: Option<String>,
+            origin_span: Option<Span>,
         }
 
         let data = SecretBinaryData::deserialize(deserializer)?;
         Ok(SecretBinary {
             inner: OptimizedBinary::from_slice(&data.inner),
             redaction_template: data.redaction_template,
+            origin_span: data.origin_span,
         })
     }
 }
@@ -72,6 +76,7 @@ impl SecretBinary {
         Self {
             inner: OptimizedBinary::from_slice(&value),
             redaction_template: None,
+            origin_span: None,
         }
     }
 
@@ -80,9 +85,26 @@ impl SecretBinary {
         Self {
             inner: OptimizedBinary::from_slice(&value),
             redaction_template: Some(template),
+            origin_span: None,
         }
     }
 
+    /// Whether this secret carries its own redaction template
+    pub fn has_custom_template(&self) -> bool {
+        self.redaction_template.is_some()
+    }
+
+    /// Record the span of the value this secret was wrapped from
+    pub fn with_origin_span(mut self, span: Span) -> Self {
+        self.origin_span = Some(span);
+        self
+    }
+
+    /// Span of the value this secret was wrapped from, if known (safe to expose)
+    pub fn origin_span(&self) -> Option<Span> {
+        self.origin_span
+    }
+
     /// Get a reference to the inner binary data (for controlled access)
     pub fn reveal(&self) -> std::borrow::Cow<'_, [u8]> {
         self.inner.as_bytes()
@@ -103,6 +125,15 @@ impl SecretBinary {
         self.inner.is_empty()
     }
 
+    /// Create a new SecretBinary holding `value` with this secret's redaction template
+    pub fn with_value(&self, value: Vec<u8>) -> SecretBinary {
+        SecretBinary {
+            inner: OptimizedBinary::from_slice(&value),
+            redaction_template: self.redaction_template.clone(),
+            origin_span: self.origin_span,
+        }
+    }
+
     /// Get a byte at a specific index while preserving secrecy
     pub fn get(&self, index: usize) -> Option<u8> {
         let bytes = self.inner.as_bytes();
@@ -212,84 +243,10 @@ impl fmt::Debug for SecretBinary {
 
 impl PartialEq for SecretBinary {
     fn eq(&self, other: &Self) -> bool {
-        // Constant-time comparison to prevent timing attacks
-        // This follows the pattern used in cryptographic libraries
-
-        let len_a = self.inner.len();
-        let len_b = other.inner.len();
-
-        // First compare lengths in constant time
-        let len_eq = constant_time_eq_usize(len_a, len_b);
-
-        // Always compare min_len bytes to avoid timing differences
-        let min_len = len_a.min(len_b);
-        let max_len = len_a.max(len_b);
-
-        // Use constant-time slice comparison for the minimum length
-        let content_eq = if min_len > 0 {
-            let self_bytes = self.inner.as_bytes();
-            let other_bytes = other.inner.as_bytes();
-            constant_time_eq_slice(&self_bytes[..min_len], &other_bytes[..min_len])
-        } else {
-            1u8 // Empty slices are equal
-        };
-
-        // If lengths differ, ensure we still do some work to maintain timing
-        let _padding_work = if len_a != len_b {
-            // Do some computation with the extra bytes to maintain consistent timing
-            let extra_bytes = max_len - min_len;
-            let mut dummy = 0u8;
-            for i in 0..extra_bytes {
-                dummy ^= (i as u8).wrapping_add(0x42);
-            }
-            dummy
-        } else {
-            0x42
-        };
-
-        // Combine results in constant time
-        (len_eq & content_eq) == 1
+        ct_eq(self.inner.as_bytes(), other.inner.as_bytes())
     }
 }
 
-/// Constant-time equality check for usize values
-fn constant_time_eq_usize(a: usize, b: usize) -> u8 {
-    let diff = a ^ b;
-
-    // Handle both 32-bit and 64-bit systems properly
-    let diff = if usize::BITS >= 64 {
-        diff | diff.wrapping_shr(32)
-    } else {
-        diff
-    };
-    let diff = diff | diff.wrapping_shr(16);
-    let diff = diff | diff.wrapping_shr(8);
-    let diff = diff | diff.wrapping_shr(4);
-    let diff = diff | diff.wrapping_shr(2);
-    let diff = diff | diff.wrapping_shr(1);
-    (diff ^ 1) as u8
-}
-
-/// Constant-time equality check for byte slices of the same length
-fn constant_time_eq_slice(a: &[u8], b: &[u8]) -> u8 {
-    // This function assumes a.len() == b.len() for constant-time behavior
-    // The caller should ensure this precondition
-
-    let mut result = 0u8;
-    for i in 0..a.len() {
-        result |= a[i] ^ b[i];
-    }
-
-    // Convert to 0 or 1 using bit manipulation to avoid branches
-    let result = result as u32;
-    let result = result | result.wrapping_shr(16);
-    let result = result | result.wrapping_shr(8);
-    let result = result | result.wrapping_shr(4);
-    let result = result | result.wrapping_shr(2);
-    let result = result | result.wrapping_shr(1);
-    (result ^ 1) as u8
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
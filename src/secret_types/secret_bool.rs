@@ -6,7 +6,7 @@ use nu_protocol::ast::Operator;
 use nu_protocol::CustomValue;
 use nu_protocol::{ShellError, Span, Value};
 
-use super::secret_comparison_operation;
+use super::{ct_eq, secret_comparison_operation};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
@@ -18,6 +18,7 @@ use crate::config::RedactionContext;
 pub struct SecretBool {
     inner: bool,
     redaction_template: Option<String>,
+    origin_span: Option<Span>,
 }
 
 // Functional serialization - serialize actual content for pipeline operations
@@ -27,9 +28,10 @@ impl Serialize for SecretBool {
         S: Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut state = serializer.serialize_struct("SecretBool", 2)?;
+        let mut state = serializer.serialize_struct("SecretBool", 3)?;
         state.serialize_field("inner", &self.inner)?;
         state.serialize_field("redaction_template", &self.redaction_template)?;
+        state.serialize_field("origin_span", &self.origin_span)?;
         state.end()
     }
 }
@@ -44,12 +46,14 @@ impl<'de> Deserialize<'de> for SecretBool {
         struct SecretBoolData {
             inner: bool,
             redaction_template: Option<String>,
+            origin_span: Option<Span>,
         }
 
         let data = SecretBoolData::deserialize(deserializer)?;
         Ok(SecretBool {
             inner: data.inner,
             redaction_template: data.redaction_template,
+            origin_span: data.origin_span,
         })
     }
 }
@@ -70,6 +74,7 @@ impl SecretBool {
         Self {
             inner: value,
             redaction_template: None,
+            origin_span: None,
         }
     }
 
@@ -78,6 +83,32 @@ impl SecretBool {
         Self {
             inner: value,
             redaction_template: Some(template),
+            origin_span: None,
+        }
+    }
+
+    /// Whether this secret carries its own redaction template
+    pub fn has_custom_template(&self) -> bool {
+        self.redaction_template.is_some()
+    }
+
+    /// Record the span of the value this secret was wrapped from
+    pub fn with_origin_span(mut self, span: Span) -> Self {
+        self.origin_span = Some(span);
+        self
+    }
+
+    /// Span of the value this secret was wrapped from, if known (safe to expose)
+    pub fn origin_span(&self) -> Option<Span> {
+        self.origin_span
+    }
+
+    /// Create a new SecretBool holding `value` with this secret's redaction template and origin span
+    pub fn with_value(&self, value: bool) -> SecretBool {
+        SecretBool {
+            inner: value,
+            redaction_template: self.redaction_template.clone(),
+            origin_span: self.origin_span,
         }
     }
 
@@ -185,9 +216,8 @@ impl fmt::Debug for SecretBool {
 
 impl PartialEq for SecretBool {
     fn eq(&self, other: &Self) -> bool {
-        // Use constant-time comparison for security
-        // For booleans, we can use XOR
-        (self.inner as u8) ^ (other.inner as u8) == 0
+        // Constant-time comparison over the byte representation
+        ct_eq(&[self.inner as u8], &[other.inner as u8])
     }
 }
 
@@ -244,6 +274,15 @@ mod tests {
         assert_ne!(secret1, secret3);
     }
 
+    #[test]
+    fn test_secret_bool_equality_all_pairs() {
+        for a in [true, false] {
+            for b in [true, false] {
+                assert_eq!(SecretBool::new(a) == SecretBool::new(b), a == b);
+            }
+        }
+    }
+
     #[test]
     fn test_secret_bool_into_inner() {
         let secret = SecretBool::new(true);
@@ -0,0 +1,337 @@
+//! Secure cell path type with redacted display.
+
+use std::fmt;
+
+use nu_protocol::ast::{CellPath, Operator, PathMember};
+use nu_protocol::CustomValue;
+use nu_protocol::{ShellError, Span, Value};
+
+use super::{ct_eq, secret_comparison_operation};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::config::RedactionContext;
+
+/// A secure cell path type that redacts its content in all display contexts
+///
+/// Useful when the path into a data structure (e.g. which column holds PII) is
+/// itself sensitive. `reveal()` and `into_inner()` return the wrapped path.
+#[derive(Clone)]
+pub struct SecretCellPath {
+    inner: CellPath,
+    redaction_template: Option<String>,
+    origin_span: Option<Span>,
+}
+
+// Functional serialization - serialize actual content for pipeline operations
+impl Serialize for SecretCellPath {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("SecretCellPath", 3)?;
+        state.serialize_field("inner", &self.inner)?;
+        state.serialize_field("redaction_template", &self.redaction_template)?;
+        state.serialize_field("origin_span", &self.origin_span)?;
+        state.end()
+    }
+}
+
+// Functional deserialization - restore actual content for pipeline operations
+impl<'de> Deserialize<'de> for SecretCellPath {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct SecretCellPathData {
+            inner: CellPath,
+            redaction_template: Option<String>,
+            origin_span: Option<Span>,
+        }
+
+        let data = SecretCellPathData::deserialize(deserializer)?;
+        Ok(SecretCellPath {
+            inner: data.inner,
+            redaction_template: data.redaction_template,
+            origin_span: data.origin_span,
+        })
+    }
+}
+
+impl Drop for SecretCellPath {
+    fn drop(&mut self) {
+        // Zeroization is best-effort: column names are cleared, while integer
+        // members hold no heap data and are released by the standard drop.
+        for member in &mut self.inner.members {
+            if let PathMember::String { val, .. } = member {
+                val.zeroize();
+            }
+        }
+    }
+}
+
+// Manual ZeroizeOnDrop implementation to ensure proper cleanup
+impl ZeroizeOnDrop for SecretCellPath {}
+
+/// Encode the members of `path` without their spans, for comparison.
+fn path_bytes(path: &CellPath) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for member in &path.members {
+        match member {
+            PathMember::String { val, optional, .. } => {
+                bytes.extend_from_slice(&[0, *optional as u8]);
+                bytes.extend_from_slice(&(val.len() as u64).to_le_bytes());
+                bytes.extend_from_slice(val.as_bytes());
+            }
+            PathMember::Int { val, optional, .. } => {
+                bytes.extend_from_slice(&[1, *optional as u8]);
+                bytes.extend_from_slice(&(*val as u64).to_le_bytes());
+            }
+        }
+    }
+    bytes
+}
+
+impl SecretCellPath {
+    /// Create a new SecretCellPath from a cell path
+    pub fn new(value: CellPath) -> Self {
+        Self {
+            inner: value,
+            redaction_template: None,
+            origin_span: None,
+        }
+    }
+
+    /// Create a new SecretCellPath with a custom redaction template
+    pub fn new_with_template(value: CellPath, template: String) -> Self {
+        Self {
+            inner: value,
+            redaction_template: Some(template),
+            origin_span: None,
+        }
+    }
+
+    /// Whether this secret carries its own redaction template
+    pub fn has_custom_template(&self) -> bool {
+        self.redaction_template.is_some()
+    }
+
+    /// Record the span of the value this secret was wrapped from
+    pub fn with_origin_span(mut self, span: Span) -> Self {
+        self.origin_span = Some(span);
+        self
+    }
+
+    /// Span of the value this secret was wrapped from, if known (safe to expose)
+    pub fn origin_span(&self) -> Option<Span> {
+        self.origin_span
+    }
+
+    /// Create a new SecretCellPath holding `value` with this secret's redaction template and origin span
+    pub fn with_value(&self, value: CellPath) -> SecretCellPath {
+        SecretCellPath {
+            inner: value,
+            redaction_template: self.redaction_template.clone(),
+            origin_span: self.origin_span,
+        }
+    }
+
+    /// Get a reference to the inner cell path (for controlled access)
+    pub fn reveal(&self) -> &CellPath {
+        &self.inner
+    }
+
+    /// Convert back to a cell path (consumes the SecretCellPath)
+    pub fn into_inner(self) -> CellPath {
+        self.inner.clone()
+    }
+
+    /// Render the redaction text for `context`
+    fn redacted_text(&self, context: RedactionContext) -> String {
+        if let Some(template) = &self.redaction_template {
+            let path_str = self.inner.to_column_name();
+            crate::redaction::generate_redacted_string_with_custom_template_and_value(
+                template,
+                "cellpath",
+                Some(self.inner.members.len()),
+                Some(path_str),
+            )
+        } else {
+            crate::redaction::get_redacted_string_with_value::<String>("cellpath", context, None)
+        }
+    }
+}
+
+#[typetag::serde]
+impl CustomValue for SecretCellPath {
+    fn clone_value(&self, span: Span) -> Value {
+        Value::custom(Box::new(self.clone()), span)
+    }
+
+    fn type_name(&self) -> String {
+        "secret_cellpath".into()
+    }
+
+    fn to_base_value(&self, span: Span) -> Result<Value, ShellError> {
+        Ok(Value::string(
+            self.redacted_text(RedactionContext::Serialization),
+            span,
+        ))
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn notify_plugin_on_drop(&self) -> bool {
+        false // We handle cleanup via ZeroizeOnDrop
+    }
+
+    fn operation(
+        &self,
+        lhs_span: Span,
+        operator: Operator,
+        op: Span,
+        right: &Value,
+    ) -> Result<Value, ShellError> {
+        secret_comparison_operation(self, lhs_span, operator, op, right, "secret_cellpath")
+    }
+}
+
+impl fmt::Display for SecretCellPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.redacted_text(RedactionContext::Display))
+    }
+}
+
+impl fmt::Debug for SecretCellPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "SecretCellPath({})",
+            self.redacted_text(RedactionContext::Debug)
+        )
+    }
+}
+
+impl PartialEq for SecretCellPath {
+    fn eq(&self, other: &Self) -> bool {
+        // Compare members (ignoring spans) using constant-time comparison
+        ct_eq(&path_bytes(&self.inner), &path_bytes(&other.inner))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nu_protocol::ast::Comparison;
+
+    fn column(name: &str, span: Span) -> PathMember {
+        PathMember::String {
+            val: name.to_string(),
+            span,
+            optional: false,
+            casing: Default::default(),
+        }
+    }
+
+    fn path(span: Span) -> CellPath {
+        CellPath {
+            members: vec![
+                column("users", span),
+                PathMember::Int {
+                    val: 0,
+                    span,
+                    optional: false,
+                },
+                column("ssn", span),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_secret_cellpath_creation() {
+        let secret = SecretCellPath::new(path(Span::test_data()));
+        assert_eq!(secret.reveal(), &path(Span::test_data()));
+    }
+
+    #[test]
+    fn test_secret_cellpath_display() {
+        let secret = SecretCellPath::new(path(Span::test_data()));
+        let display_result = format!("{}", secret);
+        let debug_result = format!("{:?}", secret);
+
+        assert_eq!(display_result, "<redacted:cellpath>");
+        assert!(!display_result.contains("ssn"));
+        assert!(debug_result.contains("SecretCellPath"));
+        assert!(!debug_result.contains("ssn"));
+    }
+
+    #[test]
+    fn test_secret_cellpath_custom_value() {
+        let secret = SecretCellPath::new(path(Span::test_data()));
+        assert_eq!(secret.type_name(), "secret_cellpath");
+
+        let base_value = secret.to_base_value(Span::test_data()).unwrap();
+        match base_value {
+            Value::String { val, .. } => assert_eq!(val, "<redacted:cellpath>"),
+            _ => panic!("Expected string value"),
+        }
+    }
+
+    #[test]
+    fn test_secret_cellpath_equality_ignores_spans() {
+        let secret1 = SecretCellPath::new(path(Span::test_data()));
+        let secret2 = SecretCellPath::new(path(Span::new(10, 20)));
+        let secret3 = SecretCellPath::new(CellPath {
+            members: vec![column("users", Span::test_data())],
+        });
+
+        assert_eq!(secret1, secret2);
+        assert_ne!(secret1, secret3);
+    }
+
+    #[test]
+    fn test_secret_cellpath_operations() {
+        let span = Span::test_data();
+        let secret = SecretCellPath::new(path(span));
+        let same = Value::custom(Box::new(SecretCellPath::new(path(span))), span);
+
+        let equal = secret
+            .operation(span, Operator::Comparison(Comparison::Equal), span, &same)
+            .unwrap();
+        assert!(equal.as_bool().unwrap());
+
+        assert!(secret
+            .operation(
+                span,
+                Operator::Comparison(Comparison::LessThan),
+                span,
+                &same
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn test_secret_cellpath_into_inner() {
+        let secret = SecretCellPath::new(path(Span::test_data()));
+        assert_eq!(secret.into_inner(), path(Span::test_data()));
+    }
+
+    #[test]
+    fn test_secret_cellpath_with_custom_template() {
+        let secret = SecretCellPath::new_with_template(
+            path(Span::test_data()),
+            "{{secret_type}}_HIDDEN".to_string(),
+        );
+
+        assert_eq!(format!("{}", secret), "cellpath_HIDDEN");
+        assert_eq!(format!("{:?}", secret), "SecretCellPath(cellpath_HIDDEN)");
+    }
+}
@@ -7,7 +7,7 @@ use nu_protocol::ast::Operator;
 use nu_protocol::CustomValue;
 use nu_protocol::{ShellError, Span, Value};
 
-use super::{secret_comparison_operation, secret_ordering_operation};
+use super::{ct_eq, secret_comparison_operation, secret_ordering_operation};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use zeroize::ZeroizeOnDrop;
 
@@ -19,6 +19,7 @@ use crate::config::RedactionContext;
 pub struct SecretDate {
     inner: chrono::DateTime<chrono::FixedOffset>,
     redaction_template: Option<String>,
+    origin_span: Option<Span>,
 }
 
 // Functional serialization - serialize actual content for pipeline operations
@@ -28,9 +29,10 @@ impl Serialize for SecretDate {
         S: Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut state = serializer.serialize_struct("SecretDate", 2)?;
+        let mut state = serializer.serialize_struct("SecretDate", 3)?;
         state.serialize_field("inner", &self.inner)?;
         state.serialize_field("redaction_template", &self.redaction_template)?;
+        state.serialize_field("origin_span", &self.origin_span)?;
         state.end()
     }
 }
@@ -45,12 +47,14 @@ impl<'de> Deserialize<'de> for SecretDate {
         struct SecretDateData {
             inner: chrono::DateTime<chrono::FixedOffset>,
             redaction_template: Option<String>,
+            origin_span: Option<Span>,
         }
 
         let data = SecretDateData::deserialize(deserializer)?;
         Ok(SecretDate {
             inner: data.inner,
             redaction_template: data.redaction_template,
+            origin_span: data.origin_span,
         })
     }
 }
@@ -72,6 +76,7 @@ impl SecretDate {
         Self {
             inner: value,
             redaction_template: None,
+            origin_span: None,
         }
     }
 
@@ -83,6 +88,32 @@ impl SecretDate {
         Self {
             inner: value,
             redaction_template: Some(template),
+            origin_span: None,
+        }
+    }
+
+    /// Whether this secret carries its own redaction template
+    pub fn has_custom_template(&self) -> bool {
+        self.redaction_template.is_some()
+    }
+
+    /// Record the span of the value this secret was wrapped from
+    pub fn with_origin_span(mut self, span: Span) -> Self {
+        self.origin_span = Some(span);
+        self
+    }
+
+    /// Span of the value this secret was wrapped from, if known (safe to expose)
+    pub fn origin_span(&self) -> Option<Span> {
+        self.origin_span
+    }
+
+    /// Create a new SecretDate holding `value` with this secret's redaction template and origin span
+    pub fn with_value(&self, value: chrono::DateTime<chrono::FixedOffset>) -> SecretDate {
+        SecretDate {
+            inner: value,
+            redaction_template: self.redaction_template.clone(),
+            origin_span: self.origin_span,
         }
     }
 
@@ -110,6 +141,16 @@ impl SecretDate {
     pub fn is_after(&self, other: &SecretDate) -> bool {
         self.inner > other.inner
     }
+
+    /// Convert to another timezone, keeping the result wrapped
+    /// The instant is unchanged and the redaction template is preserved
+    pub fn with_timezone(&self, offset: &chrono::FixedOffset) -> SecretDate {
+        SecretDate {
+            inner: self.inner.with_timezone(offset),
+            redaction_template: self.redaction_template.clone(),
+            origin_span: self.origin_span,
+        }
+    }
 }
 
 #[typetag::serde]
@@ -211,22 +252,21 @@ impl fmt::Debug for SecretDate {
     }
 }
 
+/// Fixed-width little-endian representation of a date: seconds since the
+/// epoch, sub-second nanoseconds and the UTC offset in seconds.
+fn date_bytes(date: &chrono::DateTime<chrono::FixedOffset>) -> [u8; 16] {
+    let mut bytes = [0u8; 16];
+    bytes[..8].copy_from_slice(&date.timestamp().to_le_bytes());
+    bytes[8..12].copy_from_slice(&date.timestamp_subsec_nanos().to_le_bytes());
+    bytes[12..].copy_from_slice(&date.offset().local_minus_utc().to_le_bytes());
+    bytes
+}
+
 impl PartialEq for SecretDate {
+    /// Two dates are equal when they name the same instant with the same UTC
+    /// offset, compared in constant time.
     fn eq(&self, other: &Self) -> bool {
-        // Use constant-time comparison for security
-        // DateTime implements Eq, so we can serialize for comparison
-        let self_ser = bincode::serialize(&self.inner).unwrap_or_default();
-        let other_ser = bincode::serialize(&other.inner).unwrap_or_default();
-
-        if self_ser.len() != other_ser.len() {
-            return false;
-        }
-
-        let mut result = 0u8;
-        for i in 0..self_ser.len() {
-            result |= self_ser[i] ^ other_ser[i];
-        }
-        result == 0
+        ct_eq(&date_bytes(&self.inner), &date_bytes(&other.inner))
     }
 }
 
@@ -310,6 +350,26 @@ mod tests {
         assert_ne!(secret1, secret3);
     }
 
+    #[test]
+    fn test_secret_date_equality_boundaries() {
+        let dt = test_datetime();
+        let one_nano_later = dt + chrono::Duration::nanoseconds(1);
+        let same_instant_other_offset =
+            dt.with_timezone(&chrono::FixedOffset::east_opt(3600).unwrap());
+
+        assert_ne!(SecretDate::new(dt), SecretDate::new(one_nano_later));
+        assert_ne!(
+            SecretDate::new(dt),
+            SecretDate::new(same_instant_other_offset)
+        );
+
+        let epoch: chrono::DateTime<chrono::FixedOffset> = Utc.timestamp_opt(0, 0).unwrap().into();
+        let before_epoch: chrono::DateTime<chrono::FixedOffset> =
+            Utc.timestamp_opt(-1, 999_999_999).unwrap().into();
+        assert_eq!(SecretDate::new(epoch), SecretDate::new(epoch));
+        assert_ne!(SecretDate::new(epoch), SecretDate::new(before_epoch));
+    }
+
     #[test]
     fn test_secret_date_comparisons() {
         let early_dt = test_datetime();
@@ -334,6 +394,18 @@ mod tests {
         assert_eq!(secret.year(), 2023);
     }
 
+    #[test]
+    fn test_secret_date_with_timezone() {
+        let dt = test_datetime();
+        let secret = SecretDate::new(dt);
+        let offset = chrono::FixedOffset::east_opt(5 * 3600 + 30 * 60).unwrap();
+
+        let converted = secret.with_timezone(&offset);
+        assert_eq!(converted.reveal(), &dt); // same instant
+        assert_eq!(converted.reveal().offset(), &offset);
+        assert!(format!("{}", converted).contains("redacted"));
+    }
+
     #[test]
     fn test_secret_date_serialization() {
         // Test that serialization works for functional unwrap
@@ -0,0 +1,328 @@
+//! Secure duration type with redacted display and zeroed-on-drop memory.
+
+use std::fmt;
+
+use nu_protocol::ast::Operator;
+use nu_protocol::CustomValue;
+use nu_protocol::{ShellError, Span, Value};
+
+use super::{ct_eq, secret_comparison_operation};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::config::RedactionContext;
+
+/// A secure duration type that redacts its content in all display contexts
+/// and zeros its memory on drop
+///
+/// The duration is stored as a signed number of nanoseconds, matching
+/// `Value::Duration`.
+#[derive(Clone)]
+pub struct SecretDuration {
+    inner: i64,
+    redaction_template: Option<String>,
+    origin_span: Option<Span>,
+}
+
+// Functional serialization - serialize actual content for pipeline operations
+impl Serialize for SecretDuration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("SecretDuration", 3)?;
+        state.serialize_field("inner", &self.inner)?;
+        state.serialize_field("redaction_template", &self.redaction_template)?;
+        state.serialize_field("origin_span", &self.origin_span)?;
+        state.end()
+    }
+}
+
+// Functional deserialization - restore actual content for pipeline operations
+impl<'de> Deserialize<'de> for SecretDuration {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct SecretDurationData {
+            inner: i64,
+            redaction_template: Option<String>,
+            origin_span: Option<Span>,
+        }
+
+        let data = SecretDurationData::deserialize(deserializer)?;
+        Ok(SecretDuration {
+            inner: data.inner,
+            redaction_template: data.redaction_template,
+            origin_span: data.origin_span,
+        })
+    }
+}
+
+impl Drop for SecretDuration {
+    fn drop(&mut self) {
+        // Explicitly zero the duration memory for security
+        self.inner.zeroize();
+    }
+}
+
+// Manual ZeroizeOnDrop implementation to ensure proper cleanup
+impl ZeroizeOnDrop for SecretDuration {}
+
+impl SecretDuration {
+    /// Create a new SecretDuration from a duration in nanoseconds
+    pub fn new(value: i64) -> Self {
+        Self {
+            inner: value,
+            redaction_template: None,
+            origin_span: None,
+        }
+    }
+
+    /// Create a new SecretDuration with a custom redaction template
+    pub fn new_with_template(value: i64, template: String) -> Self {
+        Self {
+            inner: value,
+            redaction_template: Some(template),
+            origin_span: None,
+        }
+    }
+
+    /// Whether this secret carries its own redaction template
+    pub fn has_custom_template(&self) -> bool {
+        self.redaction_template.is_some()
+    }
+
+    /// Record the span of the value this secret was wrapped from
+    pub fn with_origin_span(mut self, span: Span) -> Self {
+        self.origin_span = Some(span);
+        self
+    }
+
+    /// Span of the value this secret was wrapped from, if known (safe to expose)
+    pub fn origin_span(&self) -> Option<Span> {
+        self.origin_span
+    }
+
+    /// Create a new SecretDuration holding `value` with this secret's redaction template and origin span
+    pub fn with_value(&self, value: i64) -> SecretDuration {
+        SecretDuration {
+            inner: value,
+            redaction_template: self.redaction_template.clone(),
+            origin_span: self.origin_span,
+        }
+    }
+
+    /// Get the inner duration in nanoseconds (for controlled access)
+    pub fn reveal(&self) -> i64 {
+        self.inner
+    }
+
+    /// Convert back to nanoseconds (consumes the SecretDuration)
+    pub fn into_inner(self) -> i64 {
+        self.inner
+    }
+}
+
+#[typetag::serde]
+impl CustomValue for SecretDuration {
+    fn clone_value(&self, span: Span) -> Value {
+        Value::custom(Box::new(self.clone()), span)
+    }
+
+    fn type_name(&self) -> String {
+        "secret_duration".into()
+    }
+
+    fn to_base_value(&self, span: Span) -> Result<Value, ShellError> {
+        let redacted_text = if let Some(template) = &self.redaction_template {
+            crate::redaction::get_redacted_string_with_custom_template_and_value(
+                template,
+                "duration",
+                RedactionContext::Serialization,
+                Some(&self.inner),
+            )
+        } else {
+            crate::redaction::get_redacted_string_with_value(
+                "duration",
+                RedactionContext::Serialization,
+                Some(&self.inner),
+            )
+        };
+        Ok(Value::string(redacted_text, span))
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn notify_plugin_on_drop(&self) -> bool {
+        false // We handle cleanup via ZeroizeOnDrop
+    }
+
+    fn operation(
+        &self,
+        lhs_span: Span,
+        operator: Operator,
+        op: Span,
+        right: &Value,
+    ) -> Result<Value, ShellError> {
+        secret_comparison_operation(self, lhs_span, operator, op, right, "secret_duration")
+    }
+}
+
+impl fmt::Display for SecretDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let redacted_text = if let Some(template) = &self.redaction_template {
+            crate::redaction::get_redacted_string_with_custom_template_and_value(
+                template,
+                "duration",
+                RedactionContext::Display,
+                Some(&self.inner),
+            )
+        } else {
+            crate::redaction::get_redacted_string_with_value(
+                "duration",
+                RedactionContext::Display,
+                Some(&self.inner),
+            )
+        };
+        write!(f, "{}", redacted_text)
+    }
+}
+
+impl fmt::Debug for SecretDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let redacted_text = if let Some(template) = &self.redaction_template {
+            crate::redaction::get_redacted_string_with_custom_template_and_value(
+                template,
+                "duration",
+                RedactionContext::Debug,
+                Some(&self.inner),
+            )
+        } else {
+            crate::redaction::get_redacted_string_with_value(
+                "duration",
+                RedactionContext::Debug,
+                Some(&self.inner),
+            )
+        };
+        write!(f, "SecretDuration({})", redacted_text)
+    }
+}
+
+impl PartialEq for SecretDuration {
+    fn eq(&self, other: &Self) -> bool {
+        ct_eq(&self.inner.to_le_bytes(), &other.inner.to_le_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ONE_HOUR_NS: i64 = 3_600_000_000_000;
+
+    #[test]
+    fn test_secret_duration_creation() {
+        let secret = SecretDuration::new(ONE_HOUR_NS);
+        assert_eq!(secret.reveal(), ONE_HOUR_NS);
+    }
+
+    #[test]
+    fn test_secret_duration_display() {
+        let secret = SecretDuration::new(ONE_HOUR_NS);
+        let display_result = format!("{}", secret);
+        let debug_result = format!("{:?}", secret);
+
+        assert!(
+            display_result.contains("redacted")
+                || display_result.contains("***")
+                || display_result.contains("HIDDEN")
+        );
+        assert!(!display_result.contains("3600000000000"));
+        assert!(debug_result.contains("SecretDuration"));
+    }
+
+    #[test]
+    fn test_secret_duration_custom_value() {
+        let secret = SecretDuration::new(ONE_HOUR_NS);
+        assert_eq!(secret.type_name(), "secret_duration");
+
+        let base_value = secret.to_base_value(Span::test_data()).unwrap();
+        match base_value {
+            Value::String { val, .. } => {
+                assert!(val.contains("redacted") || val.contains("***") || val.contains("HIDDEN"));
+            }
+            _ => panic!("Expected string value"),
+        }
+    }
+
+    #[test]
+    fn test_secret_duration_equality() {
+        let secret1 = SecretDuration::new(ONE_HOUR_NS);
+        let secret2 = SecretDuration::new(ONE_HOUR_NS);
+        let secret3 = SecretDuration::new(1_000_000_000);
+
+        assert_eq!(secret1, secret2);
+        assert_ne!(secret1, secret3);
+    }
+
+    #[test]
+    fn test_secret_duration_operations() {
+        let span = Span::test_data();
+        let secret = SecretDuration::new(ONE_HOUR_NS);
+        let same = Value::custom(Box::new(SecretDuration::new(ONE_HOUR_NS)), span);
+
+        let equal = secret
+            .operation(
+                span,
+                Operator::Comparison(nu_protocol::ast::Comparison::Equal),
+                span,
+                &same,
+            )
+            .unwrap();
+        assert!(equal.as_bool().unwrap());
+
+        let not_equal = secret
+            .operation(
+                span,
+                Operator::Comparison(nu_protocol::ast::Comparison::NotEqual),
+                span,
+                &same,
+            )
+            .unwrap();
+        assert!(!not_equal.as_bool().unwrap());
+
+        assert!(secret
+            .operation(
+                span,
+                Operator::Comparison(nu_protocol::ast::Comparison::LessThan),
+                span,
+                &same,
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn test_secret_duration_into_inner() {
+        let secret = SecretDuration::new(-5);
+        assert_eq!(secret.into_inner(), -5);
+    }
+
+    #[test]
+    fn test_secret_duration_with_custom_template() {
+        let secret =
+            SecretDuration::new_with_template(ONE_HOUR_NS, "{{secret_type}}_HIDDEN".to_string());
+
+        assert_eq!(format!("{}", secret), "duration_HIDDEN");
+        assert_eq!(format!("{:?}", secret), "SecretDuration(duration_HIDDEN)");
+        assert_eq!(secret.reveal(), ONE_HOUR_NS);
+    }
+}
@@ -0,0 +1,305 @@
+//! Secure filesize type with redacted display and zeroed-on-drop memory.
+
+use std::fmt;
+
+use nu_protocol::ast::Operator;
+use nu_protocol::CustomValue;
+use nu_protocol::{ShellError, Span, Value};
+
+use super::{ct_eq, secret_comparison_operation, secret_ordering_operation};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::config::RedactionContext;
+
+/// A secure filesize type that redacts its content in all display contexts
+/// and zeros its memory on drop
+///
+/// The filesize is stored as a signed number of bytes, matching `Value::Filesize`.
+#[derive(Clone)]
+pub struct SecretFilesize {
+    inner: i64,
+    redaction_template: Option<String>,
+    origin_span: Option<Span>,
+}
+
+// Functional serialization - serialize actual content for pipeline operations
+impl Serialize for SecretFilesize {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("SecretFilesize", 3)?;
+        state.serialize_field("inner", &self.inner)?;
+        state.serialize_field("redaction_template", &self.redaction_template)?;
+        state.serialize_field("origin_span", &self.origin_span)?;
+        state.end()
+    }
+}
+
+// Functional deserialization - restore actual content for pipeline operations
+impl<'de> Deserialize<'de> for SecretFilesize {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct SecretFilesizeData {
+            inner: i64,
+            redaction_template: Option<String>,
+            origin_span: Option<Span>,
+        }
+
+        let data = SecretFilesizeData::deserialize(deserializer)?;
+        Ok(SecretFilesize {
+            inner: data.inner,
+            redaction_template: data.redaction_template,
+            origin_span: data.origin_span,
+        })
+    }
+}
+
+impl Drop for SecretFilesize {
+    fn drop(&mut self) {
+        // Explicitly zero the filesize memory for security
+        self.inner.zeroize();
+    }
+}
+
+// Manual ZeroizeOnDrop implementation to ensure proper cleanup
+impl ZeroizeOnDrop for SecretFilesize {}
+
+impl SecretFilesize {
+    /// Create a new SecretFilesize from a size in bytes
+    pub fn new(value: i64) -> Self {
+        Self {
+            inner: value,
+            redaction_template: None,
+            origin_span: None,
+        }
+    }
+
+    /// Create a new SecretFilesize with a custom redaction template
+    pub fn new_with_template(value: i64, template: String) -> Self {
+        Self {
+            inner: value,
+            redaction_template: Some(template),
+            origin_span: None,
+        }
+    }
+
+    /// Whether this secret carries its own redaction template
+    pub fn has_custom_template(&self) -> bool {
+        self.redaction_template.is_some()
+    }
+
+    /// Record the span of the value this secret was wrapped from
+    pub fn with_origin_span(mut self, span: Span) -> Self {
+        self.origin_span = Some(span);
+        self
+    }
+
+    /// Span of the value this secret was wrapped from, if known (safe to expose)
+    pub fn origin_span(&self) -> Option<Span> {
+        self.origin_span
+    }
+
+    /// Create a new SecretFilesize holding `value` with this secret's redaction template and origin span
+    pub fn with_value(&self, value: i64) -> SecretFilesize {
+        SecretFilesize {
+            inner: value,
+            redaction_template: self.redaction_template.clone(),
+            origin_span: self.origin_span,
+        }
+    }
+
+    /// Get the inner size in bytes (for controlled access)
+    pub fn reveal(&self) -> i64 {
+        self.inner
+    }
+
+    /// Convert back to a size in bytes (consumes the SecretFilesize)
+    pub fn into_inner(self) -> i64 {
+        self.inner
+    }
+}
+
+#[typetag::serde]
+impl CustomValue for SecretFilesize {
+    fn clone_value(&self, span: Span) -> Value {
+        Value::custom(Box::new(self.clone()), span)
+    }
+
+    fn type_name(&self) -> String {
+        "secret_filesize".into()
+    }
+
+    fn to_base_value(&self, span: Span) -> Result<Value, ShellError> {
+        let redacted_text = if let Some(template) = &self.redaction_template {
+            crate::redaction::get_redacted_string_with_custom_template_and_value(
+                template,
+                "filesize",
+                RedactionContext::Serialization,
+                Some(&self.inner),
+            )
+        } else {
+            crate::redaction::get_redacted_string_with_value(
+                "filesize",
+                RedactionContext::Serialization,
+                Some(&self.inner),
+            )
+        };
+        Ok(Value::string(redacted_text, span))
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn notify_plugin_on_drop(&self) -> bool {
+        false // We handle cleanup via ZeroizeOnDrop
+    }
+
+    fn operation(
+        &self,
+        lhs_span: Span,
+        operator: Operator,
+        op: Span,
+        right: &Value,
+    ) -> Result<Value, ShellError> {
+        match operator {
+            Operator::Comparison(
+                nu_protocol::ast::Comparison::LessThan
+                | nu_protocol::ast::Comparison::GreaterThan
+                | nu_protocol::ast::Comparison::LessThanOrEqual
+                | nu_protocol::ast::Comparison::GreaterThanOrEqual,
+            ) => secret_ordering_operation(self, lhs_span, operator, op, right, "secret_filesize"),
+            _ => {
+                secret_comparison_operation(self, lhs_span, operator, op, right, "secret_filesize")
+            }
+        }
+    }
+}
+
+impl fmt::Display for SecretFilesize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let redacted_text = if let Some(template) = &self.redaction_template {
+            crate::redaction::get_redacted_string_with_custom_template_and_value(
+                template,
+                "filesize",
+                RedactionContext::Display,
+                Some(&self.inner),
+            )
+        } else {
+            crate::redaction::get_redacted_string_with_value(
+                "filesize",
+                RedactionContext::Display,
+                Some(&self.inner),
+            )
+        };
+        write!(f, "{}", redacted_text)
+    }
+}
+
+impl fmt::Debug for SecretFilesize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let redacted_text = if let Some(template) = &self.redaction_template {
+            crate::redaction::get_redacted_string_with_custom_template_and_value(
+                template,
+                "filesize",
+                RedactionContext::Debug,
+                Some(&self.inner),
+            )
+        } else {
+            crate::redaction::get_redacted_string_with_value(
+                "filesize",
+                RedactionContext::Debug,
+                Some(&self.inner),
+            )
+        };
+        write!(f, "SecretFilesize({})", redacted_text)
+    }
+}
+
+impl PartialEq for SecretFilesize {
+    fn eq(&self, other: &Self) -> bool {
+        ct_eq(&self.inner.to_le_bytes(), &other.inner.to_le_bytes())
+    }
+}
+
+impl PartialOrd for SecretFilesize {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.inner.cmp(&other.inner))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_secret_filesize_creation() {
+        let secret = SecretFilesize::new(1_048_576);
+        assert_eq!(secret.reveal(), 1_048_576);
+    }
+
+    #[test]
+    fn test_secret_filesize_display() {
+        let secret = SecretFilesize::new(1_048_576);
+        let display_result = format!("{}", secret);
+        let debug_result = format!("{:?}", secret);
+
+        assert!(
+            display_result.contains("redacted")
+                || display_result.contains("***")
+                || display_result.contains("HIDDEN")
+        );
+        assert!(!display_result.contains("1048576"));
+        assert!(debug_result.contains("SecretFilesize"));
+    }
+
+    #[test]
+    fn test_secret_filesize_custom_value() {
+        let secret = SecretFilesize::new(2048);
+        assert_eq!(secret.type_name(), "secret_filesize");
+
+        let base_value = secret.to_base_value(Span::test_data()).unwrap();
+        match base_value {
+            Value::String { val, .. } => {
+                assert!(val.contains("redacted") || val.contains("***") || val.contains("HIDDEN"));
+            }
+            _ => panic!("Expected string value"),
+        }
+    }
+
+    #[test]
+    fn test_secret_filesize_equality_and_ordering() {
+        let small = SecretFilesize::new(1024);
+        let same = SecretFilesize::new(1024);
+        let large = SecretFilesize::new(4096);
+
+        assert_eq!(small, same);
+        assert_ne!(small, large);
+        assert!(small < large);
+    }
+
+    #[test]
+    fn test_secret_filesize_into_inner() {
+        let secret = SecretFilesize::new(512);
+        assert_eq!(secret.into_inner(), 512);
+    }
+
+    #[test]
+    fn test_secret_filesize_with_custom_template() {
+        let secret = SecretFilesize::new_with_template(2048, "{{secret_type}}_HIDDEN".to_string());
+
+        assert_eq!(format!("{}", secret), "filesize_HIDDEN");
+        assert_eq!(format!("{:?}", secret), "SecretFilesize(filesize_HIDDEN)");
+        assert_eq!(secret.reveal(), 2048);
+    }
+}
@@ -6,7 +6,7 @@ use nu_protocol::ast::Operator;
 use nu_protocol::CustomValue;
 use nu_protocol::{ShellError, Span, Value};
 
-use super::{secret_comparison_operation, secret_ordering_operation};
+use super::{ct_eq, secret_comparison_operation, secret_ordering_operation};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
@@ -18,6 +18,7 @@ use crate::config::RedactionContext;
 pub struct SecretFloat {
     inner: f64,
     redaction_template: Option<String>,
+    origin_span: Option<Span>,
 }
 
 // Functional serialization - serialize actual content for pipeline operations
@@ -27,9 +28,10 @@ impl Serialize for SecretFloat {
         S: Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut state = serializer.serialize_struct("SecretFloat", 2)?;
+        let mut state = serializer.serialize_struct("SecretFloat", 3)?;
         state.serialize_field("inner", &self.inner)?;
         state.serialize_field("redaction_template", &self.redaction_template)?;
+        state.serialize_field("origin_span", &self.origin_span)?;
         state.end()
     }
 }
@@ -44,12 +46,14 @@ impl<'de> Deserialize<'de> for SecretFloat {
         struct SecretFloatData {
             inner: f64,
             redaction_template: Option<String>,
+            origin_span: Option<Span>,
         }
 
         let data = SecretFloatData::deserialize(deserializer)?;
         Ok(SecretFloat {
             inner: data.inner,
             redaction_template: data.redaction_template,
+            origin_span: data.origin_span,
         })
     }
 }
@@ -70,6 +74,7 @@ impl SecretFloat {
         Self {
             inner: value,
             redaction_template: None,
+            origin_span: None,
         }
     }
 
@@ -78,6 +83,32 @@ impl SecretFloat {
         Self {
             inner: value,
             redaction_template: Some(template),
+            origin_span: None,
+        }
+    }
+
+    /// Whether this secret carries its own redaction template
+    pub fn has_custom_template(&self) -> bool {
+        self.redaction_template.is_some()
+    }
+
+    /// Record the span of the value this secret was wrapped from
+    pub fn with_origin_span(mut self, span: Span) -> Self {
+        self.origin_span = Some(span);
+        self
+    }
+
+    /// Span of the value this secret was wrapped from, if known (safe to expose)
+    pub fn origin_span(&self) -> Option<Span> {
+        self.origin_span
+    }
+
+    /// Create a new SecretFloat holding `value` with this secret's redaction template and origin span
+    pub fn with_value(&self, value: f64) -> SecretFloat {
+        SecretFloat {
+            inner: value,
+            redaction_template: self.redaction_template.clone(),
+            origin_span: self.origin_span,
         }
     }
 
@@ -207,22 +238,16 @@ impl fmt::Debug for SecretFloat {
 }
 
 impl PartialEq for SecretFloat {
+    /// Equality is defined on the IEEE 754 bit pattern, compared in constant time.
+    ///
+    /// Unlike `f64` equality, a NaN equals another NaN with the same bit pattern
+    /// (so `f64::NAN == f64::NAN`), NaNs with different payloads are unequal, and
+    /// `0.0` and `-0.0` are unequal.
     fn eq(&self, other: &Self) -> bool {
-        // Use constant-time comparison for security
-        // For floats, we need to handle NaN specially
-        if self.inner.is_nan() && other.inner.is_nan() {
-            return true;
-        }
-
-        // Convert to bytes for constant-time comparison
-        let self_bytes = self.inner.to_bits().to_le_bytes();
-        let other_bytes = other.inner.to_bits().to_le_bytes();
-
-        let mut result = 0u8;
-        for i in 0..8 {
-            result |= self_bytes[i] ^ other_bytes[i];
-        }
-        result == 0
+        ct_eq(
+            &self.inner.to_bits().to_le_bytes(),
+            &other.inner.to_bits().to_le_bytes(),
+        )
     }
 }
 
@@ -299,6 +324,23 @@ mod tests {
         assert_ne!(nan1, normal);
     }
 
+    #[test]
+    fn test_secret_float_bit_pattern_equality() {
+        for value in [f64::MAX, f64::MIN, f64::MIN_POSITIVE, f64::INFINITY, 0.0] {
+            assert_eq!(SecretFloat::new(value), SecretFloat::new(value));
+        }
+        assert_ne!(SecretFloat::new(0.0), SecretFloat::new(-0.0));
+        assert_ne!(
+            SecretFloat::new(f64::INFINITY),
+            SecretFloat::new(f64::NEG_INFINITY)
+        );
+
+        // NaNs are equal only when their bit patterns match
+        let other_nan = f64::from_bits(f64::NAN.to_bits() ^ 1);
+        assert!(other_nan.is_nan());
+        assert_ne!(SecretFloat::new(f64::NAN), SecretFloat::new(other_nan));
+    }
+
     #[test]
     fn test_secret_float_special_checks() {
         let normal = SecretFloat::new(1.0);
@@ -6,7 +6,7 @@ use nu_protocol::ast::Operator;
 use nu_protocol::CustomValue;
 use nu_protocol::{ShellError, Span, Value};
 
-use super::{secret_comparison_operation, secret_ordering_operation};
+use super::{ct_eq, secret_comparison_operation, secret_ordering_operation};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
@@ -18,6 +18,7 @@ use crate::config::RedactionContext;
 pub struct SecretInt {
     inner: i64,
     redaction_template: Option<String>,
+    origin_span: Option<Span>,
 }
 
 // Functional serialization - serialize actual content for pipeline operations
@@ -27,9 +28,10 @@ impl Serialize for SecretInt {
         S: Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut state = serializer.serialize_struct("SecretInt", 2)?;
+        let mut state = serializer.serialize_struct("SecretInt", 3)?;
         state.serialize_field("inner", &self.inner)?;
         state.serialize_field("redaction_template", &self.redaction_template)?;
+        state.serialize_field("origin_span", &self.origin_span)?;
         state.end()
     }
 }
@@ -44,12 +46,14 @@ impl<'de> Deserialize<'de> for SecretInt {
         struct SecretIntData {
             inner: i64,
             redaction_template: Option<String>,
+            origin_span: Option<Span>,
         }
 
         let data = SecretIntData::deserialize(deserializer)?;
         Ok(SecretInt {
             inner: data.inner,
             redaction_template: data.redaction_template,
+            origin_span: data.origin_span,
         })
     }
 }
@@ -70,6 +74,7 @@ impl SecretInt {
         Self {
             inner: value,
             redaction_template: None,
+            origin_span: None,
         }
     }
 
@@ -78,6 +83,32 @@ impl SecretInt {
         Self {
             inner: value,
             redaction_template: Some(template),
+            origin_span: None,
+        }
+    }
+
+    /// Whether this secret carries its own redaction template
+    pub fn has_custom_template(&self) -> bool {
+        self.redaction_template.is_some()
+    }
+
+    /// Record the span of the value this secret was wrapped from
+    pub fn with_origin_span(mut self, span: Span) -> Self {
+        self.origin_span = Some(span);
+        self
+    }
+
+    /// Span of the value this secret was wrapped from, if known (safe to expose)
+    pub fn origin_span(&self) -> Option<Span> {
+        self.origin_span
+    }
+
+    /// Create a new SecretInt holding `value` with this secret's redaction template and origin span
+    pub fn with_value(&self, value: i64) -> SecretInt {
+        SecretInt {
+            inner: value,
+            redaction_template: self.redaction_template.clone(),
+            origin_span: self.origin_span,
         }
     }
 
@@ -193,10 +224,8 @@ impl fmt::Debug for SecretInt {
 
 impl PartialEq for SecretInt {
     fn eq(&self, other: &Self) -> bool {
-        // Use constant-time comparison for security
-        // For integers, we can use simple XOR
-        let diff = self.inner ^ other.inner;
-        diff == 0
+        // Constant-time comparison over the little-endian byte representation
+        ct_eq(&self.inner.to_le_bytes(), &other.inner.to_le_bytes())
     }
 }
 
@@ -257,6 +286,16 @@ mod tests {
         assert_ne!(secret1, secret3);
     }
 
+    #[test]
+    fn test_secret_int_equality_boundaries() {
+        for value in [0, 1, -1, i64::MAX, i64::MIN] {
+            assert_eq!(SecretInt::new(value), SecretInt::new(value));
+        }
+        assert_ne!(SecretInt::new(i64::MAX), SecretInt::new(i64::MIN));
+        assert_ne!(SecretInt::new(0), SecretInt::new(-1));
+        assert_ne!(SecretInt::new(1), SecretInt::new(1 << 56));
+    }
+
     #[test]
     fn test_secret_int_into_inner() {
         let secret = SecretInt::new(777);
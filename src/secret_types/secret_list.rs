@@ -6,7 +6,7 @@ use nu_protocol::ast::Operator;
 use nu_protocol::CustomValue;
 use nu_protocol::{ShellError, Span, Value};
 
-use super::secret_comparison_operation;
+use super::{ct_eq, secret_comparison_operation};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use zeroize::ZeroizeOnDrop;
 
@@ -18,6 +18,7 @@ use crate::config::RedactionContext;
 pub struct SecretList {
     inner: Vec<Value>,
     redaction_template: Option<String>,
+    origin_span: Option<Span>,
 }
 
 // Functional serialization - serialize actual content for pipeline operations
@@ -27,9 +28,10 @@ impl Serialize for SecretList {
         S: Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut state = serializer.serialize_struct("SecretList", 2)?;
+        let mut state = serializer.serialize_struct("SecretList", 3)?;
         state.serialize_field("inner", &self.inner)?;
         state.serialize_field("redaction_template", &self.redaction_template)?;
+        state.serialize_field("origin_span", &self.origin_span)?;
         state.end()
     }
 }
@@ -44,12 +46,14 @@ impl<'de> Deserialize<'de> for SecretList {
         struct SecretListData {
             inner: Vec<Value>,
             redaction_template: Option<String>,
+            origin_span: Option<Span>,
         }
 
         let data = SecretListData::deserialize(deserializer)?;
         Ok(SecretList {
             inner: data.inner,
             redaction_template: data.redaction_template,
+            origin_span: data.origin_span,
         })
     }
 }
@@ -73,6 +77,7 @@ impl SecretList {
         Self {
             inner: value,
             redaction_template: None,
+            origin_span: None,
         }
     }
 
@@ -81,6 +86,32 @@ impl SecretList {
         Self {
             inner: value,
             redaction_template: Some(template),
+            origin_span: None,
+        }
+    }
+
+    /// Whether this secret carries its own redaction template
+    pub fn has_custom_template(&self) -> bool {
+        self.redaction_template.is_some()
+    }
+
+    /// Record the span of the value this secret was wrapped from
+    pub fn with_origin_span(mut self, span: Span) -> Self {
+        self.origin_span = Some(span);
+        self
+    }
+
+    /// Span of the value this secret was wrapped from, if known (safe to expose)
+    pub fn origin_span(&self) -> Option<Span> {
+        self.origin_span
+    }
+
+    /// Create a new SecretList holding `value` with this secret's redaction template and origin span
+    pub fn with_value(&self, value: Vec<Value>) -> SecretList {
+        SecretList {
+            inner: value,
+            redaction_template: self.redaction_template.clone(),
+            origin_span: self.origin_span,
         }
     }
 
@@ -216,16 +247,7 @@ impl PartialEq for SecretList {
         // This is a simplified approach - in production, we might want more sophisticated comparison
         let self_ser = bincode::serialize(&self.inner).unwrap_or_default();
         let other_ser = bincode::serialize(&other.inner).unwrap_or_default();
-
-        if self_ser.len() != other_ser.len() {
-            return false;
-        }
-
-        let mut result = 0u8;
-        for i in 0..self_ser.len() {
-            result |= self_ser[i] ^ other_ser[i];
-        }
-        result == 0
+        ct_eq(&self_ser, &other_ser)
     }
 }
 
@@ -0,0 +1,328 @@
+//! Secure range type with redacted display.
+
+use std::fmt;
+
+use nu_protocol::ast::Operator;
+use nu_protocol::{CustomValue, Range};
+use nu_protocol::{ShellError, Span, Value};
+
+use super::{ct_eq, secret_comparison_operation};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use zeroize::ZeroizeOnDrop;
+
+use crate::config::RedactionContext;
+
+/// A secure range type that redacts its content in all display contexts
+///
+/// Both int and float ranges are stored as-is, so `reveal()` and `into_inner()`
+/// return exactly the range that was wrapped.
+#[derive(Clone)]
+pub struct SecretRange {
+    inner: Range,
+    redaction_template: Option<String>,
+    origin_span: Option<Span>,
+}
+
+// Functional serialization - serialize actual content for pipeline operations
+impl Serialize for SecretRange {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("SecretRange", 3)?;
+        state.serialize_field("inner", &self.inner)?;
+        state.serialize_field("redaction_template", &self.redaction_template)?;
+        state.serialize_field("origin_span", &self.origin_span)?;
+        state.end()
+    }
+}
+
+// Functional deserialization - restore actual content for pipeline operations
+impl<'de> Deserialize<'de> for SecretRange {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct SecretRangeData {
+            inner: Range,
+            redaction_template: Option<String>,
+            origin_span: Option<Span>,
+        }
+
+        let data = SecretRangeData::deserialize(deserializer)?;
+        Ok(SecretRange {
+            inner: data.inner,
+            redaction_template: data.redaction_template,
+            origin_span: data.origin_span,
+        })
+    }
+}
+
+impl Drop for SecretRange {
+    fn drop(&mut self) {
+        // Ranges only hold primitive bounds and a step, with no heap allocation
+        // to clear. Zeroization is best-effort: the bounds are released with the
+        // struct by Rust's standard drop mechanism.
+    }
+}
+
+// Manual ZeroizeOnDrop implementation to ensure proper cleanup
+impl ZeroizeOnDrop for SecretRange {}
+
+impl SecretRange {
+    /// Create a new SecretRange from a range
+    pub fn new(value: Range) -> Self {
+        Self {
+            inner: value,
+            redaction_template: None,
+            origin_span: None,
+        }
+    }
+
+    /// Create a new SecretRange with a custom redaction template
+    pub fn new_with_template(value: Range, template: String) -> Self {
+        Self {
+            inner: value,
+            redaction_template: Some(template),
+            origin_span: None,
+        }
+    }
+
+    /// Whether this secret carries its own redaction template
+    pub fn has_custom_template(&self) -> bool {
+        self.redaction_template.is_some()
+    }
+
+    /// Record the span of the value this secret was wrapped from
+    pub fn with_origin_span(mut self, span: Span) -> Self {
+        self.origin_span = Some(span);
+        self
+    }
+
+    /// Span of the value this secret was wrapped from, if known (safe to expose)
+    pub fn origin_span(&self) -> Option<Span> {
+        self.origin_span
+    }
+
+    /// Create a new SecretRange holding `value` with this secret's redaction template and origin span
+    pub fn with_value(&self, value: Range) -> SecretRange {
+        SecretRange {
+            inner: value,
+            redaction_template: self.redaction_template.clone(),
+            origin_span: self.origin_span,
+        }
+    }
+
+    /// Get a reference to the inner range (for controlled access)
+    pub fn reveal(&self) -> &Range {
+        &self.inner
+    }
+
+    /// Convert back to a range (consumes the SecretRange)
+    pub fn into_inner(self) -> Range {
+        self.inner
+    }
+
+    /// Render the redaction text for `context`
+    fn redacted_text(&self, context: RedactionContext) -> String {
+        if let Some(template) = &self.redaction_template {
+            // Convert range to parsable string representation
+            let range_value = Value::range(self.inner, Span::unknown());
+            let range_str = range_value.to_parsable_string(", ", &nu_protocol::Config::default());
+            crate::redaction::generate_redacted_string_with_custom_template_and_value(
+                template,
+                "range",
+                None,
+                Some(range_str), // Length not meaningful for ranges
+            )
+        } else {
+            crate::redaction::get_redacted_string_with_value::<String>("range", context, None)
+        }
+    }
+}
+
+#[typetag::serde]
+impl CustomValue for SecretRange {
+    fn clone_value(&self, span: Span) -> Value {
+        Value::custom(Box::new(self.clone()), span)
+    }
+
+    fn type_name(&self) -> String {
+        "secret_range".into()
+    }
+
+    fn to_base_value(&self, span: Span) -> Result<Value, ShellError> {
+        Ok(Value::string(
+            self.redacted_text(RedactionContext::Serialization),
+            span,
+        ))
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn notify_plugin_on_drop(&self) -> bool {
+        false // We handle cleanup via ZeroizeOnDrop
+    }
+
+    fn operation(
+        &self,
+        lhs_span: Span,
+        operator: Operator,
+        op: Span,
+        right: &Value,
+    ) -> Result<Value, ShellError> {
+        secret_comparison_operation(self, lhs_span, operator, op, right, "secret_range")
+    }
+}
+
+impl fmt::Display for SecretRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.redacted_text(RedactionContext::Display))
+    }
+}
+
+impl fmt::Debug for SecretRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "SecretRange({})",
+            self.redacted_text(RedactionContext::Debug)
+        )
+    }
+}
+
+impl PartialEq for SecretRange {
+    fn eq(&self, other: &Self) -> bool {
+        // Compare ranges by serializing and using constant-time comparison
+        let self_ser = bincode::serialize(&self.inner).unwrap_or_default();
+        let other_ser = bincode::serialize(&other.inner).unwrap_or_default();
+        ct_eq(&self_ser, &other_ser)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nu_protocol::ast::{Comparison, RangeInclusion};
+
+    fn int_range(start: i64, end: i64, inclusion: RangeInclusion) -> Range {
+        let span = Span::test_data();
+        Range::new(
+            Value::int(start, span),
+            Value::nothing(span),
+            Value::int(end, span),
+            inclusion,
+            span,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_secret_range_creation() {
+        let range = int_range(1, 10, RangeInclusion::Inclusive);
+        let secret = SecretRange::new(range);
+        assert_eq!(secret.reveal(), &range);
+    }
+
+    #[test]
+    fn test_secret_range_display() {
+        let secret = SecretRange::new(int_range(100, 250, RangeInclusion::Inclusive));
+        let display_result = format!("{}", secret);
+        let debug_result = format!("{:?}", secret);
+
+        assert!(
+            display_result.contains("redacted")
+                || display_result.contains("***")
+                || display_result.contains("HIDDEN")
+        );
+        assert!(!display_result.contains("250"));
+        assert!(debug_result.contains("SecretRange"));
+        assert!(!debug_result.contains("250"));
+    }
+
+    #[test]
+    fn test_secret_range_custom_value() {
+        let secret = SecretRange::new(int_range(1, 5, RangeInclusion::RightExclusive));
+        assert_eq!(secret.type_name(), "secret_range");
+
+        let base_value = secret.to_base_value(Span::test_data()).unwrap();
+        match base_value {
+            Value::String { val, .. } => assert_eq!(val, "<redacted:range>"),
+            _ => panic!("Expected string value"),
+        }
+    }
+
+    #[test]
+    fn test_secret_range_equality() {
+        let secret1 = SecretRange::new(int_range(1, 10, RangeInclusion::Inclusive));
+        let secret2 = SecretRange::new(int_range(1, 10, RangeInclusion::Inclusive));
+        let secret3 = SecretRange::new(int_range(1, 10, RangeInclusion::RightExclusive));
+
+        assert_eq!(secret1, secret2);
+        assert_ne!(secret1, secret3);
+    }
+
+    #[test]
+    fn test_secret_range_operations() {
+        let span = Span::test_data();
+        let secret = SecretRange::new(int_range(1, 10, RangeInclusion::Inclusive));
+        let same = Value::custom(
+            Box::new(SecretRange::new(int_range(
+                1,
+                10,
+                RangeInclusion::Inclusive,
+            ))),
+            span,
+        );
+
+        let equal = secret
+            .operation(span, Operator::Comparison(Comparison::Equal), span, &same)
+            .unwrap();
+        assert!(equal.as_bool().unwrap());
+
+        let not_equal = secret
+            .operation(
+                span,
+                Operator::Comparison(Comparison::NotEqual),
+                span,
+                &same,
+            )
+            .unwrap();
+        assert!(!not_equal.as_bool().unwrap());
+
+        assert!(secret
+            .operation(
+                span,
+                Operator::Comparison(Comparison::LessThan),
+                span,
+                &same
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn test_secret_range_into_inner() {
+        let range = int_range(0, 3, RangeInclusion::RightExclusive);
+        let secret = SecretRange::new(range);
+        assert_eq!(secret.into_inner(), range);
+    }
+
+    #[test]
+    fn test_secret_range_with_custom_template() {
+        let secret = SecretRange::new_with_template(
+            int_range(1, 10, RangeInclusion::Inclusive),
+            "{{secret_type}}_HIDDEN".to_string(),
+        );
+
+        assert_eq!(format!("{}", secret), "range_HIDDEN");
+        assert_eq!(format!("{:?}", secret), "SecretRange(range_HIDDEN)");
+    }
+}
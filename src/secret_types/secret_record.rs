@@ -18,6 +18,7 @@ use crate::config::RedactionContext;
 pub struct SecretRecord {
     inner: Record,
     redaction_template: Option<String>,
+    origin_span: Option<Span>,
 }
 
 // Functional serialization - serialize actual content for pipeline operations
@@ -27,9 +28,10 @@ impl Serialize for SecretRecord {
         S: Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut state = serializer.serialize_struct("SecretRecord", 2)?;
+        let mut state = serializer.serialize_struct("SecretRecord", 3)?;
         state.serialize_field("inner", &self.inner)?;
         state.serialize_field("redaction_template", &self.redaction_template)?;
+        state.serialize_field("origin_span", &self.origin_span)?;
         state.end()
     }
 }
@@ -44,12 +46,14 @@ impl<'de> Deserialize<'de> for SecretRecord {
         struct SecretRecordData {
             inner: Record,
             redaction_template: Option<String>,
+            origin_span: Option<Span>,
         }
 
         let data = SecretRecordData::deserialize(deserializer)?;
         Ok(SecretRecord {
             inner: data.inner,
             redaction_template: data.redaction_template,
+            origin_span: data.origin_span,
         })
     }
 }
@@ -71,6 +75,7 @@ impl SecretRecord {
         Self {
             inner: value,
             redaction_template: None,
+            origin_span: None,
         }
     }
 
@@ -79,9 +84,26 @@ impl SecretRecord {
         Self {
             inner: value,
             redaction_template: Some(template),
+            origin_span: None,
         }
     }
 
+    /// Whether this secret carries its own redaction template
+    pub fn has_custom_template(&self) -> bool {
+        self.redaction_template.is_some()
+    }
+
+    /// Record the span of the value this secret was wrapped from
+    pub fn with_origin_span(mut self, span: Span) -> Self {
+        self.origin_span = Some(span);
+        self
+    }
+
+    /// Span of the value this secret was wrapped from, if known (safe to expose)
+    pub fn origin_span(&self) -> Option<Span> {
+        self.origin_span
+    }
+
     /// Get a reference to the inner record (for controlled access)
     pub fn reveal(&self) -> &Record {
         &self.inner
@@ -106,6 +128,15 @@ impl SecretRecord {
     pub fn fields(&self) -> impl Iterator<Item = &String> {
         self.inner.columns()
     }
+
+    /// Create a new SecretRecord holding `value` with this secret's redaction template
+    pub fn with_value(&self, value: Record) -> SecretRecord {
+        SecretRecord {
+            inner: value,
+            redaction_template: self.redaction_template.clone(),
+            origin_span: self.origin_span,
+        }
+    }
 }
 
 #[typetag::serde]
@@ -605,8 +636,11 @@ mod tests {
         let json_result = serde_json::to_string(&secret);
         assert!(json_result.is_ok());
         let json = json_result.unwrap();
-        // Now includes the struct format with both inner and redaction_template fields
-        assert_eq!(json, "{\"inner\":{},\"redaction_template\":null}");
+        // Now includes the struct format with inner, redaction_template and origin_span fields
+        assert_eq!(
+            json,
+            "{\"inner\":{},\"redaction_template\":null,\"origin_span\":null}"
+        );
 
         // Test deserialization of empty record
         let deserialized: Result<SecretRecord, _> = serde_json::from_str(&json);
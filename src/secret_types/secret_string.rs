@@ -6,7 +6,7 @@ use nu_protocol::ast::Operator;
 use nu_protocol::CustomValue;
 use nu_protocol::{ShellError, Span, Value};
 
-use super::secret_comparison_operation;
+use super::{ct_eq, secret_comparison_operation};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
@@ -18,6 +18,7 @@ use crate::config::RedactionContext;
 pub struct SecretString {
     inner: String,
     redaction_template: Option<String>,
+    origin_span: Option<Span>,
 }
 
 // Functional serialization - always serialize as struct for consistency
@@ -27,9 +28,10 @@ impl Serialize for SecretString {
         S: Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut state = serializer.serialize_struct("SecretString", 2)?;
+        let mut state = serializer.serialize_struct("SecretString", 3)?;
         state.serialize_field("inner", &self.inner)?;
         state.serialize_field("redaction_template", &self.redaction_template)?;
+        state.serialize_field("origin_span", &self.origin_span)?;
         state.end()
     }
 }
@@ -44,12 +46,14 @@ impl<'de> Deserialize<'de> for SecretString {
         struct SecretStringData {
             inner: String,
             redaction_template: Option<String>,
+            origin_span: Option<Span>,
         }
 
         let data = SecretStringData::deserialize(deserializer)?;
         Ok(SecretString {
             inner: data.inner,
             redaction_template: data.redaction_template,
+            origin_span: data.origin_span,
         })
     }
 }
@@ -70,6 +74,7 @@ impl SecretString {
         Self {
             inner: value,
             redaction_template: None,
+            origin_span: None,
         }
     }
 
@@ -78,9 +83,26 @@ impl SecretString {
         Self {
             inner: value,
             redaction_template: Some(template),
+            origin_span: None,
         }
     }
 
+    /// Whether this secret carries its own redaction template
+    pub fn has_custom_template(&self) -> bool {
+        self.redaction_template.is_some()
+    }
+
+    /// Record the span of the value this secret was wrapped from
+    pub fn with_origin_span(mut self, span: Span) -> Self {
+        self.origin_span = Some(span);
+        self
+    }
+
+    /// Span of the value this secret was wrapped from, if known (safe to expose)
+    pub fn origin_span(&self) -> Option<Span> {
+        self.origin_span
+    }
+
     /// Get a reference to the inner string (for controlled access)
     pub fn reveal(&self) -> &str {
         &self.inner
@@ -101,6 +123,15 @@ impl SecretString {
         self.inner.is_empty()
     }
 
+    /// Create a new SecretString holding `value` with this secret's redaction template
+    pub fn with_value(&self, value: String) -> SecretString {
+        SecretString {
+            inner: value,
+            redaction_template: self.redaction_template.clone(),
+            origin_span: self.origin_span,
+        }
+    }
+
     /// Get redacted string according to user configuration
     /// This respects the user's configuration for redaction style
     pub fn redacted_display(&self) -> String {
@@ -213,21 +244,13 @@ impl fmt::Debug for SecretString {
 }
 
 impl PartialEq for SecretString {
+    /// Compares the UTF-8 bytes with [`ct_eq`].
+    ///
+    /// There is no early return on a length mismatch: the comparison always
+    /// runs `max(len_a, len_b)` rounds, so whether the two secrets share a
+    /// length, or where they first differ, does not show up in the timing.
     fn eq(&self, other: &Self) -> bool {
-        // Use constant-time comparison for security
-        if self.inner.len() != other.inner.len() {
-            return false;
-        }
-
-        let self_bytes = self.inner.as_bytes();
-        let other_bytes = other.inner.as_bytes();
-
-        // Simple constant-time comparison
-        let mut result = 0u8;
-        for i in 0..self_bytes.len() {
-            result |= self_bytes[i] ^ other_bytes[i];
-        }
-        result == 0
+        ct_eq(self.inner.as_bytes(), other.inner.as_bytes())
     }
 }
 
@@ -283,6 +306,15 @@ mod tests {
         assert_ne!(secret1, secret3);
     }
 
+    #[test]
+    fn test_secret_string_with_value_keeps_template() {
+        let secret =
+            SecretString::new_with_template("abc".to_string(), "[{{secret_type}}]".to_string());
+        let derived = secret.with_value("xyz".to_string());
+        assert_eq!(derived.reveal(), "xyz");
+        assert_eq!(derived.redaction_template, secret.redaction_template);
+    }
+
     #[test]
     fn test_secret_string_into_inner() {
         let secret = SecretString::new("my-secret".to_string());
@@ -440,6 +472,18 @@ mod tests {
         assert_eq!(unicode1, unicode2);
     }
 
+    #[test]
+    fn test_secret_string_equality_prefix_of_other() {
+        // A shared prefix must not make strings of different lengths equal,
+        // in either direction, including when the extra bytes are NUL
+        let secret = SecretString::new("hunter2".to_string());
+        for candidate in ["hunter", "hunter22", "hunter2\0", ""] {
+            let candidate = SecretString::new(candidate.to_string());
+            assert_ne!(secret, candidate);
+            assert_ne!(candidate, secret);
+        }
+    }
+
     #[test]
     fn test_secret_string_unicode_support() {
         let unicode_secret = SecretString::new("Hello 世界 🌍 café".to_string());
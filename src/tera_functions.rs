@@ -9,6 +9,8 @@
 //! - `take(n=5, s="text")`: Returns the first n characters of input string
 //! - `strlen(s="text")`: Returns the length of the input string as a number
 //! - `mask_partial(s="text", l=2, r=2)`: Masks the middle portion of a string, keeping specified characters from left and right (l and r default to 0, optional c="*" for custom masking character)
+//! - `mask_middle(s="text", prefix=2, suffix=2)`: Shows the first `prefix` and last `suffix` characters and masks the rest (optional character="*"); values too short to keep anything hidden are fully masked
+//! - `hash(s="text", algorithm="sha256", length=8)`: Returns the hex digest of the input (sha256, sha512 or blake3), optionally truncated to `length` characters; empty when the input is missing or empty
 //! - `secret_string()`: Returns the actual secret value (WARNING: exposes sensitive data!)
 
 use std::collections::HashMap;
@@ -45,13 +47,62 @@ pub fn register_mask_partial_function(tera: &mut tera::Tera) {
     tera.register_function("mask_partial", mask_partial_function);
 }
 
+/// Register the mask_middle function with a Tera instance
+/// Shows the first and last characters of a string and masks the middle
+pub fn register_mask_middle_function(tera: &mut tera::Tera) {
+    tera.register_function("mask_middle", mask_middle_function);
+}
+
+/// Register the hash function with a Tera instance
+/// Returns a hex digest of the input string, optionally truncated
+pub fn register_hash_function(tera: &mut tera::Tera) {
+    tera.register_function("hash", hash_function);
+}
+
+/// Template functions (and the `secret_string` variable) that can be disabled
+/// through `security.disabled_template_functions`
+pub const DISABLEABLE_TEMPLATE_FUNCTIONS: &[&str] = &[
+    "replicate",
+    "reverse",
+    "take",
+    "strlen",
+    "mask_partial",
+    "mask_middle",
+    "hash",
+    "reveal_chars",
+    "secret_string",
+];
+
 /// Register all standard template functions (excluding secret_string)
 pub fn register_all_standard_functions(tera: &mut tera::Tera) {
-    register_replicate_function(tera);
-    register_reverse_function(tera);
-    register_take_function(tera);
-    register_strlen_function(tera);
-    register_mask_partial_function(tera);
+    register_standard_functions_except(tera, &[]);
+}
+
+/// Register all standard template functions except those named in `disabled`
+pub fn register_standard_functions_except(tera: &mut tera::Tera, disabled: &[String]) {
+    let enabled = |name: &str| !disabled.iter().any(|d| d == name);
+
+    if enabled("replicate") {
+        register_replicate_function(tera);
+    }
+    if enabled("reverse") {
+        register_reverse_function(tera);
+    }
+    if enabled("take") {
+        register_take_function(tera);
+    }
+    if enabled("strlen") {
+        register_strlen_function(tera);
+    }
+    if enabled("mask_partial") {
+        register_mask_partial_function(tera);
+    }
+    if enabled("mask_middle") {
+        register_mask_middle_function(tera);
+    }
+    if enabled("hash") {
+        register_hash_function(tera);
+    }
 }
 
 /// Replicate function implementation
@@ -209,6 +260,102 @@ fn mask_partial_function(args: &HashMap<String, TeraValue>) -> TeraResult<TeraVa
     Ok(TeraValue::String(result))
 }
 
+/// Mask_middle function implementation
+/// Keeps `prefix` characters from the start and `suffix` from the end, masking the
+/// middle. Unlike mask_partial, a string of at most `prefix + suffix` characters is
+/// masked entirely, so the whole value is never shown.
+fn mask_middle_function(args: &HashMap<String, TeraValue>) -> TeraResult<TeraValue> {
+    let s = args
+        .get("s")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| TeraError::msg("mask_middle function requires 's' parameter"))?;
+
+    let prefix = args
+        .get("prefix")
+        .and_then(|v| v.as_i64())
+        .ok_or_else(|| TeraError::msg("mask_middle function requires 'prefix' parameter"))?;
+
+    let suffix = args
+        .get("suffix")
+        .and_then(|v| v.as_i64())
+        .ok_or_else(|| TeraError::msg("mask_middle function requires 'suffix' parameter"))?;
+
+    let character = args
+        .get("character")
+        .and_then(|v| v.as_str())
+        .unwrap_or("*");
+
+    if prefix < 0 || suffix < 0 {
+        return Err(TeraError::msg(
+            "mask_middle function requires non-negative prefix and suffix parameters",
+        ));
+    }
+
+    let chars: Vec<char> = s.chars().collect();
+    let total_len = chars.len();
+    let prefix = prefix as usize;
+    let suffix = suffix as usize;
+
+    // Too short to keep anything hidden, so mask everything
+    if prefix.saturating_add(suffix) >= total_len {
+        return Ok(TeraValue::String(character.repeat(total_len)));
+    }
+
+    let left_part: String = chars[..prefix].iter().collect();
+    let right_part: String = chars[total_len - suffix..].iter().collect();
+    let middle_part = character.repeat(total_len - prefix - suffix);
+
+    Ok(TeraValue::String(format!(
+        "{}{}{}",
+        left_part, middle_part, right_part
+    )))
+}
+
+/// Hash function implementation
+/// Hashes `s` with `algorithm` (default sha256) and returns the lowercase hex digest,
+/// truncated to `length` characters if given. A missing or empty `s` yields an empty
+/// string so nothing is computed when no secret value is available.
+fn hash_function(args: &HashMap<String, TeraValue>) -> TeraResult<TeraValue> {
+    use sha2::{Digest, Sha256, Sha512};
+
+    let s = match args.get("s").and_then(|v| v.as_str()) {
+        Some(s) if !s.is_empty() => s,
+        _ => return Ok(TeraValue::String(String::new())),
+    };
+
+    let algorithm = args
+        .get("algorithm")
+        .and_then(|v| v.as_str())
+        .unwrap_or("sha256");
+
+    let digest = match algorithm.to_lowercase().as_str() {
+        "sha256" => hex::encode(Sha256::digest(s.as_bytes())),
+        "sha512" => hex::encode(Sha512::digest(s.as_bytes())),
+        "blake3" => blake3::hash(s.as_bytes()).to_hex().to_string(),
+        _ => {
+            return Err(TeraError::msg(format!(
+                "hash function does not support algorithm '{}'. Supported algorithms: sha256, sha512, blake3",
+                algorithm
+            )))
+        }
+    };
+
+    let digest = match args.get("length") {
+        Some(length) => {
+            let length = length
+                .as_i64()
+                .filter(|length| *length >= 0)
+                .ok_or_else(|| {
+                    TeraError::msg("hash function requires a non-negative 'length' parameter")
+                })?;
+            digest.chars().take(length as usize).collect()
+        }
+        None => digest,
+    };
+
+    Ok(TeraValue::String(digest))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -281,6 +428,21 @@ mod tests {
         assert_eq!(result, "4");
     }
 
+    #[test]
+    fn test_disabled_function_not_registered() {
+        let mut tera = Tera::default();
+
+        register_standard_functions_except(&mut tera, &["take".to_string()]);
+
+        tera.add_raw_template("take", "{{take(n=2, s='test')}}")
+            .unwrap();
+        assert!(tera.render("take", &tera::Context::new()).is_err());
+
+        tera.add_raw_template("strlen", "{{strlen(s='test')}}")
+            .unwrap();
+        assert_eq!(tera.render("strlen", &tera::Context::new()).unwrap(), "4");
+    }
+
     #[test]
     fn test_mask_partial_function_direct() {
         // Test basic functionality with default masking character
@@ -431,6 +593,94 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_mask_middle_function_direct() {
+        let mut args = HashMap::new();
+        args.insert("s".to_string(), TeraValue::String("abcdefyz".to_string()));
+        args.insert("prefix".to_string(), TeraValue::Number(2.into()));
+        args.insert("suffix".to_string(), TeraValue::Number(2.into()));
+
+        let result = mask_middle_function(&args).unwrap();
+        assert_eq!(result.as_str().unwrap(), "ab****yz");
+
+        args.insert("character".to_string(), TeraValue::String("#".to_string()));
+        let result = mask_middle_function(&args).unwrap();
+        assert_eq!(result.as_str().unwrap(), "ab####yz");
+    }
+
+    #[test]
+    fn test_mask_middle_function_errors() {
+        let mut args = HashMap::new();
+        args.insert("prefix".to_string(), TeraValue::Number(2.into()));
+        args.insert("suffix".to_string(), TeraValue::Number(2.into()));
+        assert!(mask_middle_function(&args).is_err());
+
+        args.insert("s".to_string(), TeraValue::String("abcdefgh".to_string()));
+        args.insert("prefix".to_string(), TeraValue::Number((-1).into()));
+        assert!(mask_middle_function(&args).is_err());
+    }
+
+    #[test]
+    fn test_hash_function_direct() {
+        let mut args = HashMap::new();
+        args.insert(
+            "s".to_string(),
+            TeraValue::String("hello world".to_string()),
+        );
+
+        let result = hash_function(&args).unwrap();
+        assert_eq!(
+            result.as_str().unwrap(),
+            "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9"
+        );
+
+        args.insert(
+            "algorithm".to_string(),
+            TeraValue::String("blake3".to_string()),
+        );
+        let result = hash_function(&args).unwrap();
+        assert_eq!(
+            result.as_str().unwrap(),
+            "d74981efa70a0c880b8d8c1985d075dbcbf679b99a5f9914e5aaf96b831a9e24"
+        );
+
+        args.insert(
+            "algorithm".to_string(),
+            TeraValue::String("md5".to_string()),
+        );
+        assert!(hash_function(&args).is_err());
+    }
+
+    #[test]
+    fn test_hash_function_truncation() {
+        let mut args = HashMap::new();
+        args.insert(
+            "s".to_string(),
+            TeraValue::String("hello world".to_string()),
+        );
+        args.insert("length".to_string(), TeraValue::Number(6.into()));
+
+        let result = hash_function(&args).unwrap();
+        assert_eq!(result.as_str().unwrap(), "b94d27");
+
+        args.insert("length".to_string(), TeraValue::Number(1000.into()));
+        let result = hash_function(&args).unwrap();
+        assert_eq!(result.as_str().unwrap().len(), 64);
+
+        args.insert("length".to_string(), TeraValue::Number((-1).into()));
+        assert!(hash_function(&args).is_err());
+    }
+
+    #[test]
+    fn test_hash_function_without_value() {
+        let args = HashMap::new();
+        assert_eq!(hash_function(&args).unwrap().as_str().unwrap(), "");
+
+        let mut args = HashMap::new();
+        args.insert("s".to_string(), TeraValue::String(String::new()));
+        assert_eq!(hash_function(&args).unwrap().as_str().unwrap(), "");
+    }
+
     #[test]
     fn test_mask_partial_function_registration() {
         let mut tera = Tera::default();
@@ -5,6 +5,10 @@
 
 use nu_plugin_secret::{SecretBinary, SecretInt, SecretString};
 
+/// Max/min timing ratio above which a string comparison is considered to leak
+/// information through timing
+const STRING_TIMING_RATIO_THRESHOLD: f64 = 2.0;
+
 /// Test cryptographic security properties
 #[cfg(test)]
 mod cryptographic_security_tests {
@@ -82,7 +86,7 @@ mod cryptographic_security_tests {
 
         // For truly constant-time comparison, ratio should be close to 1.0
         // Allow some variance for system noise, but flag significant differences
-        if time_ratio > 2.0 {
+        if time_ratio > STRING_TIMING_RATIO_THRESHOLD {
             println!(
                 "Warning: String comparison may not be constant-time (ratio: {:.2})",
                 time_ratio
@@ -90,6 +94,69 @@ mod cryptographic_security_tests {
         }
     }
 
+    /// Test that string comparison time does not depend on the candidate's length
+    #[test]
+    fn test_string_comparison_is_length_oblivious() {
+        let reference = SecretString::new("correct horse battery staple".to_string());
+        let reference_len = reference.reveal().len();
+
+        // Every candidate is at most as long as the reference, so each comparison
+        // runs the same number of rounds; only a length early-return (or an
+        // early exit on the first differing byte) would separate them.
+        let candidates: Vec<String> = (0..=reference_len)
+            .map(|len| "correct horse battery staple"[..len].to_string())
+            .chain(std::iter::once("x".repeat(reference_len)))
+            .collect();
+
+        let mut timings = Vec::new();
+
+        for candidate in &candidates {
+            let candidate_secret = SecretString::new(candidate.clone());
+            assert_eq!(
+                reference == candidate_secret,
+                candidate == "correct horse battery staple"
+            );
+
+            // Warm up
+            for _ in 0..100 {
+                let _ = std::hint::black_box(reference == candidate_secret);
+            }
+
+            // Keep the fastest of several batches to filter out scheduler noise
+            let best = (0..20)
+                .map(|_| {
+                    let start = std::time::Instant::now();
+                    for _ in 0..1000 {
+                        let _ = std::hint::black_box(reference == candidate_secret);
+                    }
+                    start.elapsed().as_nanos()
+                })
+                .min()
+                .unwrap();
+
+            timings.push((candidate.len(), best));
+        }
+
+        let min_time = timings.iter().map(|(_, time)| *time).min().unwrap().max(1);
+        let max_time = timings.iter().map(|(_, time)| *time).max().unwrap();
+        let time_ratio = max_time as f64 / min_time as f64;
+
+        println!("Length-oblivious string comparison analysis:");
+        for (len, time_ns) in &timings {
+            println!("  length {}: {}ns", len, time_ns);
+        }
+        println!("  Max/Min ratio: {:.2}", time_ratio);
+
+        // Wall-clock timing is too noisy on shared CI runners to fail on, so
+        // flag a suspicious ratio like the other timing tests instead
+        if time_ratio > STRING_TIMING_RATIO_THRESHOLD {
+            println!(
+                "Warning: String comparison timing may depend on candidate length (ratio: {:.2})",
+                time_ratio
+            );
+        }
+    }
+
     /// Test constant-time integer comparison implementation  
     #[test]
     fn test_constant_time_integer_comparison() {
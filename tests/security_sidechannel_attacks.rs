@@ -3,7 +3,9 @@
 //! These tests analyze potential side-channel vulnerabilities including timing attacks,
 //! cache attacks, and other information leakage through observable system behaviors.
 
-use nu_plugin_secret::{SecretBinary, SecretBool, SecretFloat, SecretInt, SecretString};
+use nu_plugin_secret::{
+    SecretBinary, SecretBool, SecretDate, SecretFloat, SecretInt, SecretString,
+};
 use std::collections::HashMap;
 use std::time::{Duration, Instant};
 
@@ -143,6 +145,91 @@ mod side_channel_tests {
         }
     }
 
+    /// Average time in nanoseconds of `iterations` equality comparisons
+    fn average_eq_nanos<T: PartialEq>(secret: &T, candidate: &T, iterations: u32) -> u64 {
+        let mut total = 0u64;
+        for _ in 0..iterations {
+            let start = Instant::now();
+            let _result = std::hint::black_box(secret == candidate);
+            total += start.elapsed().as_nanos() as u64;
+        }
+        total / iterations as u64
+    }
+
+    /// Report the max/min timing ratio across candidates, warning above `threshold`
+    fn report_timing_ratio(label: &str, timings: &[(String, u64)], threshold: f64) {
+        let min_time = timings.iter().map(|(_, t)| *t).min().unwrap().max(1);
+        let max_time = timings.iter().map(|(_, t)| *t).max().unwrap();
+        let time_ratio = max_time as f64 / min_time as f64;
+
+        println!("{} comparison timing analysis:", label);
+        for (desc, time_ns) in timings {
+            println!("  {}: {}ns", desc, time_ns);
+        }
+        println!("Max/Min ratio: {:.2}", time_ratio);
+
+        if time_ratio > threshold {
+            println!(
+                "Warning: {} comparison timing variance detected (ratio: {:.2})",
+                label, time_ratio
+            );
+        }
+    }
+
+    /// Test for timing attacks on float, bool and date comparison operations
+    #[test]
+    fn test_scalar_comparison_timing_attacks() {
+        let float_secret = SecretFloat::new(1234.5678);
+        let float_timings: Vec<(String, u64)> = [1234.5678, 1234.5679, 0.0, -0.0, f64::NAN]
+            .iter()
+            .map(|&v| {
+                let candidate = SecretFloat::new(v);
+                (
+                    v.to_string(),
+                    average_eq_nanos(&float_secret, &candidate, 1000),
+                )
+            })
+            .collect();
+        report_timing_ratio("Float", &float_timings, 2.0);
+
+        let bool_secret = SecretBool::new(true);
+        let bool_timings: Vec<(String, u64)> = [true, false]
+            .iter()
+            .map(|&v| {
+                let candidate = SecretBool::new(v);
+                (
+                    v.to_string(),
+                    average_eq_nanos(&bool_secret, &candidate, 1000),
+                )
+            })
+            .collect();
+        report_timing_ratio("Bool", &bool_timings, 2.0);
+
+        let reference = chrono::DateTime::parse_from_rfc3339("2024-01-01T12:00:00Z").unwrap();
+        let date_secret = SecretDate::new(reference);
+        let date_timings: Vec<(String, u64)> = [
+            "2024-01-01T12:00:00Z",
+            "2024-01-01T12:00:00.000000001Z",
+            "2024-01-01T13:00:00+01:00",
+            "1970-01-01T00:00:00Z",
+        ]
+        .iter()
+        .map(|&v| {
+            let candidate = SecretDate::new(chrono::DateTime::parse_from_rfc3339(v).unwrap());
+            (
+                v.to_string(),
+                average_eq_nanos(&date_secret, &candidate, 1000),
+            )
+        })
+        .collect();
+        report_timing_ratio("Date", &date_timings, 2.0);
+
+        // Correctness is unaffected by the constant-time implementation
+        assert!(float_secret == SecretFloat::new(1234.5678));
+        assert!(bool_secret != SecretBool::new(false));
+        assert!(date_secret == SecretDate::new(reference));
+    }
+
     /// Test for side-channel information leakage in binary data operations
     #[test]
     #[ignore] // Temporarily disabled due to system timing variance